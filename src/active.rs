@@ -0,0 +1,114 @@
+//! Keeping track of which slimes are moving, so finding them doesn't mean looking at every slime.
+
+use alloc::vec::Vec;
+
+use crate::{
+    ActiveKind, EscapedImpulse, JigglyBoard, PhysicsObserver, PropagationEvent, Scalar, SlimeState,
+};
+
+/// The slimes that weren't settled at the end of the last step, for
+/// [`PhysicsScratch::with_active_tracking`](crate::PhysicsScratch::with_active_tracking).
+pub(crate) struct ActiveSet<Loc> {
+    cells: Vec<(Loc, ActiveKind)>,
+    /// Every slime the step did something to, maybe more than once.
+    touched: Vec<Loc>,
+    /// Which [`JigglyBoard::loc_index`]es are already in `cells`, while it's being rebuilt.
+    seen: Vec<bool>,
+}
+
+impl<Loc> ActiveSet<Loc> {
+    pub fn new() -> Self {
+        ActiveSet {
+            cells: Vec::new(),
+            touched: Vec::new(),
+            seen: Vec::new(),
+        }
+    }
+    pub fn cells(&self) -> &[(Loc, ActiveKind)] {
+        &self.cells
+    }
+}
+
+impl<Loc: Copy> ActiveSet<Loc> {
+    /// Pass everything `observer` is told on, noting down each slime it's told about.
+    pub fn watching<'a, O>(&'a mut self, observer: &'a mut O) -> Tracking<'a, Loc, O> {
+        Tracking {
+            observer,
+            touched: &mut self.touched,
+        }
+    }
+    /// Work out which of the slimes that were active, and the ones touched since, still are.
+    pub fn refresh<S: Scalar, B: JigglyBoard<S, Loc = Loc> + ?Sized>(&mut self, board: &mut B) {
+        let mut candidates = core::mem::take(&mut self.touched);
+        candidates.extend(self.cells.drain(..).map(|(location, _)| location));
+        for &location in &candidates {
+            if let Some(index) = board.loc_index(location) {
+                if self.seen.len() <= index {
+                    self.seen.resize(index + 1, false);
+                }
+                if core::mem::replace(&mut self.seen[index], true) {
+                    continue;
+                }
+            }
+            let mut kind = None;
+            board.impulse_jiggle_with(location, |state| {
+                kind = ActiveKind::of(&state);
+                state
+            });
+            if let Some(kind) = kind {
+                self.cells.push((location, kind));
+            }
+        }
+        for &location in &candidates {
+            if let Some(index) = board.loc_index(location) {
+                self.seen[index] = false;
+            }
+        }
+        candidates.clear();
+        self.touched = candidates;
+    }
+}
+
+/// An observer that notes down every slime it's told about before passing it on.
+pub(crate) struct Tracking<'a, Loc, O> {
+    observer: &'a mut O,
+    touched: &'a mut Vec<Loc>,
+}
+
+impl<Loc: Copy, Dir, S, O: PhysicsObserver<Loc, Dir, S>> PhysicsObserver<Loc, Dir, S>
+    for Tracking<'_, Loc, O>
+{
+    fn recovered(&mut self, loc: Loc) {
+        self.observer.recovered(loc);
+    }
+    fn truncated(&mut self, loc: Loc) {
+        self.observer.truncated(loc);
+    }
+    fn step_begun(&mut self) {
+        self.observer.step_begun();
+    }
+    fn stepped(&mut self, loc: Loc, state: &SlimeState<S>) {
+        if !matches!(state, SlimeState::Settled) {
+            self.touched.push(loc);
+        }
+        self.observer.stepped(loc, state);
+    }
+    fn settled(&mut self, loc: Loc) {
+        self.observer.settled(loc);
+    }
+    fn landed(&mut self, loc: Loc, impulse: S) {
+        self.touched.push(loc);
+        self.observer.landed(loc, impulse);
+    }
+    fn impulsed(&mut self, loc: Loc, impulse: S, came_from: Dir) {
+        self.touched.push(loc);
+        self.observer.impulsed(loc, impulse, came_from);
+    }
+    fn propagated(&mut self, event: PropagationEvent<Loc, Dir, S>) {
+        self.touched.push(event.loc);
+        self.observer.propagated(event);
+    }
+    fn escaped(&mut self, escaped: EscapedImpulse<Loc, Dir, S>) {
+        self.observer.escaped(escaped);
+    }
+}
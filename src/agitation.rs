@@ -0,0 +1,78 @@
+//! How worked up a board's slimes are, for driving camera shake and the like.
+
+use crate::{JigglyBoard, Scalar, SlimeState};
+
+/// What each kind of motion counts for in [`JigglyBoard::agitation`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AgitationWeights<S = f32> {
+    /// Per unit of jiggle momentum, up and down and sideways added together.
+    pub momentum: S,
+    /// Per unit of jiggle offset, up and down and sideways added together.
+    pub offset: S,
+    /// Per unit of kinetic energy, mass times velocity squared over two, of slimes in the air.
+    pub falling: S,
+}
+
+impl<S: Scalar> Default for AgitationWeights<S> {
+    fn default() -> Self {
+        AgitationWeights {
+            momentum: S::ONE,
+            offset: S::ONE,
+            // Falls are fast, so this keeps one slime dropping from swamping a board of jiggles
+            falling: S::from_f64(0.01),
+        }
+    }
+}
+
+/// How agitated a board is, from [`JigglyBoard::agitation`]. Zero once it's settled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Agitation<Loc, S = f32> {
+    /// Every slime's agitation added up.
+    pub total: S,
+    /// The most agitated slime and how agitated it is, if any are at all.
+    pub peak: Option<(Loc, S)>,
+}
+
+/// How agitated a slime in `state` weighing `mass` is.
+fn of_state<S: Scalar>(state: &SlimeState<S>, mass: S, weights: &AgitationWeights<S>) -> S {
+    match *state {
+        SlimeState::Jiggling {
+            momentum,
+            offset,
+            momentum_x,
+            offset_x,
+            ..
+        } => {
+            weights.momentum * (momentum.abs() + momentum_x.abs())
+                + weights.offset * (offset.abs() + offset_x.abs())
+        }
+        SlimeState::Falling { velocity, .. }
+        | SlimeState::Bouncing { velocity, .. }
+        | SlimeState::Launched { velocity, .. } => {
+            weights.falling * mass * velocity * velocity / S::from_f64(2.0)
+        }
+        _ => S::ZERO,
+    }
+}
+
+pub(crate) fn measure<S: Scalar, B: JigglyBoard<S> + ?Sized>(
+    board: &B,
+    weights: &AgitationWeights<S>,
+) -> Agitation<B::Loc, S> {
+    let mut agitation = Agitation {
+        total: S::ZERO,
+        peak: None,
+    };
+    for location in board.cols().flatten() {
+        let mass = board.mass(location);
+        let Some(amount) = board.peek_slime(location, |state, _| of_state(state, mass, weights))
+        else {
+            continue;
+        };
+        agitation.total += amount;
+        if amount > S::ZERO && agitation.peak.is_none_or(|(_, peak)| amount > peak) {
+            agitation.peak = Some((location, amount));
+        }
+    }
+    agitation
+}
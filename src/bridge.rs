@@ -0,0 +1,54 @@
+//! Passing jiggles from one board to another, for boards that sit next to each other.
+
+use crate::{JigglePropagation, PhysicsObserver};
+
+/// A jiggle that ran off the side of a board, from
+/// [`JigglyBoard::run_physics_collect_escaped`](crate::JigglyBoard::run_physics_collect_escaped).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EscapedImpulse<Loc, Dir, S = f32> {
+    /// The slime it left from.
+    pub from: Loc,
+    /// The way it was going.
+    pub dir: Dir,
+    /// Before mass, and before whatever the board would have multiplied it by on the way to a neighbour.
+    pub impulse: S,
+    /// How many cells it would have travelled by the time it got to one.
+    pub hops: u32,
+}
+
+/// Where a jiggle running off one board comes in on another, for
+/// [`JigglyBoard::inject_impulses`](crate::JigglyBoard::inject_impulses).
+///
+/// Escapes are collected whenever nothing takes a wave sideways, which includes gaps in the middle of a
+/// board as well as its edges, so a bridge should only let through the ones that leave from the edge it
+/// joins. Keeping `hops` as they are keeps `PhysicsProperties::max_propagation_hops` counting across
+/// both boards. Closures from an [`EscapedImpulse`] to an `Option<JigglePropagation>` are bridges.
+pub trait BoardBridge<FromLoc, FromDir, ToLoc, ToDir, S = f32> {
+    fn cross(
+        &self,
+        escaped: EscapedImpulse<FromLoc, FromDir, S>,
+    ) -> Option<JigglePropagation<ToLoc, ToDir, S>>;
+}
+
+impl<FromLoc, FromDir, ToLoc, ToDir, S, F> BoardBridge<FromLoc, FromDir, ToLoc, ToDir, S> for F
+where
+    F: Fn(EscapedImpulse<FromLoc, FromDir, S>) -> Option<JigglePropagation<ToLoc, ToDir, S>>,
+{
+    fn cross(
+        &self,
+        escaped: EscapedImpulse<FromLoc, FromDir, S>,
+    ) -> Option<JigglePropagation<ToLoc, ToDir, S>> {
+        self(escaped)
+    }
+}
+
+/// Hands every [`EscapedImpulse`] to an `Extend`, and ignores everything else.
+pub(crate) struct Escapes<'a, E>(pub &'a mut E);
+
+impl<Loc, Dir, S, E: Extend<EscapedImpulse<Loc, Dir, S>>> PhysicsObserver<Loc, Dir, S>
+    for Escapes<'_, E>
+{
+    fn escaped(&mut self, escaped: EscapedImpulse<Loc, Dir, S>) {
+        self.0.extend([escaped]);
+    }
+}
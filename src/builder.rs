@@ -0,0 +1,239 @@
+use crate::validate::positive;
+use crate::{
+    BounceMode, ConservationMode, CycleGuard, DampingMode, EnvelopeMode, FallStretch, ImpactMode,
+    LifeCurve, LifeRefresh, OffsetLimitMode, PhysicsProperties, PhysicsPropertiesError,
+    PropagationOrder, Scalar, SquashMode, WaveMode,
+};
+
+/// Builds a [`PhysicsProperties`] from only its independent parameters.
+///
+/// `jiggle_life_threshold_inverse` is derived from `jiggle_life_threshold`, so the two can't disagree.
+/// Every setter starts from [`PhysicsProperties::default`].
+pub struct PhysicsPropertiesBuilder<S = f32> {
+    props: PhysicsProperties<S>,
+}
+
+impl<S: Scalar> Default for PhysicsPropertiesBuilder<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Scalar> PhysicsPropertiesBuilder<S> {
+    pub fn new() -> Self {
+        Self::from_props(PhysicsProperties::default())
+    }
+    /// Start from an existing tuning, e.g. one of the presets.
+    pub fn from_props(props: PhysicsProperties<S>) -> Self {
+        Self { props }
+    }
+    pub fn gravity(mut self, gravity: S) -> Self {
+        self.props.gravity = gravity;
+        self
+    }
+    pub fn velocity_to_impact(mut self, velocity_to_impact: S) -> Self {
+        self.props.velocity_to_impact = velocity_to_impact;
+        self
+    }
+    pub fn min_impactable(mut self, min_impactable: S) -> Self {
+        self.props.min_impactable = min_impactable;
+        self
+    }
+    pub fn jiggle_stiff(mut self, jiggle_stiff: S) -> Self {
+        self.props.jiggle_stiff = jiggle_stiff;
+        self
+    }
+    pub fn jiggle_stiff_stretch(mut self, jiggle_stiff_stretch: Option<S>) -> Self {
+        self.props.jiggle_stiff_stretch = jiggle_stiff_stretch;
+        self
+    }
+    pub fn jiggle_damp(mut self, jiggle_damp: S) -> Self {
+        self.props.jiggle_damp = jiggle_damp;
+        self
+    }
+    pub fn jiggle_life_decrease_rate(mut self, jiggle_life_decrease_rate: S) -> Self {
+        self.props.jiggle_life_decrease_rate = jiggle_life_decrease_rate;
+        self
+    }
+    pub fn jiggle_life_threshold(mut self, jiggle_life_threshold: S) -> Self {
+        self.props.jiggle_life_threshold = jiggle_life_threshold;
+        self
+    }
+    pub fn jiggle_offset_epsilon(mut self, jiggle_offset_epsilon: S) -> Self {
+        self.props.jiggle_offset_epsilon = jiggle_offset_epsilon;
+        self
+    }
+    pub fn jiggle_momentum_epsilon(mut self, jiggle_momentum_epsilon: S) -> Self {
+        self.props.jiggle_momentum_epsilon = jiggle_momentum_epsilon;
+        self
+    }
+    pub fn damping_mode(mut self, damping_mode: DampingMode) -> Self {
+        self.props.damping_mode = damping_mode;
+        self
+    }
+    pub fn terminal_velocity(mut self, terminal_velocity: S) -> Self {
+        self.props.terminal_velocity = terminal_velocity;
+        self
+    }
+    pub fn transfer_horizontal(mut self, transfer_horizontal: S) -> Self {
+        self.props.transfer_horizontal = transfer_horizontal;
+        self
+    }
+    pub fn transfer_up(mut self, transfer_up: S) -> Self {
+        self.props.transfer_up = transfer_up;
+        self
+    }
+    pub fn transfer_down(mut self, transfer_down: S) -> Self {
+        self.props.transfer_down = transfer_down;
+        self
+    }
+    pub fn max_offset(mut self, max_offset: S) -> Self {
+        self.props.max_offset = max_offset;
+        self
+    }
+    pub fn offset_limit_mode(mut self, offset_limit_mode: OffsetLimitMode) -> Self {
+        self.props.offset_limit_mode = offset_limit_mode;
+        self
+    }
+    pub fn min_y_scale(mut self, min_y_scale: S) -> Self {
+        self.props.min_y_scale = min_y_scale;
+        self
+    }
+    pub fn squash_mode(mut self, squash_mode: SquashMode<S>) -> Self {
+        self.props.squash_mode = squash_mode;
+        self
+    }
+    pub fn fall_stretch(mut self, fall_stretch: FallStretch<S>) -> Self {
+        self.props.fall_stretch = fall_stretch;
+        self
+    }
+    pub fn life_refresh(mut self, life_refresh: LifeRefresh<S>) -> Self {
+        self.props.life_refresh = life_refresh;
+        self
+    }
+    pub fn max_dt(mut self, max_dt: S) -> Self {
+        self.props.max_dt = max_dt;
+        self
+    }
+    pub fn max_substeps(mut self, max_substeps: u32) -> Self {
+        self.props.max_substeps = max_substeps;
+        self
+    }
+    pub fn max_momentum(mut self, max_momentum: S) -> Self {
+        self.props.max_momentum = max_momentum;
+        self
+    }
+    pub fn cell_height(mut self, cell_height: S) -> Self {
+        self.props.cell_height = cell_height;
+        self
+    }
+    pub fn idle_wobble(mut self, amplitude: S, frequency: S) -> Self {
+        self.props.idle_wobble_amplitude = amplitude;
+        self.props.idle_wobble_frequency = frequency;
+        self
+    }
+    pub fn restitution(mut self, restitution: S, bounce_min_velocity: S) -> Self {
+        self.props.restitution = restitution;
+        self.props.bounce_min_velocity = bounce_min_velocity;
+        self
+    }
+    pub fn fall_delay(mut self, fall_delay: S) -> Self {
+        self.props.fall_delay = fall_delay;
+        self
+    }
+    pub fn impact_mode(mut self, impact_mode: ImpactMode<S>) -> Self {
+        self.props.impact_mode = impact_mode;
+        self
+    }
+    pub fn detach_stretch(mut self, detach_stretch: S) -> Self {
+        self.props.detach_stretch = detach_stretch;
+        self
+    }
+    pub fn bounce_mode(mut self, bounce_mode: BounceMode<S>) -> Self {
+        self.props.bounce_mode = bounce_mode;
+        self
+    }
+    pub fn max_propagation_hops(mut self, max_propagation_hops: u32) -> Self {
+        self.props.max_propagation_hops = max_propagation_hops;
+        self
+    }
+    pub fn max_propagations_per_step(mut self, max_propagations_per_step: Option<u32>) -> Self {
+        self.props.max_propagations_per_step = max_propagations_per_step;
+        self
+    }
+    pub fn cycle_guard(mut self, cycle_guard: CycleGuard) -> Self {
+        self.props.cycle_guard = cycle_guard;
+        self
+    }
+    pub fn wave_mode(mut self, wave_mode: WaveMode) -> Self {
+        self.props.wave_mode = wave_mode;
+        self
+    }
+    pub fn conservation_mode(mut self, conservation_mode: ConservationMode) -> Self {
+        self.props.conservation_mode = conservation_mode;
+        self
+    }
+    pub fn propagation_order(mut self, propagation_order: PropagationOrder) -> Self {
+        self.props.propagation_order = propagation_order;
+        self
+    }
+    pub fn propagate_up_factor(mut self, propagate_up_factor: S) -> Self {
+        self.props.propagate_up_factor = propagate_up_factor;
+        self
+    }
+    pub fn edge_reflectance(mut self, edge_reflectance: S) -> Self {
+        self.props.edge_reflectance = edge_reflectance;
+        self
+    }
+    pub fn cutoff_growth(mut self, cutoff_growth: S) -> Self {
+        self.props.cutoff_growth = cutoff_growth;
+        self
+    }
+    pub fn life_curve(mut self, life_curve: LifeCurve) -> Self {
+        self.props.life_curve = life_curve;
+        self
+    }
+    pub fn envelope_mode(mut self, envelope_mode: EnvelopeMode) -> Self {
+        self.props.envelope_mode = envelope_mode;
+        self
+    }
+    pub fn squish_release_momentum(mut self, squish_release_momentum: S) -> Self {
+        self.props.squish_release_momentum = squish_release_momentum;
+        self
+    }
+    pub fn spawn_duration(mut self, spawn_duration: S) -> Self {
+        self.props.spawn_duration = spawn_duration;
+        self
+    }
+    pub fn pop_duration(mut self, pop_duration: S) -> Self {
+        self.props.pop_duration = pop_duration;
+        self
+    }
+    pub fn slide_bounce(mut self, slide_bounce: S) -> Self {
+        self.props.slide_bounce = slide_bounce;
+        self
+    }
+    pub fn swap_duration(mut self, swap_duration: S) -> Self {
+        self.props.swap_duration = swap_duration;
+        self
+    }
+    pub fn swap_momentum(mut self, swap_momentum: S) -> Self {
+        self.props.swap_momentum = swap_momentum;
+        self
+    }
+    pub fn merge_duration(mut self, merge_duration: S) -> Self {
+        self.props.merge_duration = merge_duration;
+        self
+    }
+    pub fn merge_impulse(mut self, merge_impulse: S) -> Self {
+        self.props.merge_impulse = merge_impulse;
+        self
+    }
+    pub fn build(self) -> Result<PhysicsProperties<S>, PhysicsPropertiesError> {
+        let mut props = self.props;
+        positive("jiggle_life_threshold", props.jiggle_life_threshold)?;
+        props.jiggle_life_threshold_inverse = props.jiggle_life_threshold.recip();
+        props.validate()?;
+        Ok(props)
+    }
+}
@@ -0,0 +1,120 @@
+//! Stepping a column straight from slices, for boards that keep their slimes in dense arrays.
+
+use alloc::vec::Vec;
+
+use crate::pending;
+use crate::step::{self, StepContext, StepOutcome};
+use crate::{PhysicsProperties, RenderProps, Scalar, SlimePropsIn, SlimeState};
+
+/// What [`step_column_slice`] did besides updating the column, with slimes given by their index in it.
+///
+/// The board deals with these the way `run_physics` would have: a jiggle propagation from each landing
+/// (see [`JigglyBoard::apply_propagations`](crate::JigglyBoard::apply_propagations)),
+/// [`JigglyBoard::on_popped`](crate::JigglyBoard::on_popped) for each pop, and so on.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ColumnResult<S = f32> {
+    /// Slimes that hit their floor, with the landing impulse to send out from them.
+    pub landings: Vec<(usize, S)>,
+    /// Slimes that finished popping.
+    pub popped: Vec<usize>,
+    /// Slimes that finished merging into their neighbour.
+    pub merged: Vec<usize>,
+    /// Slimes that slid a whole cell sideways, with their sideways velocity.
+    pub slides: Vec<(usize, S)>,
+    /// Nothing in the column is moving or sent anything out.
+    pub settled: bool,
+    /// Every slime in the column is at rest, by `SlimeState::is_at_rest`, and nothing was sent out, so it's
+    /// safe to stop
+    /// stepping it as [`JigglyBoard::on_column_at_rest`](crate::JigglyBoard::on_column_at_rest) describes.
+    pub at_rest: bool,
+}
+
+/// The column pass for one column, the `col_index`th, from slices of its slimes' states and
+/// `y_bottom`s, bottom first, writing how to draw each into `outs`.
+///
+/// Does the same as [`JigglyBoard::step_column`](crate::JigglyBoard::step_column) for a board whose
+/// slimes all have a `mass` of 1.0 and take `physprop` as it is, on flat ground without any groups, but
+/// without a call per slime. `idle_clock` is the board's
+/// [`JigglyBoard::idle_clock`](crate::JigglyBoard::idle_clock). A `dt` that `run_physics` wouldn't step
+/// with leaves the slices as they are, `outs` included, and just reports whether the column is at rest.
+///
+/// # Panics
+///
+/// If the slices aren't all the same length.
+pub fn step_column_slice<S: Scalar>(
+    col_index: usize,
+    states: &mut [SlimeState<S>],
+    y_bottoms: &mut [S],
+    outs: &mut [RenderProps<S>],
+    dt: S,
+    idle_clock: S,
+    physprop: &PhysicsProperties<S>,
+) -> ColumnResult<S> {
+    assert!(
+        states.len() == y_bottoms.len() && states.len() == outs.len(),
+        "step_column_slice needs a y_bottom and an out for every state"
+    );
+    pending::assert_valid(physprop);
+    if !pending::steppable(dt) {
+        let at_rest = states.iter().all(SlimeState::is_at_rest);
+        return ColumnResult {
+            settled: at_rest,
+            at_rest,
+            ..ColumnResult::default()
+        };
+    }
+    let ctx = StepContext {
+        dt,
+        idle_phase: S::TAU * physprop.idle_wobble_frequency * idle_clock,
+        col_index,
+    };
+    let mut result = ColumnResult {
+        settled: true,
+        at_rest: true,
+        ..ColumnResult::default()
+    };
+    let mut jiggle_offset = S::ZERO;
+    let mut falling_below = None;
+    let slimes = states.iter_mut().zip(y_bottoms.iter_mut()).zip(outs);
+    for (index, ((state, y_bottom), render)) in slimes.enumerate() {
+        let mut outcome = StepOutcome::default();
+        let props_in = SlimePropsIn {
+            // Only ever left `Settled` for the moment it takes to step it
+            state: core::mem::replace(state, SlimeState::Settled),
+            y_bottom: *y_bottom,
+        };
+        let out = step::step_slime(
+            props_in,
+            &mut jiggle_offset,
+            &mut falling_below,
+            S::ONE,
+            physprop,
+            &ctx,
+            &mut outcome,
+        );
+        *render = RenderProps::from(&out);
+        result.at_rest &= out.state.is_at_rest();
+        *state = out.state;
+        *y_bottom = out.y_bottom;
+        if outcome.active {
+            result.settled = false;
+        }
+        if outcome.popped {
+            result.popped.push(index);
+        }
+        if outcome.merged {
+            result.merged.push(index);
+        }
+        if let Some(x_velocity) = outcome.slid_across {
+            result.slides.push((index, x_velocity));
+        }
+        if let Some(impulse) = outcome.landing {
+            result.landings.push((index, impulse));
+        }
+        if outcome.landing.is_some() || outcome.slid_across.is_some() || outcome.merged {
+            result.settled = false;
+            result.at_rest = false;
+        }
+    }
+    result
+}
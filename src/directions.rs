@@ -0,0 +1,186 @@
+//! Ready-made [`Direction`]s for the usual board shapes.
+
+use crate::Direction;
+
+/// A [`Direction`] between squares of a grid, for [`DenseGridBoard`](crate::DenseGridBoard).
+pub trait GridDirection: Direction + Copy + PartialEq {
+    /// How far the neighbour this way is, in cells rightwards and upwards.
+    fn grid_offset(self) -> (isize, isize);
+}
+
+/// Up, down, left and right, for square grids.
+///
+/// See [`DenseGridBoard`](crate::DenseGridBoard) for a board that uses it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cardinal4 {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Cardinal4 {
+    /// The opposite of [`Direction::UP`], as a constant for code that names directions rather than
+    /// matching on them.
+    pub const DOWN: Cardinal4 = Cardinal4::Down;
+    pub const LEFT: Cardinal4 = Cardinal4::Left;
+    /// The direction itself, where `<Cardinal4 as Direction>::RIGHT` is the same thing in a `Some`.
+    pub const RIGHT: Cardinal4 = Cardinal4::Right;
+    pub const ALL: [Cardinal4; 4] = [
+        Cardinal4::Up,
+        Cardinal4::Down,
+        Cardinal4::Left,
+        Cardinal4::Right,
+    ];
+}
+
+impl Direction for Cardinal4 {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        Cardinal4::ALL.into_iter().filter(move |&dir| dir != self)
+    }
+    fn opposite(self) -> Self {
+        match self {
+            Cardinal4::Up => Cardinal4::Down,
+            Cardinal4::Down => Cardinal4::Up,
+            Cardinal4::Left => Cardinal4::Right,
+            Cardinal4::Right => Cardinal4::Left,
+        }
+    }
+    const UP: Self = Cardinal4::Up;
+    const RIGHT: Option<Self> = Some(Cardinal4::Right);
+}
+
+impl GridDirection for Cardinal4 {
+    fn grid_offset(self) -> (isize, isize) {
+        match self {
+            Cardinal4::Up => (0, 1),
+            Cardinal4::Down => (0, -1),
+            Cardinal4::Left => (-1, 0),
+            Cardinal4::Right => (1, 0),
+        }
+    }
+}
+
+/// The eight neighbours of a square, diagonals included, so waves spread out in rings rather than
+/// diamonds.
+///
+/// A landing arrives from `Up`, so it heads out in the other seven, `UpLeft` and `UpRight` included.
+/// Each neighbour it reaches is also a neighbour of some of the others, so a wave can reach a slime by
+/// more than one path; use `CycleGuard::VisitOnce` or `WaveMode::Coalesced` to reach each once.
+///
+/// A diagonal is √2 cells long rather than 1, so its transfer should be weaker to keep the rings round,
+/// about the straight transfer to the power of √2: [`DenseGridBoard`](crate::DenseGridBoard) multiplies
+/// impulses going diagonally by its `transfer_diagonal`, 0.375 to its 0.5 straight to begin with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Diag8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Diag8 {
+    /// Clockwise from `Up`.
+    pub const ALL: [Diag8; 8] = [
+        Diag8::Up,
+        Diag8::UpRight,
+        Diag8::Right,
+        Diag8::DownRight,
+        Diag8::Down,
+        Diag8::DownLeft,
+        Diag8::Left,
+        Diag8::UpLeft,
+    ];
+
+    pub fn is_diagonal(self) -> bool {
+        let (dx, dy) = self.grid_offset();
+        dx != 0 && dy != 0
+    }
+}
+
+impl Direction for Diag8 {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        Diag8::ALL.into_iter().filter(move |&dir| dir != self)
+    }
+    fn opposite(self) -> Self {
+        match self {
+            Diag8::Up => Diag8::Down,
+            Diag8::UpRight => Diag8::DownLeft,
+            Diag8::Right => Diag8::Left,
+            Diag8::DownRight => Diag8::UpLeft,
+            Diag8::Down => Diag8::Up,
+            Diag8::DownLeft => Diag8::UpRight,
+            Diag8::Left => Diag8::Right,
+            Diag8::UpLeft => Diag8::DownRight,
+        }
+    }
+    const UP: Self = Diag8::Up;
+    const RIGHT: Option<Self> = Some(Diag8::Right);
+    fn points_right(self) -> bool {
+        self.grid_offset().0 > 0
+    }
+}
+
+impl GridDirection for Diag8 {
+    fn grid_offset(self) -> (isize, isize) {
+        match self {
+            Diag8::Up => (0, 1),
+            Diag8::UpRight => (1, 1),
+            Diag8::Right => (1, 0),
+            Diag8::DownRight => (1, -1),
+            Diag8::Down => (0, -1),
+            Diag8::DownLeft => (-1, -1),
+            Diag8::Left => (-1, 0),
+            Diag8::UpLeft => (-1, 1),
+        }
+    }
+}
+
+/// The directions `D` within a layer of a [`LayeredGridBoard`](crate::LayeredGridBoard), plus the two
+/// towards the layers in front of and behind it.
+///
+/// Impulses arriving from another layer squash a slime, like ones arriving from above or below.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layered<D> {
+    Within(D),
+    /// Towards layer 0.
+    Front,
+    /// Away from layer 0.
+    Back,
+}
+
+impl<D: Direction + Copy + PartialEq> Direction for Layered<D> {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        let within = core::iter::once(D::UP)
+            .chain(D::UP.other_directions())
+            .map(Layered::Within);
+        within
+            .chain([Layered::Front, Layered::Back])
+            .filter(move |&dir| dir != self)
+    }
+    fn opposite(self) -> Self {
+        match self {
+            Layered::Within(dir) => Layered::Within(dir.opposite()),
+            Layered::Front => Layered::Back,
+            Layered::Back => Layered::Front,
+        }
+    }
+    const UP: Self = Layered::Within(D::UP);
+    const RIGHT: Option<Self> = match D::RIGHT {
+        Some(dir) => Some(Layered::Within(dir)),
+        None => None,
+    };
+    fn points_right(self) -> bool {
+        matches!(self, Layered::Within(dir) if dir.points_right())
+    }
+    fn squashes(self) -> bool {
+        match self {
+            Layered::Within(dir) => dir.squashes(),
+            Layered::Front | Layered::Back => true,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Printing what a board's slimes are doing, for when one misbehaves.
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::{JigglyBoard, Scalar, SlimeState};
+
+/// One character for `state`: a digit for how far a jiggling slime is squashed or leaning, in tenths of
+/// a cell, or `~` if it's less than half a tenth.
+fn glyph<S: Scalar>(state: &SlimeState<S>) -> char {
+    match *state {
+        SlimeState::Settled => '.',
+        SlimeState::AboutToFall { .. } => '!',
+        SlimeState::Falling { .. } => 'v',
+        SlimeState::Bouncing { .. } => 'b',
+        SlimeState::Jiggling {
+            offset, offset_x, ..
+        } => {
+            let tenths = offset.abs().max(offset_x.abs()) * S::from_f64(10.0) + S::from_f64(0.5);
+            match tenths.to_u32().min(9) {
+                0 => '~',
+                digit => char::from_digit(digit, 10).unwrap_or('9'),
+            }
+        }
+        SlimeState::Launched { .. } => '^',
+        SlimeState::Squished { .. } => '_',
+        SlimeState::Spawning { .. } => '+',
+        SlimeState::Popping { .. } => '*',
+        SlimeState::Frozen => '#',
+        SlimeState::Held { .. } => 'H',
+        SlimeState::Sliding { .. } => '>',
+        SlimeState::Swapping { .. } => 's',
+        SlimeState::Merging { .. } => 'm',
+    }
+}
+
+pub(crate) fn dump<S: Scalar, B: JigglyBoard<S> + ?Sized>(board: &B, verbose: bool) -> String {
+    let mut out = String::new();
+    for (column, locations) in board.cols().enumerate() {
+        if verbose {
+            let _ = writeln!(out, "column {column}:");
+            for (slot, location) in locations.enumerate() {
+                let _ = match board
+                    .peek_slime(location, |state, y_bottom| (glyph(state), *state, y_bottom))
+                {
+                    Some((glyph, state, y_bottom)) => {
+                        writeln!(out, "  {slot} {glyph} y_bottom {y_bottom:?} {state:?}")
+                    }
+                    None => writeln!(out, "  {slot} ?"),
+                };
+            }
+        } else {
+            out.extend(locations.map(|location| {
+                board
+                    .peek_slime(location, |state, _| glyph(state))
+                    .unwrap_or('?')
+            }));
+            out.push('\n');
+        }
+    }
+    out
+}
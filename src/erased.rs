@@ -0,0 +1,356 @@
+//! Object-safe mirrors of the board traits, for boards kept behind `dyn`.
+//!
+//! Every generic trait here has a blanket implementation of its mirror, so there's nothing extra to
+//! implement: a `Box<dyn JigglyBoardDyn<Loc = L, Dir = D>>` can be made from any [`JigglyBoard`], and the
+//! trait object is itself a [`JigglyBoard`] again, so it can be stepped like any other.
+
+use alloc::vec::Vec;
+
+use crate::{
+    ColumnJob, Direction, GravityDirection, GroupId, JiggleImpulsable, JigglyBoard, Material,
+    MutSlime, PhysicsProperties, RenderProps, Scalar, SlimePropsIn, SlimePropsOut, SlimeState,
+};
+
+/// Pass a `FnOnce` through a `dyn FnMut`, which is only ever meant to be called the once.
+fn once<A, R>(f: impl FnOnce(A) -> R) -> impl FnMut(A) -> R {
+    let mut f = Some(f);
+    move |arg| match f.take() {
+        Some(f) => f(arg),
+        None => panic!("slime callback called more than once"),
+    }
+}
+
+/// [`MutSlime`], with the callback behind `dyn`. `f` must be called exactly once.
+pub trait MutSlimeDyn<S = f32> {
+    fn modify_slime_dyn(&mut self, f: &mut dyn FnMut(SlimePropsIn<S>) -> SlimePropsOut<S>);
+}
+
+impl<S, T: MutSlime<S>> MutSlimeDyn<S> for T {
+    fn modify_slime_dyn(&mut self, f: &mut dyn FnMut(SlimePropsIn<S>) -> SlimePropsOut<S>) {
+        self.modify_slime(f);
+    }
+}
+
+impl<S> MutSlime<S> for dyn MutSlimeDyn<S> + '_ {
+    fn modify_slime(&mut self, f: impl FnOnce(SlimePropsIn<S>) -> SlimePropsOut<S>) {
+        self.modify_slime_dyn(&mut once(f));
+    }
+}
+
+/// [`JiggleImpulsable`], with the callback behind `dyn`. `f` must be called exactly once.
+pub trait JiggleImpulsableDyn<S = f32> {
+    fn modify_state_dyn(&mut self, f: &mut dyn FnMut(SlimeState<S>) -> SlimeState<S>);
+}
+
+impl<S, T: JiggleImpulsable<S>> JiggleImpulsableDyn<S> for T {
+    fn modify_state_dyn(&mut self, f: &mut dyn FnMut(SlimeState<S>) -> SlimeState<S>) {
+        self.modify_state(f);
+    }
+}
+
+impl<S> JiggleImpulsable<S> for dyn JiggleImpulsableDyn<S> + '_ {
+    fn modify_state(&mut self, f: impl FnOnce(SlimeState<S>) -> SlimeState<S>) {
+        self.modify_state_dyn(&mut once(f));
+    }
+}
+
+/// The hooks of [`JigglyBoard`] in a form that can be used as `dyn JigglyBoardDyn<Loc = L, Dir = D>`.
+///
+/// Implemented for every `JigglyBoard`, so implement that rather than this. Each method is the
+/// `JigglyBoard` one of the same name without `_dyn`, and callbacks must be called exactly once. Only these
+/// hooks get through: a board that overrides one of `JigglyBoard`'s stepping methods, like
+/// `propagate_jiggle`, gets the default behind `dyn`. Its columns are collected into a `Vec` every step.
+pub trait JigglyBoardDyn<S: Scalar = f32> {
+    type Dir: Direction + Copy + PartialEq;
+    type Loc: Copy;
+    fn apply_dir_to_loc_dyn(
+        &self,
+        dir: Self::Dir,
+        loc: Self::Loc,
+        impulse: S,
+    ) -> Option<(Self::Loc, S)>;
+    /// Calls `out` for every branch rather than extending a collection.
+    fn apply_dir_to_loc_multi_dyn(
+        &self,
+        dir: Self::Dir,
+        loc: Self::Loc,
+        impulse: S,
+        out: &mut dyn FnMut(Self::Loc, S),
+    );
+    /// Calls `f` with each column in turn.
+    fn cols_dyn(&self, f: &mut dyn FnMut(&mut dyn Iterator<Item = Self::Loc>));
+    /// Calls `f` with each row in turn.
+    fn rows_dyn(&self, f: &mut dyn FnMut(&mut dyn Iterator<Item = Self::Loc>));
+    fn neighbor_dyn(&self, loc: Self::Loc, dir: Self::Dir) -> Option<Self::Loc>;
+    fn mut_slime_with_dyn(
+        &mut self,
+        loc: Self::Loc,
+        f: &mut dyn FnMut(SlimePropsIn<S>) -> SlimePropsOut<S>,
+    );
+    fn impulse_jiggle_with_dyn(
+        &mut self,
+        loc: Self::Loc,
+        f: &mut dyn FnMut(SlimeState<S>) -> SlimeState<S>,
+    );
+    fn peek_slime_dyn(&self, loc: Self::Loc, f: &mut dyn FnMut(&SlimeState<S>, S)) -> bool;
+    fn mass_dyn(&self, loc: Self::Loc) -> S;
+    fn gap_before_dyn(&self, loc: Self::Loc) -> S;
+    fn column_base_dyn(&self, col_index: usize) -> S;
+    fn cell_material_dyn(&self, loc: Self::Loc) -> Material<S>;
+    fn props_for_dyn(&self, loc: Self::Loc, base: &PhysicsProperties<S>) -> PhysicsProperties<S>;
+    fn idle_clock_dyn(&self) -> S;
+    fn on_popped_dyn(&mut self, loc: Self::Loc);
+    fn on_merged_dyn(&mut self, from: Self::Loc) -> Option<(Self::Loc, Self::Dir)>;
+    fn try_slide_dyn(&mut self, from: Self::Loc, positive: bool) -> Option<(Self::Loc, Self::Dir)>;
+    fn exchange_slimes_dyn(&mut self, a: Self::Loc, b: Self::Loc) -> Option<(S, S)>;
+    fn remove_slime_dyn(&mut self, loc: Self::Loc) -> bool;
+    fn take_slime_dyn(&mut self, loc: Self::Loc) -> Option<(SlimeState<S>, S)>;
+    fn set_slime_dyn(&mut self, loc: Self::Loc, state: SlimeState<S>, y_bottom: S) -> bool;
+    fn push_bottom_row_dyn(
+        &mut self,
+        row: &mut dyn Iterator<Item = SlimeState<S>>,
+        lift: S,
+    ) -> bool;
+    fn group_of_dyn(&self, loc: Self::Loc) -> Option<GroupId>;
+    fn loc_index_dyn(&self, loc: Self::Loc) -> Option<usize>;
+    fn column_dirty_hint_dyn(&self, col_index: usize) -> bool;
+    fn on_previous_output_dyn(&mut self, loc: Self::Loc, prev: RenderProps<S>);
+    fn on_column_at_rest_dyn(&mut self, col_index: usize);
+    fn par_columns_mut_dyn(&mut self, jobs: &mut [ColumnJob<Self::Loc, S>]);
+    fn gravity_dir_dyn(&self) -> GravityDirection;
+}
+
+/// Adds everything it's extended with to a callback.
+struct Sink<'a, Loc, S>(&'a mut dyn FnMut(Loc, S));
+
+impl<Loc, S> Extend<(Loc, S)> for Sink<'_, Loc, S> {
+    fn extend<I: IntoIterator<Item = (Loc, S)>>(&mut self, iter: I) {
+        for (loc, impulse) in iter {
+            (self.0)(loc, impulse);
+        }
+    }
+}
+
+impl<S: Scalar, B: JigglyBoard<S>> JigglyBoardDyn<S> for B {
+    type Dir = B::Dir;
+    type Loc = B::Loc;
+    fn apply_dir_to_loc_dyn(
+        &self,
+        dir: Self::Dir,
+        loc: Self::Loc,
+        impulse: S,
+    ) -> Option<(Self::Loc, S)> {
+        self.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn apply_dir_to_loc_multi_dyn(
+        &self,
+        dir: Self::Dir,
+        loc: Self::Loc,
+        impulse: S,
+        out: &mut dyn FnMut(Self::Loc, S),
+    ) {
+        self.apply_dir_to_loc_multi(dir, loc, impulse, &mut Sink(out));
+    }
+    fn cols_dyn(&self, f: &mut dyn FnMut(&mut dyn Iterator<Item = Self::Loc>)) {
+        for mut col in self.cols() {
+            f(&mut col);
+        }
+    }
+    fn rows_dyn(&self, f: &mut dyn FnMut(&mut dyn Iterator<Item = Self::Loc>)) {
+        for mut row in self.rows() {
+            f(&mut row);
+        }
+    }
+    fn neighbor_dyn(&self, loc: Self::Loc, dir: Self::Dir) -> Option<Self::Loc> {
+        self.neighbor(loc, dir)
+    }
+    fn mut_slime_with_dyn(
+        &mut self,
+        loc: Self::Loc,
+        f: &mut dyn FnMut(SlimePropsIn<S>) -> SlimePropsOut<S>,
+    ) {
+        self.mut_slime_with(loc, f);
+    }
+    fn impulse_jiggle_with_dyn(
+        &mut self,
+        loc: Self::Loc,
+        f: &mut dyn FnMut(SlimeState<S>) -> SlimeState<S>,
+    ) {
+        self.impulse_jiggle_with(loc, f);
+    }
+    fn peek_slime_dyn(&self, loc: Self::Loc, f: &mut dyn FnMut(&SlimeState<S>, S)) -> bool {
+        self.peek_slime(loc, f).is_some()
+    }
+    fn mass_dyn(&self, loc: Self::Loc) -> S {
+        self.mass(loc)
+    }
+    fn gap_before_dyn(&self, loc: Self::Loc) -> S {
+        self.gap_before(loc)
+    }
+    fn column_base_dyn(&self, col_index: usize) -> S {
+        self.column_base(col_index)
+    }
+    fn cell_material_dyn(&self, loc: Self::Loc) -> Material<S> {
+        self.cell_material(loc)
+    }
+    fn props_for_dyn(&self, loc: Self::Loc, base: &PhysicsProperties<S>) -> PhysicsProperties<S> {
+        self.props_for(loc, base)
+    }
+    fn idle_clock_dyn(&self) -> S {
+        self.idle_clock()
+    }
+    fn on_popped_dyn(&mut self, loc: Self::Loc) {
+        self.on_popped(loc);
+    }
+    fn on_merged_dyn(&mut self, from: Self::Loc) -> Option<(Self::Loc, Self::Dir)> {
+        self.on_merged(from)
+    }
+    fn try_slide_dyn(&mut self, from: Self::Loc, positive: bool) -> Option<(Self::Loc, Self::Dir)> {
+        self.try_slide(from, positive)
+    }
+    fn exchange_slimes_dyn(&mut self, a: Self::Loc, b: Self::Loc) -> Option<(S, S)> {
+        self.exchange_slimes(a, b)
+    }
+    fn remove_slime_dyn(&mut self, loc: Self::Loc) -> bool {
+        self.remove_slime(loc)
+    }
+    fn take_slime_dyn(&mut self, loc: Self::Loc) -> Option<(SlimeState<S>, S)> {
+        self.take_slime(loc)
+    }
+    fn set_slime_dyn(&mut self, loc: Self::Loc, state: SlimeState<S>, y_bottom: S) -> bool {
+        self.set_slime(loc, state, y_bottom)
+    }
+    fn push_bottom_row_dyn(
+        &mut self,
+        row: &mut dyn Iterator<Item = SlimeState<S>>,
+        lift: S,
+    ) -> bool {
+        self.push_bottom_row(row, lift)
+    }
+    fn group_of_dyn(&self, loc: Self::Loc) -> Option<GroupId> {
+        self.group_of(loc)
+    }
+    fn loc_index_dyn(&self, loc: Self::Loc) -> Option<usize> {
+        self.loc_index(loc)
+    }
+    fn column_dirty_hint_dyn(&self, col_index: usize) -> bool {
+        self.column_dirty_hint(col_index)
+    }
+    fn on_previous_output_dyn(&mut self, loc: Self::Loc, prev: RenderProps<S>) {
+        self.on_previous_output(loc, prev);
+    }
+    fn on_column_at_rest_dyn(&mut self, col_index: usize) {
+        self.on_column_at_rest(col_index);
+    }
+    fn par_columns_mut_dyn(&mut self, jobs: &mut [ColumnJob<Self::Loc, S>]) {
+        self.par_columns_mut(jobs);
+    }
+    fn gravity_dir_dyn(&self) -> GravityDirection {
+        self.gravity_dir()
+    }
+}
+
+impl<S: Scalar, L: Copy, D: Direction + Copy + PartialEq> JigglyBoard<S>
+    for dyn JigglyBoardDyn<S, Loc = L, Dir = D> + '_
+{
+    type Dir = D;
+    type Loc = L;
+    fn apply_dir_to_loc(&self, dir: D, loc: L, impulse: S) -> Option<(L, S)> {
+        self.apply_dir_to_loc_dyn(dir, loc, impulse)
+    }
+    fn apply_dir_to_loc_multi(&self, dir: D, loc: L, impulse: S, out: &mut impl Extend<(L, S)>) {
+        self.apply_dir_to_loc_multi_dyn(dir, loc, impulse, &mut |to, impulse| {
+            out.extend([(to, impulse)])
+        });
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = L>> {
+        let mut cols = Vec::new();
+        self.cols_dyn(&mut |col| cols.push(col.collect::<Vec<_>>()));
+        cols.into_iter().map(Vec::into_iter)
+    }
+    fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = L>> {
+        let mut rows = Vec::new();
+        self.rows_dyn(&mut |row| rows.push(row.collect::<Vec<_>>()));
+        rows.into_iter().map(Vec::into_iter)
+    }
+    fn neighbor(&self, loc: L, dir: D) -> Option<L> {
+        self.neighbor_dyn(loc, dir)
+    }
+    fn mut_slime_with(&mut self, loc: L, f: impl FnOnce(SlimePropsIn<S>) -> SlimePropsOut<S>) {
+        self.mut_slime_with_dyn(loc, &mut once(f));
+    }
+    fn impulse_jiggle_with(&mut self, loc: L, f: impl FnOnce(SlimeState<S>) -> SlimeState<S>) {
+        self.impulse_jiggle_with_dyn(loc, &mut once(f));
+    }
+    fn peek_slime<R>(&self, loc: L, f: impl FnOnce(&SlimeState<S>, S) -> R) -> Option<R> {
+        let mut f = Some(f);
+        let mut out = None;
+        self.peek_slime_dyn(loc, &mut |state, y_bottom| {
+            out = f.take().map(|f| f(state, y_bottom));
+        });
+        out
+    }
+    fn mass(&self, loc: L) -> S {
+        self.mass_dyn(loc)
+    }
+    fn gap_before(&self, loc: L) -> S {
+        self.gap_before_dyn(loc)
+    }
+    fn column_base(&self, col_index: usize) -> S {
+        self.column_base_dyn(col_index)
+    }
+    fn cell_material(&self, loc: L) -> Material<S> {
+        self.cell_material_dyn(loc)
+    }
+    fn props_for(&self, loc: L, base: &PhysicsProperties<S>) -> PhysicsProperties<S> {
+        self.props_for_dyn(loc, base)
+    }
+    fn idle_clock(&self) -> S {
+        self.idle_clock_dyn()
+    }
+    fn on_popped(&mut self, loc: L) {
+        self.on_popped_dyn(loc);
+    }
+    fn on_merged(&mut self, from: L) -> Option<(L, D)> {
+        self.on_merged_dyn(from)
+    }
+    fn try_slide(&mut self, from: L, positive: bool) -> Option<(L, D)> {
+        self.try_slide_dyn(from, positive)
+    }
+    fn exchange_slimes(&mut self, a: L, b: L) -> Option<(S, S)> {
+        self.exchange_slimes_dyn(a, b)
+    }
+    fn remove_slime(&mut self, loc: L) -> bool {
+        self.remove_slime_dyn(loc)
+    }
+    fn take_slime(&mut self, loc: L) -> Option<(SlimeState<S>, S)> {
+        self.take_slime_dyn(loc)
+    }
+    fn set_slime(&mut self, loc: L, state: SlimeState<S>, y_bottom: S) -> bool {
+        self.set_slime_dyn(loc, state, y_bottom)
+    }
+    fn push_bottom_row(&mut self, mut row: impl Iterator<Item = SlimeState<S>>, lift: S) -> bool {
+        self.push_bottom_row_dyn(&mut row, lift)
+    }
+    fn group_of(&self, loc: L) -> Option<GroupId> {
+        self.group_of_dyn(loc)
+    }
+    fn loc_index(&self, loc: L) -> Option<usize> {
+        self.loc_index_dyn(loc)
+    }
+    fn column_dirty_hint(&self, col_index: usize) -> bool {
+        self.column_dirty_hint_dyn(col_index)
+    }
+    fn on_previous_output(&mut self, loc: L, prev: RenderProps<S>) {
+        self.on_previous_output_dyn(loc, prev);
+    }
+    fn on_column_at_rest(&mut self, col_index: usize) {
+        self.on_column_at_rest_dyn(col_index);
+    }
+    fn par_columns_mut(&mut self, jobs: &mut [ColumnJob<L, S>]) {
+        self.par_columns_mut_dyn(jobs);
+    }
+    fn gravity_dir(&self) -> GravityDirection {
+        self.gravity_dir_dyn()
+    }
+}
@@ -0,0 +1,112 @@
+use core::fmt;
+
+/// Why a set of [`PhysicsProperties`](crate::PhysicsProperties) was rejected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PhysicsPropertiesError {
+    /// The field is NaN or infinite.
+    NotFinite { field: &'static str, value: f32 },
+    /// The field must be strictly greater than zero.
+    NotPositive { field: &'static str, value: f32 },
+    /// The field must not be below zero.
+    Negative { field: &'static str, value: f32 },
+    /// The field is outside the range `min..max`. Whether the ends are allowed depends on the field.
+    OutOfRange {
+        field: &'static str,
+        value: f32,
+        min: f32,
+        max: f32,
+    },
+    /// `jiggle_life_threshold_inverse` isn't `1.0 / jiggle_life_threshold`.
+    InverseMismatch { expected: f32, actual: f32 },
+}
+
+impl PhysicsPropertiesError {
+    /// The name of the offending field.
+    pub fn field(&self) -> &'static str {
+        use PhysicsPropertiesError::*;
+        match self {
+            NotFinite { field, .. }
+            | NotPositive { field, .. }
+            | Negative { field, .. }
+            | OutOfRange { field, .. } => field,
+            InverseMismatch { .. } => "jiggle_life_threshold_inverse",
+        }
+    }
+}
+
+impl fmt::Display for PhysicsPropertiesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use PhysicsPropertiesError::*;
+        match self {
+            NotFinite { field, value } => write!(f, "{field} must be finite, got {value}"),
+            NotPositive { field, value } => write!(f, "{field} must be positive, got {value}"),
+            Negative { field, value } => write!(f, "{field} must not be negative, got {value}"),
+            OutOfRange {
+                field,
+                value,
+                min,
+                max,
+            } => write!(f, "{field} must be between {min} and {max}, got {value}"),
+            InverseMismatch { expected, actual } => write!(
+                f,
+                "jiggle_life_threshold_inverse should be {expected}, got {actual}"
+            ),
+        }
+    }
+}
+
+/// Why a [`BoardLayoutCache`](crate::BoardLayoutCache) no longer fits its board, which has changed shape
+/// since it was filled without it being invalidated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaleLayout {
+    /// The board has a different number of columns.
+    ColumnCount { cached: usize, actual: usize },
+    /// The `column`th column holds a different number of slimes.
+    ColumnLength {
+        column: usize,
+        cached: usize,
+        actual: usize,
+    },
+}
+
+impl fmt::Display for StaleLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StaleLayout::ColumnCount { cached, actual } => write!(
+                f,
+                "the board has {actual} columns but its layout cache has {cached}"
+            ),
+            StaleLayout::ColumnLength {
+                column,
+                cached,
+                actual,
+            } => write!(
+                f,
+                "column {column} has {actual} slimes but the layout cache has {cached}"
+            ),
+        }
+    }
+}
+
+/// Why [`RollbackBuffer::rollback_to`](crate::RollbackBuffer::rollback_to) couldn't.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollbackError {
+    /// The frame is older than every frame the buffer still has, so if it was saved it's been thrown away.
+    Evicted { frame: u64, oldest: u64 },
+    /// The frame was never saved, or was thrown away by rolling back past it.
+    NotSaved { frame: u64 },
+}
+
+impl fmt::Display for RollbackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RollbackError::Evicted { frame, oldest } => write!(
+                f,
+                "frame {frame} is gone from the rollback buffer, the oldest left is {oldest}"
+            ),
+            RollbackError::NotSaved { frame } => {
+                write!(f, "frame {frame} isn't in the rollback buffer")
+            }
+        }
+    }
+}
@@ -0,0 +1,641 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::RngSource;
+use crate::impulse::{self, Impulse};
+#[cfg(feature = "alloc")]
+use crate::shake;
+use crate::{
+    Direction, GravityDirection, JigglePropagation, JigglyBoard, PhysicsProperties, Scalar,
+    SlimeState,
+};
+
+/// Helpers for driving slimes into particular states, available on every [`JigglyBoard`].
+pub trait JigglyBoardExt<S: Scalar = f32>: JigglyBoard<S> {
+    /// Pop the slime at `loc` up out of its slot at `upward_velocity` cells per second.
+    ///
+    /// It arcs under gravity, keeping its slot reserved, and lands back in it with a jiggle.
+    /// Slimes that are already in the air are left alone.
+    fn launch(&mut self, loc: Self::Loc, upward_velocity: S) {
+        self.impulse_jiggle_with(loc, |state| match state {
+            SlimeState::Settled | SlimeState::Jiggling { .. } => SlimeState::Launched {
+                velocity: upward_velocity,
+                height: S::ZERO,
+            },
+            state => state,
+        });
+    }
+    /// Send a jiggle wave of `value` out from the slime at `loc`, as if it had arrived from `dir`.
+    ///
+    /// Positive squashes, like a landing. Negative starts it stretching instead, e.g. for something
+    /// slamming into it from below, and the wave carries the stretch on to its neighbours.
+    fn impulse_signed(
+        &mut self,
+        loc: Self::Loc,
+        value: S,
+        dir: Self::Dir,
+        physprop: &PhysicsProperties<S>,
+    ) {
+        self.propagate_jiggle(
+            JigglePropagation {
+                at: loc,
+                impulse: value,
+                came_from: dir,
+                hops: 0,
+            },
+            physprop,
+        );
+    }
+    /// Hit the slime at `loc` with `impulse` from `from`, e.g. when the player taps it, and send the
+    /// ripple out the same way a landing there would: the usual wave, plus `propagate_up_factor` of it
+    /// back the way it came. A landing comes from `Direction::UP`, or its opposite when gravity is up.
+    ///
+    /// `impulse` is sent out as it is, like a landing's once that's been multiplied by the landing slime's
+    /// mass, so nothing here multiplies it by a mass. Each slime it reaches, `loc` included, divides what
+    /// arrives by its own [`JigglyBoard::mass`], as with any jiggle propagation.
+    #[cfg(feature = "alloc")]
+    fn apply_impulse_at(
+        &mut self,
+        loc: Self::Loc,
+        impulse: S,
+        from: Self::Dir,
+        physprop: &PhysicsProperties<S>,
+    ) {
+        let mut propagations = Vec::with_capacity(2);
+        propagations.push(JigglePropagation {
+            at: loc,
+            impulse,
+            came_from: from,
+            hops: 0,
+        });
+        let up_factor = self.props_for(loc, physprop).propagate_up_factor;
+        if up_factor > S::ZERO
+            && let Some((back, impulse)) = self.apply_dir_to_loc(from, loc, impulse * up_factor)
+        {
+            propagations.push(JigglePropagation {
+                at: back,
+                impulse,
+                came_from: from.opposite(),
+                hops: 1,
+            });
+        }
+        self.apply_propagations(propagations, physprop);
+    }
+    /// Blast every slime around `center` with `impulse`, less the further away it is, e.g. for a bomb.
+    ///
+    /// The blast spreads out a ring of neighbours at a time through `apply_dir_to_loc_multi`, each hop
+    /// multiplying it by what the board does to a jiggle passing that way and by the `transfer_for` that
+    /// way of the slime it leaves, and each slime it reaches is hit once, from the side facing `center`,
+    /// without setting off waves of its own. Each slime's [`JigglyBoard::cell_material`] soaks up or adds
+    /// to it as it arrives, as with any jiggle. It stops at frozen slimes, once it's below
+    /// `PhysicsProperties::min_impactable`, and after `max_propagation_hops`.
+    ///
+    /// Slimes already hit are looked up by [`JigglyBoard::loc_index`], or on boards without one, in a list
+    /// of where the blast has been, which takes longer the more slimes it reaches.
+    #[cfg(feature = "alloc")]
+    fn explode_at(&mut self, center: Self::Loc, impulse: S, physprop: &PhysicsProperties<S>)
+    where
+        Self::Loc: PartialEq,
+    {
+        let mut visited = Vec::new();
+        let mut visited_unindexed = Vec::new();
+        let mut branches = Vec::new();
+        let mut next = Vec::new();
+        // The centre is hit from above, and the blast goes every way from it
+        let mut front = Vec::new();
+        front.push((center, impulse, Self::Dir::UP, 0));
+        while !front.is_empty() {
+            for (at, impulse, came_from, hops) in front.drain(..) {
+                let impulse = impulse * self.cell_material(at).factor();
+                if !impulse.is_finite() || impulse.abs() < physprop.min_impactable_at(hops) {
+                    continue;
+                }
+                if let Some(index) = self.loc_index(at) {
+                    if visited.len() <= index {
+                        visited.resize(index + 1, false);
+                    }
+                    if core::mem::replace(&mut visited[index], true) {
+                        continue;
+                    }
+                } else if visited_unindexed.contains(&at) {
+                    continue;
+                } else {
+                    visited_unindexed.push(at);
+                }
+                let props = self.props_for(at, physprop);
+                let arriving = Impulse::arriving(impulse / self.mass(at), came_from);
+                let mut blocked = false;
+                self.impulse_jiggle_with(at, |state| {
+                    impulse::receive(state, arriving, &props, &mut blocked)
+                });
+                if blocked || hops >= physprop.max_propagation_hops {
+                    continue;
+                }
+                let dirs = (hops == 0)
+                    .then_some(came_from)
+                    .into_iter()
+                    .chain(came_from.other_directions());
+                for dir in dirs {
+                    self.apply_dir_to_loc_multi(dir, at, impulse, &mut branches);
+                    let transfer = props.transfer_for(dir);
+                    next.extend(
+                        branches.drain(..).map(|(to, impulse)| {
+                            (to, impulse * transfer, dir.opposite(), hops + 1)
+                        }),
+                    );
+                }
+            }
+            core::mem::swap(&mut front, &mut next);
+        }
+    }
+    /// Set every slime on the board wobbling at once, each with `base_impulse` give or take up to
+    /// `variation`, picked with `rng`, so they don't all move in step. Nothing is passed on to the
+    /// neighbours, since they're all getting their own.
+    ///
+    /// Slimes get it just as if a jiggle had reached them from the side a landing would, above them or
+    /// below them when gravity is up, before mass, so settled ones start jiggling, jiggling ones get it
+    /// added, and held ones bank it. Slimes falling or bouncing are left alone.
+    #[cfg(feature = "alloc")]
+    fn shake(
+        &mut self,
+        base_impulse: S,
+        variation: S,
+        rng: &mut impl RngSource,
+        physprop: &PhysicsProperties<S>,
+    ) {
+        shake::shake(self, base_impulse, variation, rng, false, physprop);
+    }
+    /// Like [`JigglyBoardExt::shake`], but every other slime up each column gets it the other way,
+    /// starting the other way in every other column, so on a full grid neighbours squash and stretch
+    /// in turn.
+    #[cfg(feature = "alloc")]
+    fn shake_alternating(
+        &mut self,
+        base_impulse: S,
+        variation: S,
+        rng: &mut impl RngSource,
+        physprop: &PhysicsProperties<S>,
+    ) {
+        shake::shake(self, base_impulse, variation, rng, true, physprop);
+    }
+    /// Jiggle just the slime at `loc` with `impulse`, as if a wave had reached it from `dir`, without
+    /// passing any of it on. Boards use it to jolt the slimes next to a change in their shape.
+    fn nudge(
+        &mut self,
+        loc: Self::Loc,
+        impulse: S,
+        dir: Self::Dir,
+        physprop: &PhysicsProperties<S>,
+    ) {
+        let props = self.props_for(loc, physprop);
+        let arriving = Impulse::arriving(impulse / self.mass(loc), dir);
+        self.impulse_jiggle_with(loc, |state| {
+            impulse::receive(state, arriving, &props, &mut false)
+        });
+    }
+    /// Press the slime at `loc` down by `amount` (0 to 1) and hold it there.
+    ///
+    /// Works on settled, jiggling and already squished slimes; anything in the air is left alone.
+    fn squish(&mut self, loc: Self::Loc, amount: S) {
+        let amount = amount.clamp(S::ZERO, S::ONE);
+        self.impulse_jiggle_with(loc, |state| match state {
+            SlimeState::Settled | SlimeState::Jiggling { .. } | SlimeState::Squished { .. } => {
+                SlimeState::Squished { amount }
+            }
+            state => state,
+        });
+    }
+    /// Let go of a squished slime, so it springs back with momentum proportional to how squished it was.
+    fn unsquish(&mut self, loc: Self::Loc, physprop: &PhysicsProperties<S>) {
+        let props = self.props_for(loc, physprop);
+        self.impulse_jiggle_with(loc, |state| match state {
+            SlimeState::Squished { amount } => SlimeState::Jiggling {
+                // Negative so it heads back up, towards a stretch
+                momentum: -amount * props.squish_release_momentum,
+                offset: amount,
+                life: S::ONE,
+                momentum_x: S::ZERO,
+                offset_x: S::ZERO,
+            },
+            state => state,
+        });
+    }
+    /// Grab the slime at `loc`, pausing its physics. Impulses it receives are buffered until it's released.
+    fn hold(&mut self, loc: Self::Loc) {
+        self.impulse_jiggle_with(loc, |state| match state {
+            SlimeState::Held { buffered_impulse } => SlimeState::Held { buffered_impulse },
+            _ => SlimeState::Held {
+                buffered_impulse: S::ZERO,
+            },
+        });
+    }
+    /// Let go of a held slime.
+    ///
+    /// If `supported`, it jiggles with everything it buffered while held as momentum. Otherwise the
+    /// support below is gone, so it drops from wherever it was held, and the buffered impulse is discarded.
+    fn release(&mut self, loc: Self::Loc, supported: bool, physprop: &PhysicsProperties<S>) {
+        let props = self.props_for(loc, physprop);
+        self.impulse_jiggle_with(loc, |state| match state {
+            // Already divided by mass on the way in
+            SlimeState::Held { buffered_impulse } if supported => SlimeState::Jiggling {
+                momentum: buffered_impulse.clamp(-props.max_momentum, props.max_momentum),
+                offset: S::ZERO,
+                life: S::ONE,
+                momentum_x: S::ZERO,
+                offset_x: S::ZERO,
+            },
+            SlimeState::Held { .. } => SlimeState::Falling {
+                velocity: S::ZERO,
+                fallen: S::ZERO,
+            },
+            state => state,
+        });
+    }
+    /// [`JigglyBoardExt::hold`] every resting or jiggling slime in `locs`, so it stays perfectly still
+    /// while waves pass through it, banking whatever reaches it. A jiggle it was already in is banked too.
+    ///
+    /// Anything else, like a slime in the air, is left alone. Held slimes count as at rest, so a board
+    /// that's settled apart from its frozen region says so, and has to be stepped again once it's unfrozen.
+    fn freeze_region(&mut self, locs: impl IntoIterator<Item = Self::Loc>) {
+        for location in locs {
+            self.impulse_jiggle_with(location, |state| match state {
+                SlimeState::Settled => SlimeState::Held {
+                    buffered_impulse: S::ZERO,
+                },
+                SlimeState::Jiggling { momentum, .. } => SlimeState::Held {
+                    buffered_impulse: momentum,
+                },
+                state => state,
+            });
+        }
+    }
+    /// [`JigglyBoardExt::release`] every held slime in `locs`, still supported, so it jiggles with
+    /// everything it banked while [`JigglyBoardExt::freeze_region`] held it.
+    fn unfreeze_region(
+        &mut self,
+        locs: impl IntoIterator<Item = Self::Loc>,
+        physprop: &PhysicsProperties<S>,
+    ) {
+        for location in locs {
+            self.release(location, true, physprop);
+        }
+    }
+    /// Start the slime at `loc` sliding sideways at `x_velocity` cells per second.
+    ///
+    /// Positive is towards the side [`JigglyBoard::try_slide`] calls `positive`.
+    fn shove(&mut self, loc: Self::Loc, x_velocity: S) {
+        self.impulse_jiggle_with(loc, |state| match state {
+            SlimeState::Settled | SlimeState::Jiggling { .. } => SlimeState::Sliding {
+                x_offset: S::ZERO,
+                x_velocity,
+                returning: false,
+            },
+            state => state,
+        });
+    }
+    /// Swap the slimes at `a` and `b` via [`JigglyBoard::exchange_slimes`], animating them past each other
+    /// over `PhysicsProperties::swap_duration` and leaving both jiggling when they arrive.
+    ///
+    /// Returns whether the board agreed to the swap.
+    fn swap(&mut self, a: Self::Loc, b: Self::Loc, physprop: &PhysicsProperties<S>) -> bool {
+        let Some((dx, dy)) = self.exchange_slimes(a, b) else {
+            return false;
+        };
+        // Each now sits where the other started, so it comes from there
+        for (loc, from_x, from_y) in [(a, dx, dy), (b, -dx, -dy)] {
+            let max_momentum = self.props_for(loc, physprop).max_momentum;
+            self.impulse_jiggle_with(loc, |state| SlimeState::Swapping {
+                progress: S::ZERO,
+                from_x,
+                from_y,
+                // Whatever it was already doing carries over into the arrival
+                buffered_impulse: match state {
+                    SlimeState::Jiggling { momentum, .. } => momentum,
+                    SlimeState::Held { buffered_impulse }
+                    | SlimeState::Swapping {
+                        buffered_impulse, ..
+                    } => buffered_impulse,
+                    _ => S::ZERO,
+                }
+                .clamp(-max_momentum, max_momentum),
+            });
+        }
+        true
+    }
+    /// Start the slime at `loc` merging into the neighbour `into_x`, `into_y` cells away (rightwards and
+    /// upwards). See `SlimeState::Merging`.
+    fn merge(&mut self, loc: Self::Loc, into_x: S, into_y: S) {
+        self.impulse_jiggle_with(loc, |state| match state {
+            SlimeState::Settled | SlimeState::Jiggling { .. } | SlimeState::Squished { .. } => {
+                SlimeState::Merging {
+                    progress: S::ZERO,
+                    into_x,
+                    into_y,
+                }
+            }
+            state => state,
+        });
+    }
+    /// Drop the slime at `loc` because its support has gone, honouring `PhysicsProperties::fall_delay`.
+    ///
+    /// With `PhysicsProperties::detach_stretch` set, the slime it was resting on, if any, also gets
+    /// pulled upwards into a stretch as they come apart.
+    fn begin_fall(&mut self, loc: Self::Loc, physprop: &PhysicsProperties<S>) {
+        let props = self.props_for(loc, physprop);
+        self.impulse_jiggle_with(loc, |_| SlimeState::falling(&props));
+        if props.detach_stretch <= S::ZERO {
+            return;
+        }
+        let down = match self.gravity_dir() {
+            GravityDirection::Down => Self::Dir::UP.opposite(),
+            GravityDirection::Up => Self::Dir::UP,
+        };
+        let Some((below, _)) = self.apply_dir_to_loc(down, loc, props.detach_stretch) else {
+            return;
+        };
+        let below_props = self.props_for(below, physprop);
+        let max_momentum = below_props.max_momentum;
+        // Negative so it heads upwards, towards a stretch
+        let pull = -below_props.detach_stretch / self.mass(below);
+        self.impulse_jiggle_with(below, |state| match state {
+            SlimeState::Settled => SlimeState::Jiggling {
+                momentum: pull.max(-max_momentum),
+                offset: S::ZERO,
+                life: S::ONE,
+                momentum_x: S::ZERO,
+                offset_x: S::ZERO,
+            },
+            SlimeState::Jiggling {
+                momentum,
+                offset,
+                life,
+                momentum_x,
+                offset_x,
+            } => SlimeState::Jiggling {
+                momentum: (momentum + pull).clamp(-max_momentum, max_momentum),
+                offset,
+                life,
+                momentum_x,
+                offset_x,
+            },
+            state => state,
+        });
+    }
+    /// Take the slimes at `locs` off the board with [`JigglyBoard::remove_slime`], e.g. for a match,
+    /// returning how many it removed.
+    ///
+    /// Whatever was stacked on them starts falling from where it is, keeping its `y_bottom`, so clearing
+    /// two slimes one on top of the other drops what was above by two cells, once. Every neighbour left
+    /// below and beside them gets `reaction_impulse` sent into it as a jiggle wave; 0 skips that. They're
+    /// removed top first, so a board that moves slimes down into the gap as it removes each one, like
+    /// [`DenseGridBoard`](crate::DenseGridBoard), removes the right ones.
+    #[cfg(feature = "alloc")]
+    fn clear_cells(
+        &mut self,
+        locs: impl IntoIterator<Item = Self::Loc>,
+        physprop: &PhysicsProperties<S>,
+        reaction_impulse: S,
+    ) -> usize
+    where
+        Self::Loc: PartialEq,
+    {
+        let mut cleared: Vec<Self::Loc> = Vec::new();
+        for loc in locs {
+            if !cleared.contains(&loc) {
+                cleared.push(loc);
+            }
+        }
+        let up = match self.gravity_dir() {
+            GravityDirection::Down => Self::Dir::UP,
+            GravityDirection::Up => Self::Dir::UP.opposite(),
+        };
+        let mut above: Vec<Self::Loc> = Vec::new();
+        for &loc in &cleared {
+            let mut next = self.neighbor(loc, up);
+            while let Some(loc) = next {
+                if above.contains(&loc) {
+                    // Already found from a cleared slime lower down
+                    break;
+                }
+                if !cleared.contains(&loc) {
+                    above.push(loc);
+                }
+                next = self.neighbor(loc, up);
+            }
+        }
+        for loc in above {
+            let props = self.props_for(loc, physprop);
+            self.impulse_jiggle_with(loc, |state| match state {
+                SlimeState::Settled | SlimeState::Jiggling { .. } | SlimeState::Squished { .. } => {
+                    SlimeState::falling(&props)
+                }
+                state => state,
+            });
+        }
+        if reaction_impulse != S::ZERO {
+            let mut pokes = Vec::new();
+            for &loc in &cleared {
+                for dir in up.other_directions() {
+                    if let Some((at, impulse)) = self.apply_dir_to_loc(dir, loc, reaction_impulse)
+                        && !cleared.contains(&at)
+                    {
+                        pokes.push(JigglePropagation {
+                            at,
+                            impulse,
+                            came_from: dir.opposite(),
+                            hops: 1,
+                        });
+                    }
+                }
+            }
+            self.apply_propagations(pokes, physprop);
+        }
+        let mut ordered: Vec<(usize, Self::Loc)> = Vec::with_capacity(cleared.len());
+        for col in self.cols() {
+            for (row, loc) in col.enumerate() {
+                if cleared.contains(&loc) {
+                    ordered.push((row, loc));
+                }
+            }
+        }
+        // Top first
+        ordered.sort_by_key(|&(row, _)| row);
+        if self.gravity_dir() == GravityDirection::Down {
+            ordered.reverse();
+        }
+        ordered
+            .into_iter()
+            .filter(|&(_, loc)| self.remove_slime(loc))
+            .count()
+    }
+    /// Teleport the slimes at `a` and `b` into each other's slots with [`JigglyBoard::take_slime`] and
+    /// [`JigglyBoard::set_slime`], states and all. Returns whether both were there to swap; swapping a
+    /// slime with itself does nothing.
+    ///
+    /// A slime in the air stays at the height it was, unless that's below where the slime it swapped
+    /// with was resting, in which case it lands there straight away. Anything else takes the `y_bottom` of
+    /// the slime it swapped with, and drops from there if that was in the air. Each that arrives resting
+    /// gets `arrival_impulse` as if it had landed.
+    #[cfg(feature = "alloc")]
+    fn swap_cells(
+        &mut self,
+        a: Self::Loc,
+        b: Self::Loc,
+        physprop: &PhysicsProperties<S>,
+        arrival_impulse: S,
+    ) -> bool
+    where
+        Self::Loc: PartialEq,
+    {
+        if a == b {
+            return false;
+        }
+        let Some((a_state, a_y)) = self.take_slime(a) else {
+            return false;
+        };
+        let Some((b_state, b_y)) = self.take_slime(b) else {
+            self.set_slime(a, a_state, a_y);
+            return false;
+        };
+        let in_air = |state: &SlimeState<S>| {
+            matches!(
+                state,
+                SlimeState::Falling { .. }
+                    | SlimeState::Bouncing { .. }
+                    | SlimeState::AboutToFall { .. }
+            )
+        };
+        let (a_in_air, b_in_air) = (in_air(&a_state), in_air(&b_state));
+        // Where each ends up, and whether it's resting there
+        let arrive =
+            |state: SlimeState<S>, y: S, in_air: bool, other_in_air: bool, other_y: S| match (
+                in_air,
+                other_in_air,
+            ) {
+                (true, false) if y <= other_y => (SlimeState::landed(), other_y, true),
+                (true, _) => (state, y, false),
+                (false, _) => (state, other_y, true),
+            };
+        let up = match self.gravity_dir() {
+            GravityDirection::Down => Self::Dir::UP,
+            GravityDirection::Up => Self::Dir::UP.opposite(),
+        };
+        let mut arrivals = Vec::new();
+        for (at, (state, y_bottom, resting)) in [
+            (b, arrive(a_state, a_y, a_in_air, b_in_air, b_y)),
+            (a, arrive(b_state, b_y, b_in_air, a_in_air, a_y)),
+        ] {
+            self.set_slime(at, state, y_bottom);
+            if resting && arrival_impulse != S::ZERO {
+                arrivals.push(JigglePropagation {
+                    at,
+                    impulse: arrival_impulse,
+                    came_from: up,
+                    hops: 0,
+                });
+            }
+        }
+        self.apply_propagations(arrivals, physprop);
+        true
+    }
+    /// Push every column up a slot and slot a slime from `row` in under each, like garbage rising from
+    /// below, via [`JigglyBoard::push_bottom_row`]. Returns whether the board made room.
+    ///
+    /// Slimes in the air rise with their columns, so they still land on top of the stack where it is now.
+    /// Each new slime then gets `bump_impulse` from below, stretching it and what's stacked on it like
+    /// something slamming into the stack from underneath; 0 skips that.
+    #[cfg(feature = "alloc")]
+    fn insert_bottom_row(
+        &mut self,
+        row: impl IntoIterator<Item = SlimeState<S>>,
+        physprop: &PhysicsProperties<S>,
+        bump_impulse: S,
+    ) -> bool {
+        let mut inserted = 0;
+        let row = row.into_iter().inspect(|_| inserted += 1);
+        if !self.push_bottom_row(row, physprop.cell_height) {
+            return false;
+        }
+        if bump_impulse == S::ZERO {
+            return true;
+        }
+        let (down, bottoms): (_, Vec<_>) = match self.gravity_dir() {
+            GravityDirection::Down => (
+                Self::Dir::UP.opposite(),
+                self.cols().filter_map(|mut col| col.next()).collect(),
+            ),
+            GravityDirection::Up => (
+                Self::Dir::UP,
+                self.cols().filter_map(|col| col.last()).collect(),
+            ),
+        };
+        let bumps = bottoms
+            .into_iter()
+            .take(inserted)
+            .map(|at| JigglePropagation {
+                at,
+                impulse: -bump_impulse,
+                came_from: down,
+                hops: 0,
+            })
+            .collect();
+        self.apply_propagations(bumps, physprop);
+        true
+    }
+    /// Get the settled slimes among `locs` moving again after changing the board behind the physics'
+    /// back, so the next steps settle them properly rather than snapping them into place.
+    ///
+    /// Each one whose `y_bottom` isn't where its column stacks it any more starts falling, honouring
+    /// `PhysicsProperties::fall_delay`, and lands there. The rest start jiggling with no momentum, and
+    /// settle again straight away unless something reaches them first. Slimes that aren't settled, or
+    /// that [`JigglyBoard::peek_slime`] can't look at, are left alone.
+    #[cfg(feature = "alloc")]
+    fn wake_region(
+        &mut self,
+        locs: impl IntoIterator<Item = Self::Loc>,
+        physprop: &PhysicsProperties<S>,
+    ) where
+        Self::Loc: PartialEq,
+    {
+        let region: Vec<Self::Loc> = locs.into_iter().collect();
+        if region.is_empty() {
+            return;
+        }
+        // Where the column pass would stack each of them, if everything under them were resting
+        let mut stacked = Vec::new();
+        let cols: Vec<Vec<Self::Loc>> = self.cols().map(Iterator::collect).collect();
+        for (col_index, mut col) in cols.into_iter().enumerate() {
+            if self.gravity_dir() == GravityDirection::Up {
+                col.reverse();
+            }
+            let mut jiggle_offset = self.column_base(col_index);
+            for location in col {
+                jiggle_offset += self.gap_before(location);
+                if region.contains(&location) {
+                    stacked.push((location, jiggle_offset));
+                }
+                jiggle_offset += self.props_for(location, physprop).cell_height;
+            }
+        }
+        for (location, y_bottom) in stacked {
+            let props = self.props_for(location, physprop);
+            let tolerance = props.jiggle_offset_epsilon * props.cell_height;
+            let moved = self.peek_slime(location, |state, stored| {
+                matches!(state, SlimeState::Settled).then(|| (stored - y_bottom).abs() > tolerance)
+            });
+            let Some(Some(moved)) = moved else {
+                continue;
+            };
+            self.impulse_jiggle_with(location, |_| {
+                if moved {
+                    SlimeState::falling(&props)
+                } else {
+                    SlimeState::landed()
+                }
+            });
+        }
+    }
+}
+
+impl<S: Scalar, B: JigglyBoard<S> + ?Sized> JigglyBoardExt<S> for B {}
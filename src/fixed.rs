@@ -0,0 +1,337 @@
+//! A Q16.16 fixed-point [`Scalar`], for simulations that have to match bit for bit across machines.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::Scalar;
+
+/// A signed Q16.16 fixed-point number: an `i32` counting 1/65536ths.
+///
+/// Everything is integer arithmetic, so a board stepped in `Fixed` ends up in exactly the same state on
+/// every platform, which is what lockstep networking needs. Floats only come in through
+/// [`Fixed::from_f32`]/[`Fixed::from_f64`], which round the same way everywhere too.
+///
+/// The range is about ±32768 with a resolution of about 0.000015. Nothing wraps: every operation
+/// saturates at the ends of the range, and [`Fixed::MAX`] and [`Fixed::MIN`] stand in for the infinities,
+/// so `PhysicsProperties::gelatin().cast::<Fixed>()` keeps its "no limit" fields meaning no limit. There's
+/// no NaN. Multiplication rounds to the nearest value, ties upward. Division rounds towards zero, and
+/// dividing by zero gives `MAX`, `MIN` or zero depending on the sign of the dividend.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed(i32);
+
+/// Bits after the point.
+const FRAC_BITS: u32 = 16;
+/// Bits after the point in the Q32.32 used inside the transcendental functions.
+const WIDE_BITS: u32 = 32;
+const WIDE_ONE: i128 = 1 << WIDE_BITS;
+/// `ln(2)`, `π` and `√2` in Q32.32.
+const WIDE_LN_2: i128 = 2_977_044_472;
+const WIDE_PI: i128 = 13_493_037_705;
+const WIDE_SQRT_2: i128 = 6_074_001_000;
+
+impl Fixed {
+    pub const ZERO: Fixed = Fixed(0);
+    pub const ONE: Fixed = Fixed(1 << FRAC_BITS);
+    pub const MAX: Fixed = Fixed(i32::MAX);
+    pub const MIN: Fixed = Fixed(i32::MIN);
+    /// The smallest positive value, 1/65536.
+    pub const EPSILON: Fixed = Fixed(1);
+
+    /// The value whose raw representation is `bits`, i.e. `bits / 65536`.
+    pub const fn from_bits(bits: i32) -> Fixed {
+        Fixed(bits)
+    }
+    /// The raw representation, e.g. for hashing a game state or sending it over the network.
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+    /// The nearest value to `value`, ties away from zero, saturating. NaN is zero.
+    pub fn from_f32(value: f32) -> Fixed {
+        Fixed::from_f64(value as f64)
+    }
+    /// The nearest value to `value`, ties away from zero, saturating. NaN is zero.
+    pub fn from_f64(value: f64) -> Fixed {
+        let scaled = value * (1u32 << FRAC_BITS) as f64;
+        let rounded = if scaled >= 0.0 {
+            scaled + 0.5
+        } else {
+            scaled - 0.5
+        };
+        // `as` saturates and sends NaN to zero
+        Fixed(rounded as i32)
+    }
+    /// The nearest `f32`, e.g. for rendering.
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+    /// Exactly this value.
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / (1u32 << FRAC_BITS) as f64
+    }
+
+    fn saturate(raw: i128) -> Fixed {
+        Fixed(raw.clamp(i32::MIN as i128, i32::MAX as i128) as i32)
+    }
+    /// `raw / 2^shift`, rounded to the nearest, ties upward.
+    fn round_shift(raw: i128, shift: u32) -> i128 {
+        if shift == 0 {
+            raw
+        } else {
+            (raw + (1 << (shift - 1))) >> shift
+        }
+    }
+    fn from_wide(wide: i128) -> Fixed {
+        Fixed::saturate(Fixed::round_shift(wide, WIDE_BITS - FRAC_BITS))
+    }
+    fn to_wide(self) -> i128 {
+        (self.0 as i128) << (WIDE_BITS - FRAC_BITS)
+    }
+}
+
+/// `e^x` for `x` in Q32.32.
+fn wide_exp(x: i128) -> Fixed {
+    // Past these the result saturates or rounds to zero anyway
+    if x > 11 * WIDE_ONE {
+        return Fixed::MAX;
+    }
+    if x < -12 * WIDE_ONE {
+        return Fixed::ZERO;
+    }
+    // x = k * ln(2) + r, with |r| <= ln(2) / 2
+    let k = (x + WIDE_LN_2 / 2).div_euclid(WIDE_LN_2);
+    let r = x - k * WIDE_LN_2;
+    let mut term = WIDE_ONE;
+    let mut sum = WIDE_ONE;
+    for i in 1..=12 {
+        term = ((term * r) >> WIDE_BITS) / i;
+        sum += term;
+    }
+    let shift = WIDE_BITS as i128 - FRAC_BITS as i128 - k;
+    if shift >= 0 {
+        Fixed::saturate(Fixed::round_shift(sum, shift as u32))
+    } else {
+        Fixed::saturate(sum << -shift)
+    }
+}
+
+/// `ln(x)` in Q32.32, for positive `x`.
+fn wide_ln(x: Fixed) -> i128 {
+    let top = 31 - x.0.leading_zeros() as i128;
+    // x = m * 2^exponent, with m in [√2 / 2, √2]
+    let mut exponent = top - FRAC_BITS as i128;
+    let mut m = (x.0 as i128) << (WIDE_BITS as i128 - top);
+    if m > WIDE_SQRT_2 {
+        m >>= 1;
+        exponent += 1;
+    }
+    // ln(m) = 2 * atanh((m - 1) / (m + 1))
+    let s = ((m - WIDE_ONE) << WIDE_BITS) / (m + WIDE_ONE);
+    let s2 = (s * s) >> WIDE_BITS;
+    let mut term = s;
+    let mut series = 0;
+    for k in 0..8 {
+        series += term / (2 * k + 1);
+        term = (term * s2) >> WIDE_BITS;
+    }
+    2 * series + exponent * WIDE_LN_2
+}
+
+impl Scalar for Fixed {
+    const ZERO: Self = Fixed::ZERO;
+    const ONE: Self = Fixed::ONE;
+    const INFINITY: Self = Fixed::MAX;
+    const PI: Self = Fixed(205_887);
+    const TAU: Self = Fixed(411_775);
+    fn from_f64(value: f64) -> Self {
+        Fixed::from_f64(value)
+    }
+    fn from_u32(value: u32) -> Self {
+        Fixed::saturate((value as i128) << FRAC_BITS)
+    }
+    fn to_f64(self) -> f64 {
+        Fixed::to_f64(self)
+    }
+    fn to_u32(self) -> u32 {
+        if self == Fixed::MAX {
+            u32::MAX
+        } else {
+            self.0.max(0) as u32 >> FRAC_BITS
+        }
+    }
+    fn abs(self) -> Self {
+        Fixed(self.0.saturating_abs())
+    }
+    fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+    fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+    fn clamp(self, min: Self, max: Self) -> Self {
+        Ord::clamp(self, min, max)
+    }
+    fn recip(self) -> Self {
+        Fixed::ONE / self
+    }
+    /// Everything but the saturated ends, which stand in for the infinities.
+    fn is_finite(self) -> bool {
+        self != Fixed::MAX && self != Fixed::MIN
+    }
+    fn is_nan(self) -> bool {
+        false
+    }
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+    fn exp(self) -> Self {
+        wide_exp(self.to_wide())
+    }
+    /// Zero for a base that isn't positive.
+    fn powf(self, exponent: Self) -> Self {
+        if exponent == Fixed::ZERO {
+            return Fixed::ONE;
+        }
+        if self <= Fixed::ZERO {
+            return Fixed::ZERO;
+        }
+        wide_exp((exponent.0 as i128 * wide_ln(self)) >> FRAC_BITS)
+    }
+    fn sin(self) -> Self {
+        let x = self.to_wide();
+        let tau = 2 * WIDE_PI;
+        let mut r = x - (x + WIDE_PI).div_euclid(tau) * tau;
+        // Fold [-π, π] into [-π/2, π/2], where the series converges quickly
+        if r > WIDE_PI / 2 {
+            r = WIDE_PI - r;
+        } else if r < -WIDE_PI / 2 {
+            r = -WIDE_PI - r;
+        }
+        let r2 = (r * r) >> WIDE_BITS;
+        let mut term = r;
+        let mut sum = r;
+        for i in 1..=8 {
+            term = -((term * r2) >> WIDE_BITS) / ((2 * i) * (2 * i + 1));
+            sum += term;
+        }
+        Fixed::from_wide(sum)
+    }
+    fn sqrt(self) -> Self {
+        if self <= Fixed::ZERO {
+            return Fixed::ZERO;
+        }
+        Fixed(((self.0 as u64) << FRAC_BITS).isqrt() as i32)
+    }
+}
+
+impl Add for Fixed {
+    type Output = Fixed;
+    fn add(self, rhs: Fixed) -> Fixed {
+        Fixed(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+    fn sub(self, rhs: Fixed) -> Fixed {
+        Fixed(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Fixed;
+    fn mul(self, rhs: Fixed) -> Fixed {
+        Fixed::saturate(Fixed::round_shift(
+            self.0 as i128 * rhs.0 as i128,
+            FRAC_BITS,
+        ))
+    }
+}
+
+impl Div for Fixed {
+    type Output = Fixed;
+    fn div(self, rhs: Fixed) -> Fixed {
+        if rhs.0 == 0 {
+            return match self.0.cmp(&0) {
+                Ordering::Greater => Fixed::MAX,
+                Ordering::Less => Fixed::MIN,
+                Ordering::Equal => Fixed::ZERO,
+            };
+        }
+        Fixed::saturate(((self.0 as i128) << FRAC_BITS) / rhs.0 as i128)
+    }
+}
+
+impl Rem for Fixed {
+    type Output = Fixed;
+    /// Zero when `rhs` is zero.
+    fn rem(self, rhs: Fixed) -> Fixed {
+        Fixed(self.0.checked_rem(rhs.0).unwrap_or(0))
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+    fn neg(self) -> Fixed {
+        Fixed(self.0.saturating_neg())
+    }
+}
+
+macro_rules! assign_ops {
+    ($($trait:ident::$method:ident => $op:tt),* $(,)?) => {
+        $(
+            impl $trait for Fixed {
+                fn $method(&mut self, rhs: Fixed) {
+                    *self = *self $op rhs;
+                }
+            }
+        )*
+    };
+}
+
+assign_ops! {
+    AddAssign::add_assign => +,
+    SubAssign::sub_assign => -,
+    MulAssign::mul_assign => *,
+    DivAssign::div_assign => /,
+    RemAssign::rem_assign => %,
+}
+
+impl Sum for Fixed {
+    fn sum<I: Iterator<Item = Fixed>>(iter: I) -> Fixed {
+        iter.fold(Fixed::ZERO, Add::add)
+    }
+}
+
+impl From<i16> for Fixed {
+    fn from(value: i16) -> Fixed {
+        Fixed((value as i32) << FRAC_BITS)
+    }
+}
+
+impl From<Fixed> for f32 {
+    fn from(value: Fixed) -> f32 {
+        value.to_f32()
+    }
+}
+
+impl From<Fixed> for f64 {
+    fn from(value: Fixed) -> f64 {
+        value.to_f64()
+    }
+}
+
+impl fmt::Debug for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f64(), f)
+    }
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
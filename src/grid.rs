@@ -0,0 +1,356 @@
+//! A ready-made rectangular [`JigglyBoard`], for games that don't need anything fancier.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::{
+    Cardinal4, GridDirection, JigglyBoard, JigglyBoardExt, PhysicsProperties, RenderProps, Scalar,
+    SlimePropsIn, SlimePropsOut, SlimeState,
+};
+
+/// One occupied cell of a [`DenseGridBoard`].
+pub struct GridCell<S = f32> {
+    pub state: SlimeState<S>,
+    pub y_bottom: S,
+    /// How to draw it, as of the last time it was stepped.
+    pub render: RenderProps<S>,
+}
+
+impl<S: Scalar> GridCell<S> {
+    fn new(state: SlimeState<S>, y_bottom: S) -> Self {
+        GridCell {
+            state,
+            y_bottom,
+            render: RenderProps {
+                y_bottom,
+                y_scale: S::ONE,
+                x_scale: S::ONE,
+                x_offset: S::ZERO,
+            },
+        }
+    }
+}
+
+/// A `width` by `height` grid of slimes, at `(x, y)` with `(0, 0)` bottom left, jiggling up, down, left
+/// and right, or to all eight neighbours with [`Diag8`](crate::Diag8) for `D`.
+///
+/// `y_bottom`s are in cells, so leave `PhysicsProperties::cell_height` at 1.0. Each column should be
+/// packed at the bottom: a slime with nothing under it falls until it lands on the stack, but stays in its
+/// cell. [`DenseGridBoard::remove`] keeps them packed, and so does popping.
+pub struct DenseGridBoard<S = f32, D = Cardinal4> {
+    width: usize,
+    height: usize,
+    /// Column by column, bottom first.
+    cells: Vec<Option<GridCell<S>>>,
+    /// What impulses are multiplied by going from one column to the next, on top of
+    /// `PhysicsProperties::transfer_horizontal`.
+    ///
+    /// Keep these below 1.0 unless `PhysicsProperties::cycle_guard` or `max_propagation_hops` stops waves
+    /// some other way: every square of four slimes is a loop a wave can go round.
+    pub transfer_horizontal: S,
+    /// What impulses are multiplied by going up or down a column, on top of
+    /// `PhysicsProperties::transfer_up` and `transfer_down`.
+    pub transfer_vertical: S,
+    /// What impulses are multiplied by going diagonally, with directions that can.
+    pub transfer_diagonal: S,
+    directions: PhantomData<D>,
+}
+
+impl<S: Scalar> DenseGridBoard<S> {
+    /// An empty board, passing on half of each impulse from one slime to the next.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::with_directions(width, height)
+    }
+}
+
+impl<S: Scalar, D: GridDirection> DenseGridBoard<S, D> {
+    /// An empty board with neighbours in `D`, e.g. `DenseGridBoard::<f32, Diag8>::with_directions`,
+    /// passing on half of each impulse, or 0.375 diagonally.
+    pub fn with_directions(width: usize, height: usize) -> Self {
+        DenseGridBoard {
+            width,
+            height,
+            cells: (0..width * height).map(|_| None).collect(),
+            transfer_horizontal: S::from_f64(0.5),
+            transfer_vertical: S::from_f64(0.5),
+            transfer_diagonal: S::from_f64(0.375),
+            directions: PhantomData,
+        }
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(x * self.height + y)
+    }
+    pub fn cell(&self, x: usize, y: usize) -> Option<&GridCell<S>> {
+        self.cells[self.index(x, y)?].as_ref()
+    }
+    pub fn cell_mut(&mut self, x: usize, y: usize) -> Option<&mut GridCell<S>> {
+        let index = self.index(x, y)?;
+        self.cells[index].as_mut()
+    }
+    /// The state of the slime at `(x, y)`, if there is one.
+    pub fn get(&self, x: usize, y: usize) -> Option<&SlimeState<S>> {
+        self.cell(x, y).map(|cell| &cell.state)
+    }
+    /// Put a slime in `state` at `(x, y)`, returning the state of the one that was there.
+    ///
+    /// A new slime starts with its `y_bottom` at `y`; one that's replaced keeps its `y_bottom`.
+    ///
+    /// # Panics
+    ///
+    /// If `(x, y)` is off the board.
+    pub fn set(&mut self, x: usize, y: usize, state: SlimeState<S>) -> Option<SlimeState<S>> {
+        let index = self.index(x, y).expect("cell is off the board");
+        match &mut self.cells[index] {
+            Some(cell) => Some(core::mem::replace(&mut cell.state, state)),
+            empty => {
+                *empty = Some(GridCell::new(state, S::from_u32(y as u32)));
+                None
+            }
+        }
+    }
+    /// Whether there's a slime at `(x, y)` for jiggles to reach, rather than nothing or one in the air.
+    pub(crate) fn is_reachable(&self, x: usize, y: usize) -> bool {
+        self.get(x, y).is_some_and(|state| {
+            !matches!(
+                state,
+                SlimeState::Falling { .. }
+                    | SlimeState::Bouncing { .. }
+                    | SlimeState::Launched { .. }
+            )
+        })
+    }
+    /// Take the slime at `(x, y)` out, returning its state, and move everything above it down a cell.
+    ///
+    /// They keep their `y_bottom`s, so they start falling on the next step and land in the gap.
+    pub fn remove(&mut self, x: usize, y: usize) -> Option<SlimeState<S>> {
+        let index = self.index(x, y)?;
+        let removed = self.cells[index].take()?;
+        let top = x * self.height + self.height;
+        self.cells[index..top].rotate_left(1);
+        Some(removed.state)
+    }
+    /// Take column `x` out, returning its slimes' states bottom first, and close the gap by moving the
+    /// columns to its right left. The board ends up a column narrower.
+    ///
+    /// The slimes either side of the gap lean away from it with `side_impulse`, as if hit from where it
+    /// was; nothing else is touched. Invalidate any [`BoardLayoutCache`](crate::BoardLayoutCache) for it.
+    pub fn remove_column(
+        &mut self,
+        x: usize,
+        side_impulse: S,
+        physprop: &PhysicsProperties<S>,
+    ) -> Option<Vec<SlimeState<S>>> {
+        if x >= self.width {
+            return None;
+        }
+        let removed = self
+            .cells
+            .drain(x * self.height..(x + 1) * self.height)
+            .flatten()
+            .map(|cell| cell.state)
+            .collect();
+        self.width -= 1;
+        self.jolt_either_side(x.checked_sub(1), x, side_impulse, physprop);
+        Some(removed)
+    }
+    /// Put a new column in at `x`, moving the columns from `x` on right to make room, and fill it from the
+    /// bottom with as many of `states` as fit. The board ends up a column wider.
+    ///
+    /// The slimes either side of it lean away from it with `side_impulse`, as if it had pushed its way in.
+    /// Invalidate any [`BoardLayoutCache`](crate::BoardLayoutCache) for it.
+    ///
+    /// # Panics
+    ///
+    /// If `x` is past the right edge of the board.
+    pub fn insert_column(
+        &mut self,
+        x: usize,
+        states: impl IntoIterator<Item = SlimeState<S>>,
+        side_impulse: S,
+        physprop: &PhysicsProperties<S>,
+    ) {
+        assert!(x <= self.width, "column is off the board");
+        let mut states = states.into_iter();
+        let column: Vec<_> = (0..self.height)
+            .map(|y| {
+                let state = states.next()?;
+                Some(GridCell::new(state, S::from_u32(y as u32)))
+            })
+            .collect();
+        let at = x * self.height;
+        self.cells.splice(at..at, column);
+        self.width += 1;
+        self.jolt_either_side(x.checked_sub(1), x + 1, side_impulse, physprop);
+    }
+    /// [`JigglyBoardExt::nudge`] every slime in column `left` as if from its right, and every slime in
+    /// column `right` as if from its left.
+    fn jolt_either_side(
+        &mut self,
+        left: Option<usize>,
+        right: usize,
+        impulse: S,
+        physprop: &PhysicsProperties<S>,
+    ) {
+        let Some(rightwards) = D::RIGHT else {
+            return;
+        };
+        if impulse == S::ZERO {
+            return;
+        }
+        let sides = [(left, rightwards), (Some(right), rightwards.opposite())];
+        for (x, came_from) in sides {
+            let Some(x) = x.filter(|&x| x < self.width) else {
+                continue;
+            };
+            for y in 0..self.height {
+                if self.cell(x, y).is_some() {
+                    self.nudge((x, y), impulse, came_from, physprop);
+                }
+            }
+        }
+    }
+    /// Whether the first `columns` columns all have room at the top for [`JigglyBoard::push_bottom_row`].
+    pub(crate) fn has_room_below(&self, columns: usize) -> bool {
+        self.height > 0 && (0..columns).all(|x| self.cell(x, self.height - 1).is_none())
+    }
+}
+
+impl<S: Scalar, D: GridDirection> JigglyBoard<S> for DenseGridBoard<S, D> {
+    type Dir = D;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: D,
+        (x, y): (usize, usize),
+        impulse: S,
+    ) -> Option<((usize, usize), S)> {
+        let (dx, dy) = dir.grid_offset();
+        let to = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        let transfer = match (dx, dy) {
+            (0, _) => self.transfer_vertical,
+            (_, 0) => self.transfer_horizontal,
+            _ => self.transfer_diagonal,
+        };
+        self.is_reachable(to.0, to.1)
+            .then(|| (to, impulse * transfer))
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        (0..self.width).map(move |x| {
+            (0..self.height)
+                .filter(move |&y| self.cell(x, y).is_some())
+                .map(move |y| (x, y))
+        })
+    }
+    fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        (0..self.height).map(move |y| {
+            (0..self.width)
+                .filter(move |&x| self.cell(x, y).is_some())
+                .map(move |x| (x, y))
+        })
+    }
+    /// Whatever slime is in the next cell over, falling or not.
+    fn neighbor(&self, (x, y): (usize, usize), dir: D) -> Option<(usize, usize)> {
+        let (dx, dy) = dir.grid_offset();
+        let to = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.cell(to.0, to.1).is_some().then_some(to)
+    }
+    fn peek_slime<R>(
+        &self,
+        (x, y): (usize, usize),
+        f: impl FnOnce(&SlimeState<S>, S) -> R,
+    ) -> Option<R> {
+        self.cell(x, y).map(|cell| f(&cell.state, cell.y_bottom))
+    }
+    fn mut_slime_with(
+        &mut self,
+        (x, y): (usize, usize),
+        f: impl FnOnce(SlimePropsIn<S>) -> SlimePropsOut<S>,
+    ) {
+        let Some(cell) = self.cell_mut(x, y) else {
+            return;
+        };
+        let out = f(SlimePropsIn {
+            state: core::mem::replace(&mut cell.state, SlimeState::Settled),
+            y_bottom: cell.y_bottom,
+        });
+        cell.render = RenderProps::from(&out);
+        cell.state = out.state;
+        cell.y_bottom = out.y_bottom;
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        (x, y): (usize, usize),
+        f: impl FnOnce(SlimeState<S>) -> SlimeState<S>,
+    ) {
+        if let Some(cell) = self.cell_mut(x, y) {
+            let state = core::mem::replace(&mut cell.state, SlimeState::Settled);
+            cell.state = f(state);
+        }
+    }
+    /// Pops come bottom first, and each one moves the rest of its column down, so by the time a later one
+    /// in the same column is reported it may be a few cells lower.
+    fn on_popped(&mut self, (x, y): (usize, usize)) {
+        let finished = (0..=y).rev().find(|&y| {
+            matches!(
+                self.get(x, y),
+                Some(SlimeState::Popping { progress }) if *progress >= S::ONE
+            )
+        });
+        if let Some(y) = finished {
+            self.remove(x, y);
+        }
+    }
+    fn exchange_slimes(&mut self, a: (usize, usize), b: (usize, usize)) -> Option<(S, S)> {
+        let (a_index, b_index) = (self.index(a.0, a.1)?, self.index(b.0, b.1)?);
+        // Only the states move; each slot keeps where it's drawn
+        let [Some(a_cell), Some(b_cell)] = self.cells.get_disjoint_mut([a_index, b_index]).ok()?
+        else {
+            return None;
+        };
+        core::mem::swap(&mut a_cell.state, &mut b_cell.state);
+        let offset = |from: usize, to: usize| S::from_u32(to as u32) - S::from_u32(from as u32);
+        Some((offset(a.0, b.0), offset(a.1, b.1)))
+    }
+    fn remove_slime(&mut self, (x, y): (usize, usize)) -> bool {
+        self.remove(x, y).is_some()
+    }
+    /// Leaves a gap in its column, rather than moving what's above down.
+    fn take_slime(&mut self, (x, y): (usize, usize)) -> Option<(SlimeState<S>, S)> {
+        let index = self.index(x, y)?;
+        let cell = self.cells[index].take()?;
+        Some((cell.state, cell.y_bottom))
+    }
+    fn set_slime(&mut self, (x, y): (usize, usize), state: SlimeState<S>, y_bottom: S) -> bool {
+        let Some(index) = self.index(x, y) else {
+            return false;
+        };
+        self.cells[index] = Some(GridCell::new(state, y_bottom));
+        true
+    }
+    /// Fails if any column that would get a slime is full to the top.
+    fn push_bottom_row(&mut self, row: impl Iterator<Item = SlimeState<S>>, lift: S) -> bool {
+        let row: Vec<_> = row.take(self.width).collect();
+        if !self.has_room_below(row.len()) {
+            return false;
+        }
+        for (x, state) in row.into_iter().enumerate() {
+            let column = &mut self.cells[x * self.height..(x + 1) * self.height];
+            column.rotate_right(1);
+            for cell in column.iter_mut().flatten() {
+                cell.y_bottom += lift;
+                cell.render.y_bottom += lift;
+            }
+            column[0] = Some(GridCell::new(state, S::ZERO));
+        }
+        true
+    }
+    fn loc_index(&self, (x, y): (usize, usize)) -> Option<usize> {
+        self.index(x, y)
+    }
+}
@@ -0,0 +1,355 @@
+//! Rigid multi-cell slimes, which fall, land and jiggle as one.
+
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::SlimeState;
+use crate::interp::RenderHistory;
+#[cfg(feature = "alloc")]
+use crate::interp::RenderProps;
+use crate::step::StepContext;
+#[cfg(feature = "alloc")]
+use crate::step::{self, StepOutcome};
+use crate::{
+    JigglePropagation, JigglyBoard, PhysicsObserver, PhysicsProperties, Scalar, SlimePropsIn,
+    SlimePropsOut,
+};
+
+/// Which rigid group a cell belongs to; see [`JigglyBoard::group_of`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GroupId(pub u32);
+
+#[cfg(feature = "alloc")]
+struct Member<Loc, S> {
+    loc: Loc,
+    /// Where it sits in the group, ignoring any squash.
+    y_bottom: S,
+    /// Where the lowest member of the group in its column sits, which it squashes towards.
+    column_base: S,
+    /// The top of the column beneath it, if nothing else from its group is in the way.
+    floor: Option<S>,
+}
+
+#[cfg(feature = "alloc")]
+struct Group<Loc, S> {
+    members: Vec<Member<Loc, S>>,
+    /// Velocity and distance fallen, if any member is falling.
+    falling: Option<(S, S)>,
+}
+
+/// The grouped cells seen during a column pass, to be moved together once it's done.
+#[cfg(feature = "alloc")]
+pub(crate) struct Groups<Loc, S> {
+    groups: BTreeMap<GroupId, Group<Loc, S>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<Loc: Copy, S: Scalar> Groups<Loc, S> {
+    pub fn new() -> Self {
+        Groups {
+            groups: BTreeMap::new(),
+        }
+    }
+    /// Take the slime at `loc` out of the column pass if it can move as part of group `id`, holding it in
+    /// place for [`Groups::resolve`]. Hands it back if it's busy with something of its own, like popping.
+    ///
+    /// `on_group` is whether the slime below it in the column was claimed for the same group.
+    pub fn claim(
+        &mut self,
+        id: GroupId,
+        loc: Loc,
+        on_group: bool,
+        props_in: SlimePropsIn<S>,
+        (jiggle_offset, falling_below): (&mut S, &mut Option<S>),
+        physprop: &PhysicsProperties<S>,
+    ) -> Result<SlimePropsOut<S>, SlimePropsIn<S>> {
+        let falling = match props_in.state {
+            SlimeState::Settled | SlimeState::Jiggling { .. } => None,
+            SlimeState::Falling { velocity, fallen } => Some((velocity, fallen)),
+            _ => return Err(props_in),
+        };
+        let SlimePropsIn { state, y_bottom } = props_in;
+        let group = self.groups.entry(id).or_insert_with(|| Group {
+            members: Vec::new(),
+            falling: None,
+        });
+        // Whatever it's stacked on in the group was claimed just before it
+        let member = match group.members.last() {
+            Some(below) if on_group => Member {
+                loc,
+                y_bottom: below.y_bottom + physprop.cell_height,
+                column_base: below.column_base,
+                floor: None,
+            },
+            _ => Member {
+                loc,
+                y_bottom,
+                column_base: y_bottom,
+                floor: Some(*jiggle_offset),
+            },
+        };
+        if let Some((velocity, fallen)) = falling {
+            let (v, f) = group.falling.get_or_insert((velocity, fallen));
+            *v = v.max(velocity);
+            *f = f.max(fallen);
+        }
+        // Where it was last step; anything stacked on it catches up next step
+        *jiggle_offset = member.y_bottom + physprop.cell_height;
+        *falling_below = falling.map(|(velocity, _)| velocity);
+        group.members.push(member);
+        Ok(SlimePropsOut {
+            state,
+            y_bottom,
+            y_scale: S::ONE,
+            x_scale: S::ONE,
+            x_offset: S::ZERO,
+        })
+    }
+    /// Move every group as one: falling groups drop until their lowest point of contact lands, and
+    /// resting groups sit on their highest support and jiggle, squashing towards it as a whole.
+    ///
+    /// Returns false if anything is still moving.
+    #[allow(clippy::too_many_arguments)]
+    pub fn resolve<B: JigglyBoard<S, Loc = Loc> + ?Sized>(
+        &self,
+        board: &mut B,
+        physprop: &PhysicsProperties<S>,
+        ctx: &StepContext<S>,
+        landed_from: B::Dir,
+        jiggle_propagations: &mut impl Extend<JigglePropagation<Loc, B::Dir, S>>,
+        render: &mut RenderHistory<S>,
+        observer: &mut impl PhysicsObserver<Loc, B::Dir, S>,
+    ) -> bool {
+        let mut settled = true;
+        for group in self.groups.values() {
+            let Some(leader) = group.members.first() else {
+                continue;
+            };
+            let props = board.props_for(leader.loc, physprop);
+            let cell_height = props.cell_height;
+            let (clearance, contact) = group
+                .members
+                .iter()
+                .filter_map(|m| m.floor.map(|floor| (m.y_bottom - floor, m.loc)))
+                .fold((S::INFINITY, leader.loc), |best, this| {
+                    if this.0 < best.0 { this } else { best }
+                });
+            let clearance = if clearance.is_finite() {
+                clearance
+            } else {
+                S::ZERO
+            };
+            match group.falling {
+                Some((velocity, fallen)) => {
+                    settled = false;
+                    let velocity = (velocity + ctx.dt * props.gravity).min(props.terminal_velocity);
+                    let drop = velocity * ctx.dt * cell_height;
+                    if velocity >= S::ZERO && drop >= clearance {
+                        let fallen = fallen + clearance / cell_height;
+                        let mass: S = group.members.iter().map(|m| board.mass(m.loc)).sum();
+                        for m in &group.members {
+                            place(
+                                board,
+                                render,
+                                m.loc,
+                                SlimeState::landed(),
+                                m.y_bottom - clearance,
+                            );
+                            observer.stepped(m.loc, &SlimeState::landed());
+                        }
+                        // One impulse for the whole group, as heavy as all of it
+                        let impact = props
+                            .impact_mode
+                            .impact(props.velocity_to_impact * velocity, fallen.max(S::ZERO))
+                            .max(S::ZERO);
+                        observer.landed(contact, impact * mass);
+                        jiggle_propagations.extend([JigglePropagation {
+                            at: contact,
+                            impulse: impact * mass,
+                            came_from: landed_from,
+                            hops: 0,
+                        }]);
+                    } else {
+                        let fallen = fallen + drop / cell_height;
+                        for m in &group.members {
+                            place(
+                                board,
+                                render,
+                                m.loc,
+                                SlimeState::Falling { velocity, fallen },
+                                m.y_bottom - drop,
+                            );
+                            observer.stepped(m.loc, &SlimeState::Falling { velocity, fallen });
+                        }
+                    }
+                }
+                None => {
+                    let base = group
+                        .members
+                        .iter()
+                        .map(|m| m.y_bottom)
+                        .fold(S::INFINITY, S::min)
+                        - clearance;
+                    for m in &group.members {
+                        let y_bottom = m.y_bottom - clearance;
+                        let column_base = m.column_base - clearance;
+                        let mass = board.mass(m.loc);
+                        let mut outcome = StepOutcome::default();
+                        let mut now_settled = false;
+                        let mut written = None;
+                        board.mut_slime_with(m.loc, |props_in| {
+                            let was_settled = matches!(props_in.state, SlimeState::Settled);
+                            // Everyone steps from the same base so they all squash alike
+                            let mut jiggle_offset = base;
+                            let out = step::step_slime(
+                                SlimePropsIn {
+                                    state: props_in.state,
+                                    y_bottom: base,
+                                },
+                                &mut jiggle_offset,
+                                &mut None,
+                                mass,
+                                &props,
+                                ctx,
+                                &mut outcome,
+                            );
+                            observer.stepped(m.loc, &out.state);
+                            now_settled = !was_settled && matches!(out.state, SlimeState::Settled);
+                            let out = SlimePropsOut {
+                                y_bottom: column_base + (y_bottom - column_base) * out.y_scale,
+                                ..out
+                            };
+                            written = Some(RenderProps::from(&out));
+                            out
+                        });
+                        if let Some(written) = written {
+                            render.record(board, m.loc, written);
+                        }
+                        if outcome.active {
+                            settled = false;
+                        }
+                        if now_settled {
+                            observer.settled(m.loc);
+                        }
+                    }
+                }
+            }
+        }
+        settled
+    }
+    /// After jiggle propagation, even out whatever each member of a landed group received so the whole
+    /// group keeps jiggling in unison.
+    pub fn unify<B: JigglyBoard<S, Loc = Loc> + ?Sized>(&self, board: &mut B) {
+        for group in self.groups.values() {
+            let mut total = [S::ZERO; 4];
+            let mut life = S::ZERO;
+            let mut jiggling = 0;
+            let mut resting = true;
+            for m in &group.members {
+                board.impulse_jiggle_with(m.loc, |state| {
+                    match state {
+                        SlimeState::Jiggling {
+                            momentum,
+                            offset,
+                            life: l,
+                            momentum_x,
+                            offset_x,
+                        } => {
+                            for (sum, v) in total
+                                .iter_mut()
+                                .zip([momentum, offset, momentum_x, offset_x])
+                            {
+                                *sum += v;
+                            }
+                            life = life.max(l);
+                            jiggling += 1;
+                        }
+                        SlimeState::Settled => {}
+                        _ => resting = false,
+                    }
+                    state
+                });
+            }
+            if !resting || jiggling == 0 {
+                continue;
+            }
+            let n = S::from_f64(group.members.len() as f64);
+            let [momentum, offset, momentum_x, offset_x] = total.map(|sum| sum / n);
+            for m in &group.members {
+                board.impulse_jiggle_with(m.loc, |_| SlimeState::Jiggling {
+                    momentum,
+                    offset,
+                    life,
+                    momentum_x,
+                    offset_x,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn place<S: Scalar, B: JigglyBoard<S> + ?Sized>(
+    board: &mut B,
+    render: &mut RenderHistory<S>,
+    loc: B::Loc,
+    state: SlimeState<S>,
+    y_bottom: S,
+) {
+    board.mut_slime_with(loc, |_| SlimePropsOut {
+        state,
+        y_bottom,
+        y_scale: S::ONE,
+        x_scale: S::ONE,
+        x_offset: S::ZERO,
+    });
+    render.record(
+        board,
+        loc,
+        RenderProps {
+            y_bottom,
+            y_scale: S::ONE,
+            x_scale: S::ONE,
+            x_offset: S::ZERO,
+        },
+    );
+}
+
+/// Without the `alloc` feature there's nowhere to gather groups, so grouped cells step on their own.
+#[cfg(not(feature = "alloc"))]
+pub(crate) struct Groups<Loc, S>(core::marker::PhantomData<(Loc, S)>);
+
+#[cfg(not(feature = "alloc"))]
+impl<Loc: Copy, S: Scalar> Groups<Loc, S> {
+    pub fn new() -> Self {
+        Groups(core::marker::PhantomData)
+    }
+    pub fn claim(
+        &mut self,
+        _id: GroupId,
+        _loc: Loc,
+        _on_group: bool,
+        props_in: SlimePropsIn<S>,
+        _: (&mut S, &mut Option<S>),
+        _physprop: &PhysicsProperties<S>,
+    ) -> Result<SlimePropsOut<S>, SlimePropsIn<S>> {
+        Err(props_in)
+    }
+    #[allow(clippy::too_many_arguments)]
+    pub fn resolve<B: JigglyBoard<S, Loc = Loc> + ?Sized>(
+        &self,
+        _board: &mut B,
+        _physprop: &PhysicsProperties<S>,
+        _ctx: &StepContext<S>,
+        _landed_from: B::Dir,
+        _jiggle_propagations: &mut impl Extend<JigglePropagation<Loc, B::Dir, S>>,
+        _render: &mut RenderHistory<S>,
+        _observer: &mut impl PhysicsObserver<Loc, B::Dir, S>,
+    ) -> bool {
+        true
+    }
+    pub fn unify<B: JigglyBoard<S, Loc = Loc> + ?Sized>(&self, _board: &mut B) {}
+}
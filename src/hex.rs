@@ -0,0 +1,224 @@
+//! Hexagonal boards, with flat-topped hexes stacked in columns.
+
+use crate::{
+    DenseGridBoard, Direction, GridCell, JigglyBoard, Scalar, SlimePropsIn, SlimePropsOut,
+    SlimeState,
+};
+
+/// The six neighbours of a flat-topped hex: straight up and down its own column, and two either side,
+/// half a cell up and half a cell down in the next column over.
+///
+/// A landing arrives from `Up`, so it goes out in the other five: `Down` into the stack it landed on,
+/// `DownLeft` and `DownRight` into the two slimes beside that, and `UpLeft` and `UpRight` into the two
+/// beside itself. Each of those arrives from the way back, so it never goes straight back to the slime it
+/// came from. It can still come round by the other two sides of a triangle, and every slime is in six of
+/// them, so hex boards want `CycleGuard::VisitOnce` or `WaveMode::Coalesced` to reach each slime once.
+///
+/// Impulses arriving along a diagonal lean a slime sideways, away from where they came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    Up,
+    UpRight,
+    DownRight,
+    Down,
+    DownLeft,
+    UpLeft,
+}
+
+impl HexDirection {
+    /// Clockwise from `Up`.
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::Up,
+        HexDirection::UpRight,
+        HexDirection::DownRight,
+        HexDirection::Down,
+        HexDirection::DownLeft,
+        HexDirection::UpLeft,
+    ];
+
+    /// How far the neighbour this way is in axial `(q, r)`, as [`HexBoard`] lays them out.
+    pub fn axial_offset(self) -> (i32, i32) {
+        match self {
+            HexDirection::Up => (0, 1),
+            HexDirection::UpRight => (1, 0),
+            HexDirection::DownRight => (1, -1),
+            HexDirection::Down => (0, -1),
+            HexDirection::DownLeft => (-1, 0),
+            HexDirection::UpLeft => (-1, 1),
+        }
+    }
+}
+
+impl Direction for HexDirection {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        HexDirection::ALL
+            .into_iter()
+            .filter(move |&dir| dir != self)
+    }
+    fn opposite(self) -> Self {
+        match self {
+            HexDirection::Up => HexDirection::Down,
+            HexDirection::UpRight => HexDirection::DownLeft,
+            HexDirection::DownRight => HexDirection::UpLeft,
+            HexDirection::Down => HexDirection::Up,
+            HexDirection::DownLeft => HexDirection::UpRight,
+            HexDirection::UpLeft => HexDirection::DownRight,
+        }
+    }
+    const UP: Self = HexDirection::Up;
+    const RIGHT: Option<Self> = Some(HexDirection::UpRight);
+    fn points_right(self) -> bool {
+        matches!(self, HexDirection::UpRight | HexDirection::DownRight)
+    }
+}
+
+/// A `width` by `height` board of flat-topped hexes, at axial `(q, r)`: `q` is the column, counting
+/// right from 0, and `r` goes up the column, with `(q + 1, r)` up and to the right of `(q, r)`.
+///
+/// Odd columns sit half a cell higher than even ones, so the bottom of column `q` is at `r = -(q / 2)`
+/// (rounding down). [`HexBoard::to_offset`] and [`HexBoard::from_offset`] convert to and from
+/// `(column, row)`, and slimes are stored and drawn as in a [`DenseGridBoard`] of the same size, with
+/// `y_bottom` in cells from the bottom of their own column.
+pub struct HexBoard<S = f32> {
+    grid: DenseGridBoard<S>,
+    /// What impulses are multiplied by going up or down a column.
+    pub transfer_vertical: S,
+    /// What impulses are multiplied by going into a neighbouring column.
+    pub transfer_diagonal: S,
+}
+
+impl<S: Scalar> HexBoard<S> {
+    /// An empty board, passing on half of each impulse from one slime to the next.
+    pub fn new(width: usize, height: usize) -> Self {
+        HexBoard {
+            grid: DenseGridBoard::new(width, height),
+            transfer_vertical: S::from_f64(0.5),
+            transfer_diagonal: S::from_f64(0.5),
+        }
+    }
+    pub fn width(&self) -> usize {
+        self.grid.width()
+    }
+    pub fn height(&self) -> usize {
+        self.grid.height()
+    }
+    /// Where `(q, r)` is as `(column, row)`, if it's on the board.
+    pub fn to_offset(&self, (q, r): (i32, i32)) -> Option<(usize, usize)> {
+        let x = usize::try_from(q).ok()?;
+        let y = usize::try_from(r.checked_add(q.div_euclid(2))?).ok()?;
+        (x < self.width() && y < self.height()).then_some((x, y))
+    }
+    /// The axial `(q, r)` of the hex in row `y` of column `x`.
+    pub fn from_offset(x: usize, y: usize) -> (i32, i32) {
+        let q = x as i32;
+        (q, y as i32 - q.div_euclid(2))
+    }
+    pub fn cell(&self, loc: (i32, i32)) -> Option<&GridCell<S>> {
+        let (x, y) = self.to_offset(loc)?;
+        self.grid.cell(x, y)
+    }
+    pub fn cell_mut(&mut self, loc: (i32, i32)) -> Option<&mut GridCell<S>> {
+        let (x, y) = self.to_offset(loc)?;
+        self.grid.cell_mut(x, y)
+    }
+    /// The state of the slime at `loc`, if there is one.
+    pub fn get(&self, loc: (i32, i32)) -> Option<&SlimeState<S>> {
+        self.cell(loc).map(|cell| &cell.state)
+    }
+    /// [`DenseGridBoard::set`], at axial `loc`.
+    ///
+    /// # Panics
+    ///
+    /// If `loc` is off the board.
+    pub fn set(&mut self, loc: (i32, i32), state: SlimeState<S>) -> Option<SlimeState<S>> {
+        let (x, y) = self.to_offset(loc).expect("cell is off the board");
+        self.grid.set(x, y, state)
+    }
+    /// [`DenseGridBoard::remove`], at axial `loc`.
+    pub fn remove(&mut self, loc: (i32, i32)) -> Option<SlimeState<S>> {
+        let (x, y) = self.to_offset(loc)?;
+        self.grid.remove(x, y)
+    }
+    /// The board as plain columns and rows.
+    pub fn grid(&self) -> &DenseGridBoard<S> {
+        &self.grid
+    }
+}
+
+impl<S: Scalar> JigglyBoard<S> for HexBoard<S> {
+    type Dir = HexDirection;
+    type Loc = (i32, i32);
+    fn apply_dir_to_loc(
+        &self,
+        dir: HexDirection,
+        (q, r): (i32, i32),
+        impulse: S,
+    ) -> Option<((i32, i32), S)> {
+        let (dq, dr) = dir.axial_offset();
+        let to = (q + dq, r + dr);
+        let (x, y) = self.to_offset(to)?;
+        let transfer = if dq == 0 {
+            self.transfer_vertical
+        } else {
+            self.transfer_diagonal
+        };
+        self.grid
+            .is_reachable(x, y)
+            .then(|| (to, impulse * transfer))
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (i32, i32)>> {
+        self.grid
+            .cols()
+            .map(|col| col.map(|(x, y)| Self::from_offset(x, y)))
+    }
+    /// Whatever slime is in the next cell over, falling or not.
+    fn neighbor(&self, (q, r): (i32, i32), dir: HexDirection) -> Option<(i32, i32)> {
+        let (dq, dr) = dir.axial_offset();
+        let to = (q + dq, r + dr);
+        self.cell(to).is_some().then_some(to)
+    }
+    fn peek_slime<R>(&self, loc: (i32, i32), f: impl FnOnce(&SlimeState<S>, S) -> R) -> Option<R> {
+        self.cell(loc).map(|cell| f(&cell.state, cell.y_bottom))
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (i32, i32),
+        f: impl FnOnce(SlimePropsIn<S>) -> SlimePropsOut<S>,
+    ) {
+        if let Some(offset) = self.to_offset(loc) {
+            self.grid.mut_slime_with(offset, f);
+        }
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (i32, i32),
+        f: impl FnOnce(SlimeState<S>) -> SlimeState<S>,
+    ) {
+        if let Some(offset) = self.to_offset(loc) {
+            self.grid.impulse_jiggle_with(offset, f);
+        }
+    }
+    fn on_popped(&mut self, loc: (i32, i32)) {
+        if let Some(offset) = self.to_offset(loc) {
+            self.grid.on_popped(offset);
+        }
+    }
+    fn remove_slime(&mut self, loc: (i32, i32)) -> bool {
+        self.remove(loc).is_some()
+    }
+    fn take_slime(&mut self, loc: (i32, i32)) -> Option<(SlimeState<S>, S)> {
+        let offset = self.to_offset(loc)?;
+        self.grid.take_slime(offset)
+    }
+    fn set_slime(&mut self, loc: (i32, i32), state: SlimeState<S>, y_bottom: S) -> bool {
+        self.to_offset(loc)
+            .is_some_and(|offset| self.grid.set_slime(offset, state, y_bottom))
+    }
+    fn push_bottom_row(&mut self, row: impl Iterator<Item = SlimeState<S>>, lift: S) -> bool {
+        self.grid.push_bottom_row(row, lift)
+    }
+    fn loc_index(&self, loc: (i32, i32)) -> Option<usize> {
+        let (x, y) = self.to_offset(loc)?;
+        self.grid.loc_index((x, y))
+    }
+}
@@ -0,0 +1,770 @@
+//! What a single jiggle impulse does to the slime it reaches.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::PropagationEvent;
+#[cfg(not(feature = "alloc"))]
+use crate::small::ArrayDeque;
+use crate::small::{Overflowing, SmallDeque};
+use crate::{
+    ConservationMode, CycleGuard, Direction, EscapedImpulse, JigglePropagation, JigglyBoard,
+    PhysicsObserver, PhysicsProperties, PropagationOrder, Scalar, SlimeState,
+};
+
+/// An impulse arriving at one slime, already divided by its mass.
+#[derive(Clone, Copy)]
+pub(crate) struct Impulse<S> {
+    /// Feeds the vertical spring.
+    pub y: S,
+    /// Feeds the sideways spring, positive towards `Direction::RIGHT`.
+    pub x: S,
+    /// How hard it hit regardless of direction, for life refresh and buffering. Negative if it stretches.
+    pub amount: S,
+}
+
+impl<S: Scalar> Impulse<S> {
+    /// `impulse` (after mass) arriving from `came_from`.
+    pub fn arriving<D: Direction + Copy + PartialEq>(impulse: S, came_from: D) -> Self {
+        // Arriving from the side leans the slime over rather than squashing it
+        let (y, x) = if came_from.squashes() {
+            (impulse, S::ZERO)
+        } else if came_from.points_right() {
+            (S::ZERO, -impulse)
+        } else {
+            (S::ZERO, impulse)
+        };
+        Impulse {
+            y,
+            x,
+            amount: impulse,
+        }
+    }
+}
+
+/// The state a slime ends up in after `impulse` reaches it. Sets `blocked` if it stops the wave dead.
+pub(crate) fn receive<S: Scalar>(
+    state: SlimeState<S>,
+    Impulse {
+        y: impulse_y,
+        x: impulse_x,
+        amount,
+    }: Impulse<S>,
+    cell_props: &PhysicsProperties<S>,
+    blocked: &mut bool,
+) -> SlimeState<S> {
+    let PhysicsProperties {
+        velocity_to_impact,
+        life_refresh,
+        max_momentum,
+        propagate_up_factor,
+        ..
+    } = *cell_props;
+    let clamp_momentum = |momentum: S| momentum.clamp(-max_momentum, max_momentum);
+    use SlimeState::*;
+    match state {
+        Settled => Jiggling {
+            momentum: clamp_momentum(impulse_y),
+            offset: S::ZERO,
+            life: S::ONE,
+            momentum_x: clamp_momentum(impulse_x),
+            offset_x: S::ZERO,
+        },
+        AboutToFall { remaining } => AboutToFall { remaining },
+        Launched { velocity, height } => Launched { velocity, height },
+        // Whatever is pressing on it keeps it still
+        Squished { amount } => Squished { amount },
+        Spawning { progress } => Spawning { progress },
+        Merging {
+            progress,
+            into_x,
+            into_y,
+        } => Merging {
+            progress,
+            into_x,
+            into_y,
+        },
+        Popping { progress } => Popping { progress },
+        Frozen => {
+            *blocked = true;
+            Frozen
+        }
+        Held { buffered_impulse } => Held {
+            buffered_impulse: buffered_impulse + amount,
+        },
+        Swapping {
+            progress,
+            from_x,
+            from_y,
+            buffered_impulse,
+        } => Swapping {
+            progress,
+            from_x,
+            from_y,
+            buffered_impulse: buffered_impulse + amount,
+        },
+        // Sideways motion doesn't care about vertical pokes
+        Sliding {
+            x_offset,
+            x_velocity,
+            returning,
+        } => Sliding {
+            x_offset,
+            x_velocity,
+            returning,
+        },
+        // A slime on its way back up from a bounce is in the air, so there's nothing to jiggle
+        Falling { velocity, fallen } if velocity < S::ZERO => Falling { velocity, fallen },
+        Bouncing {
+            velocity,
+            fallen,
+            bounces_left,
+        } if velocity < S::ZERO => Bouncing {
+            velocity,
+            fallen,
+            bounces_left,
+        },
+        // The wave sent up the stack from a landing jolts a slime still falling onto it back up
+        Falling { velocity, fallen }
+            if propagate_up_factor > S::ZERO && velocity_to_impact > S::ZERO =>
+        {
+            Falling {
+                velocity: velocity - impulse_y / velocity_to_impact,
+                fallen,
+            }
+        }
+        Bouncing {
+            velocity,
+            fallen,
+            bounces_left,
+        } if propagate_up_factor > S::ZERO && velocity_to_impact > S::ZERO => Bouncing {
+            velocity: velocity - impulse_y / velocity_to_impact,
+            fallen,
+            bounces_left,
+        },
+        //Note: this really should not be encountered, but it will have defined behaviour in the case it is.
+        Falling { velocity, .. } | Bouncing { velocity, .. } => Jiggling {
+            momentum: clamp_momentum(impulse_y + velocity * velocity_to_impact),
+            offset: S::ZERO,
+            life: S::ONE,
+            momentum_x: clamp_momentum(impulse_x),
+            offset_x: S::ZERO,
+        },
+        Jiggling {
+            momentum,
+            offset,
+            life,
+            momentum_x,
+            offset_x,
+        } => Jiggling {
+            momentum: clamp_momentum(momentum + impulse_y),
+            offset,
+            life: life_refresh.refresh(life, amount.abs()),
+            momentum_x: clamp_momentum(momentum_x + impulse_x),
+            offset_x,
+        },
+    }
+}
+
+/// One impulse reaching one cell, waiting to be added up with the others that reach it this step.
+#[cfg(feature = "alloc")]
+struct Delivery<Loc, S> {
+    index: usize,
+    at: Loc,
+    impulse: Impulse<S>,
+}
+
+/// Run all of `propagations` for [`JigglyBoard::loc_index`] boards: trace where every impulse goes
+/// first, then give each cell the sum of everything that reached it, added in a fixed order. That makes
+/// the result independent of the order the propagations, and so the columns, came in.
+#[cfg(feature = "alloc")]
+pub(crate) fn propagate_all<S: Scalar, B: JigglyBoard<S> + ?Sized>(
+    board: &mut B,
+    propagations: Vec<JigglePropagation<B::Loc, B::Dir, S>>,
+    physprop: &PhysicsProperties<S>,
+    observer: &mut impl PhysicsObserver<B::Loc, B::Dir, S>,
+) {
+    let mut traced = Traced::default();
+    traced.trace(board, propagations, physprop, observer);
+    traced.deliver(board, physprop);
+}
+
+/// [`propagate_all`] a few propagations at a time: where everything traced so far goes, waiting for the
+/// rest before any of it is delivered. Tracing doesn't change any slime with a `loc_index`, so splitting
+/// it up comes out the same as tracing them all in one go.
+#[cfg(feature = "alloc")]
+pub(crate) struct Traced<Loc, S>(Vec<Delivery<Loc, S>>);
+
+#[cfg(feature = "alloc")]
+impl<Loc, S> Default for Traced<Loc, S> {
+    fn default() -> Self {
+        Traced(Vec::new())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Loc: Copy, S: Scalar> Traced<Loc, S> {
+    /// Trace where each of `propagations` goes, telling `observer` about every slime it reaches.
+    pub fn trace<B: JigglyBoard<S, Loc = Loc> + ?Sized>(
+        &mut self,
+        board: &mut B,
+        propagations: impl IntoIterator<Item = JigglePropagation<Loc, B::Dir, S>>,
+        physprop: &PhysicsProperties<S>,
+        observer: &mut impl PhysicsObserver<Loc, B::Dir, S>,
+    ) {
+        for propagation in propagations {
+            trace(board, propagation, physprop, &mut self.0, observer);
+        }
+    }
+    /// Give each slime everything traced to it, added up in a fixed order, leaving nothing traced.
+    pub fn deliver<B: JigglyBoard<S, Loc = Loc> + ?Sized>(
+        &mut self,
+        board: &mut B,
+        physprop: &PhysicsProperties<S>,
+    ) {
+        self.0.sort_by(|a, b| {
+            a.index
+                .cmp(&b.index)
+                .then(a.impulse.y.total_cmp(&b.impulse.y))
+                .then(a.impulse.x.total_cmp(&b.impulse.x))
+                .then(a.impulse.amount.total_cmp(&b.impulse.amount))
+        });
+        let mut deliveries = self.0.drain(..).peekable();
+        while let Some(first) = deliveries.next() {
+            let mut total = first.impulse;
+            while let Some(next) = deliveries.next_if(|next| next.index == first.index) {
+                total.y += next.impulse.y;
+                total.x += next.impulse.x;
+                total.amount += next.impulse.amount;
+            }
+            let cell_props = board.props_for(first.at, physprop);
+            board.impulse_jiggle_with(first.at, |state| {
+                receive(state, total, &cell_props, &mut false)
+            });
+        }
+    }
+}
+
+/// One impulse reaching one cell as part of a coalesced wave front.
+#[cfg(feature = "alloc")]
+struct Arrival<Loc, Dir, S> {
+    index: usize,
+    at: Loc,
+    /// Before mass, as it goes on to spread.
+    raw: S,
+    impulse: Impulse<S>,
+    came_from: Dir,
+    hops: u32,
+}
+
+/// Run all of `propagations` as one wave for `WaveMode::Coalesced`. Everything reaching a cell on the
+/// same hop is added up, and the cell spreads that total onwards, once. Whatever else reaches it later
+/// is added to what it receives but goes no further. Each cell then gets its total in one go, in a
+/// fixed order. Cells without a [`JigglyBoard::loc_index`] are spread from one at a time.
+#[cfg(feature = "alloc")]
+pub(crate) fn propagate_coalesced<S: Scalar, B: JigglyBoard<S> + ?Sized>(
+    board: &mut B,
+    propagations: Vec<JigglePropagation<B::Loc, B::Dir, S>>,
+    physprop: &PhysicsProperties<S>,
+    observer: &mut impl PhysicsObserver<B::Loc, B::Dir, S>,
+) {
+    let mut visited = Vec::new();
+    let mut received = Vec::new();
+    let mut branches = Vec::new();
+    let mut front = Vec::new();
+    for propagation in propagations {
+        arrive(board, propagation, physprop, &mut front, observer);
+    }
+    while !front.is_empty() {
+        sort_arrivals(&mut front);
+        let mut next = Vec::new();
+        let mut arrivals = front.drain(..).peekable();
+        while let Some(first) = arrivals.next() {
+            let (mut raw, mut hops) = (first.raw, first.hops);
+            let (at, index, came_from) = (first.at, first.index, first.came_from);
+            received.push(first);
+            while let Some(more) = arrivals.next_if(|more| more.index == index) {
+                raw += more.raw;
+                hops = hops.min(more.hops);
+                received.push(more);
+            }
+            if visited.len() <= index {
+                visited.resize(index + 1, false);
+            }
+            if core::mem::replace(&mut visited[index], true)
+                || raw.abs() < physprop.min_impactable_at(hops)
+                || hops >= physprop.max_propagation_hops
+            {
+                continue;
+            }
+            // Only a slime's kind decides whether it blocks, and impulses never change that
+            let mut blocked = false;
+            board.impulse_jiggle_with(at, |state| {
+                blocked = matches!(state, SlimeState::Frozen);
+                state
+            });
+            if blocked {
+                continue;
+            }
+            let cell_props = board.props_for(at, physprop);
+            // Anything else it came from is already visited
+            for dir in came_from.other_directions() {
+                board.apply_dir_to_loc_multi(dir, at, raw, &mut branches);
+                if branches.is_empty() && dir != B::Dir::UP && dir != B::Dir::UP.opposite() {
+                    let impulse = raw * cell_props.transfer_for(dir);
+                    if impulse.abs() >= physprop.min_impactable_at(hops + 1) {
+                        observer.escaped(EscapedImpulse {
+                            from: at,
+                            dir,
+                            impulse,
+                            hops: hops + 1,
+                        });
+                    }
+                }
+                for (to, impulse) in branches.drain(..) {
+                    let propagation = JigglePropagation {
+                        at: to,
+                        impulse: impulse * cell_props.transfer_for(dir),
+                        came_from: dir.opposite(),
+                        hops: hops + 1,
+                    };
+                    arrive(board, propagation, physprop, &mut next, observer);
+                }
+            }
+        }
+        drop(arrivals);
+        front = next;
+    }
+    sort_arrivals(&mut received);
+    let mut received = received.into_iter().peekable();
+    while let Some(first) = received.next() {
+        let (mut raw, mut total) = (first.raw, first.impulse);
+        while let Some(more) = received.next_if(|more| more.index == first.index) {
+            raw += more.raw;
+            total.y += more.impulse.y;
+            total.x += more.impulse.x;
+            total.amount += more.impulse.amount;
+        }
+        if raw.abs() < physprop.min_impactable_at(first.hops) {
+            continue;
+        }
+        let cell_props = board.props_for(first.at, physprop);
+        board.impulse_jiggle_with(first.at, |state| {
+            receive(state, total, &cell_props, &mut false)
+        });
+        observer.propagated(PropagationEvent {
+            loc: first.at,
+            impulse: raw,
+            came_from: first.came_from,
+            hops: first.hops,
+        });
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn sort_arrivals<Loc, Dir, S: Scalar>(arrivals: &mut [Arrival<Loc, Dir, S>]) {
+    arrivals.sort_by(|a, b| {
+        a.index
+            .cmp(&b.index)
+            .then(a.raw.total_cmp(&b.raw))
+            .then(a.impulse.y.total_cmp(&b.impulse.y))
+            .then(a.impulse.x.total_cmp(&b.impulse.x))
+    });
+}
+
+/// Add `propagation` to the wave `front`, or spread it the usual way if its cell has no index.
+#[cfg(feature = "alloc")]
+fn arrive<S: Scalar, B: JigglyBoard<S> + ?Sized>(
+    board: &mut B,
+    propagation: JigglePropagation<B::Loc, B::Dir, S>,
+    physprop: &PhysicsProperties<S>,
+    front: &mut Vec<Arrival<B::Loc, B::Dir, S>>,
+    observer: &mut impl PhysicsObserver<B::Loc, B::Dir, S>,
+) {
+    let JigglePropagation {
+        at,
+        impulse,
+        came_from,
+        hops,
+    } = propagation;
+    // Tiny arrivals still count towards the total, but a NaN would poison it
+    if !impulse.is_finite() {
+        return;
+    }
+    match board.loc_index(at) {
+        Some(index) => {
+            let impulse = impulse * board.cell_material(at).factor();
+            front.push(Arrival {
+                index,
+                at,
+                raw: impulse,
+                impulse: Impulse::arriving(impulse / board.mass(at), came_from),
+                came_from,
+                hops,
+            });
+        }
+        None => board.propagate_jiggle_observed(propagation, physprop, observer),
+    }
+}
+
+/// Follow one propagation the way [`JigglyBoard::propagate_jiggle`] would, noting down what it delivers
+/// where instead of applying it. Cells without an index get theirs straight away.
+#[cfg(feature = "alloc")]
+fn trace<S: Scalar, B: JigglyBoard<S> + ?Sized>(
+    board: &mut B,
+    propagation: JigglePropagation<B::Loc, B::Dir, S>,
+    physprop: &PhysicsProperties<S>,
+    deliveries: &mut Vec<Delivery<B::Loc, S>>,
+    observer: &mut impl PhysicsObserver<B::Loc, B::Dir, S>,
+) {
+    spread(
+        board,
+        propagation,
+        physprop,
+        observer,
+        |board, observer, reached, cell_props| {
+            let JigglePropagation {
+                at,
+                impulse,
+                came_from,
+                hops,
+            } = reached;
+            let arriving = Impulse::arriving(impulse / board.mass(at), came_from);
+            let mut blocked = false;
+            match board.loc_index(at) {
+                Some(index) => {
+                    // Only a slime's kind decides whether it blocks, and impulses never change that
+                    board.impulse_jiggle_with(at, |state| {
+                        blocked = matches!(state, SlimeState::Frozen);
+                        state
+                    });
+                    deliveries.push(Delivery {
+                        index,
+                        at,
+                        impulse: arriving,
+                    });
+                }
+                None => board.impulse_jiggle_with(at, |state| {
+                    receive(state, arriving, cell_props, &mut blocked)
+                }),
+            }
+            observer.propagated(PropagationEvent {
+                loc: at,
+                impulse,
+                came_from,
+                hops,
+            });
+            !blocked
+        },
+    );
+}
+
+/// A cell a propagation has reached, and the directions it has yet to spread in from there.
+struct Frame<Loc, Dir, I, S> {
+    at: Loc,
+    impulse: S,
+    hops: u32,
+    dirs: I,
+    /// The direction the branches above `branches_from` in the shared list lead in.
+    dir: Dir,
+    branches_from: usize,
+    cell_props: PhysicsProperties<S>,
+    /// What `ConservationMode::Conserving` scales everything it sends onwards by.
+    scale: S,
+}
+
+impl<Loc: Copy, Dir: Direction + Copy + PartialEq, I, S: Scalar> Frame<Loc, Dir, I, S> {
+    /// The propagation `apply_dir_to_loc` sending it in `dir` to `at` with `impulse` leads on to.
+    fn onward(&self, dir: Dir, at: Loc, impulse: S) -> JigglePropagation<Loc, Dir, S> {
+        JigglePropagation {
+            at,
+            impulse: impulse * self.cell_props.transfer_for(dir) * self.scale,
+            came_from: dir.opposite(),
+            hops: self.hops + 1,
+        }
+    }
+    /// Where it goes when `apply_dir_to_loc` won't take it anywhere in `dir`.
+    ///
+    /// Off the side of the board it bounces back into the slime it's in. Floors and stack tops don't
+    /// reflect, or every landing would come straight back at the slime that landed.
+    fn reflected(&self, dir: Dir) -> Option<JigglePropagation<Loc, Dir, S>> {
+        let reflectance = self.cell_props.edge_reflectance;
+        if reflectance <= S::ZERO || dir == Dir::UP || dir == Dir::UP.opposite() {
+            return None;
+        }
+        Some(JigglePropagation {
+            at: self.at,
+            impulse: self.impulse * reflectance * self.scale,
+            came_from: dir,
+            hops: self.hops + 1,
+        })
+    }
+    /// What runs off the board when `apply_dir_to_loc` won't take it anywhere in `dir`, if it's sideways
+    /// and strong enough to have gone on.
+    fn escaped(&self, dir: Dir) -> Option<EscapedImpulse<Loc, Dir, S>> {
+        if dir == Dir::UP || dir == Dir::UP.opposite() {
+            return None;
+        }
+        let hops = self.hops + 1;
+        let impulse = self.impulse * self.cell_props.transfer_for(dir) * self.scale;
+        (impulse.abs() >= self.cell_props.min_impactable_at(hops)).then_some(EscapedImpulse {
+            from: self.at,
+            dir,
+            impulse,
+            hops,
+        })
+    }
+    /// How much it would send onwards in all, going by size, not counting anything too weak to go on.
+    fn onward_total<B: JigglyBoard<S, Loc = Loc, Dir = Dir> + ?Sized>(
+        &self,
+        board: &B,
+        came_from: Dir,
+        physprop: &PhysicsProperties<S>,
+        probe: &mut SmallDeque<(Loc, S), INLINE_FRAMES>,
+    ) -> S {
+        let cutoff = physprop.min_impactable_at(self.hops + 1);
+        let mut total = S::ZERO;
+        for dir in came_from.other_directions() {
+            board.apply_dir_to_loc_multi(dir, self.at, self.impulse, probe);
+            let reflected = probe.is_empty().then(|| self.reflected(dir)).flatten();
+            let onward = probe
+                .drain()
+                .map(|(at, impulse)| self.onward(dir, at, impulse))
+                .chain(reflected);
+            for next in onward {
+                if next.impulse.abs() >= cutoff {
+                    total += next.impulse.abs();
+                }
+            }
+        }
+        total
+    }
+}
+
+/// How deep a depth first wave, or how wide a breadth first one, gets before [`spread`] needs the heap.
+/// Without the `alloc` feature, the most it gets to at all, past which it tells
+/// [`PhysicsObserver::truncated`] what it drops.
+const INLINE_FRAMES: usize = 16;
+
+/// The cells a wave has been to, by [`JigglyBoard::loc_index`].
+#[cfg(feature = "alloc")]
+#[derive(Default)]
+struct Visited(Vec<bool>);
+
+/// The cells a wave has been to, by [`JigglyBoard::loc_index`]. Without the `alloc` feature, only the
+/// first [`INLINE_VISITED`] are remembered.
+#[cfg(not(feature = "alloc"))]
+#[derive(Default)]
+struct Visited(ArrayDeque<usize, INLINE_VISITED>);
+
+#[cfg(not(feature = "alloc"))]
+const INLINE_VISITED: usize = 64;
+
+impl Visited {
+    /// Note down the cell at `index`, returning whether it had been to it already.
+    #[cfg(feature = "alloc")]
+    fn visit(&mut self, index: usize) -> Option<bool> {
+        if self.0.len() <= index {
+            self.0.resize(index + 1, false);
+        }
+        Some(core::mem::replace(&mut self.0[index], true))
+    }
+    /// Note down the cell at `index`, returning whether it had been to it already, or `None` if it
+    /// hadn't and there's no room left to note it down.
+    #[cfg(not(feature = "alloc"))]
+    fn visit(&mut self, index: usize) -> Option<bool> {
+        if (0..self.0.len()).any(|i| self.0.get(i) == Some(&index)) {
+            return Some(true);
+        }
+        self.0.push_back(index).ok().map(|()| false)
+    }
+}
+
+/// Walk `propagation` out across the board in `PhysicsProperties::propagation_order`, calling `visit` for
+/// every cell it reaches with enough impulse. `visit` returns false if the wave stops dead there.
+/// Whatever runs off the side of the board goes to `observer`.
+///
+/// Depth first visits cells in the same order recursing on each direction in turn would, keeping the
+/// cells still to come back to in a buffer rather than on the call stack, so long gentle waves can't
+/// overflow it. Where [`JigglyBoard::apply_dir_to_loc_multi`] splits a wave, its branches are followed
+/// in the order the board gave them.
+pub(crate) fn spread<
+    S: Scalar,
+    B: JigglyBoard<S> + ?Sized,
+    O: PhysicsObserver<B::Loc, B::Dir, S>,
+>(
+    board: &mut B,
+    propagation: JigglePropagation<B::Loc, B::Dir, S>,
+    physprop: &PhysicsProperties<S>,
+    observer: &mut O,
+    mut visit: impl FnMut(
+        &mut B,
+        &mut O,
+        JigglePropagation<B::Loc, B::Dir, S>,
+        &PhysicsProperties<S>,
+    ) -> bool,
+) {
+    let guard = physprop.cycle_guard;
+    let conserving = physprop.conservation_mode == ConservationMode::Conserving;
+    let mut visited = Visited::default();
+    let mut probe = SmallDeque::new();
+    let mut enter = |board: &mut B,
+                     observer: &mut O,
+                     propagation: JigglePropagation<B::Loc, B::Dir, S>,
+                     branches_from: usize| {
+        let JigglePropagation {
+            at,
+            impulse,
+            came_from,
+            hops,
+        } = propagation;
+        let impulse = impulse * board.cell_material(at).factor();
+        // A NaN would sail past the cutoff and poison every slime it reached
+        if !impulse.is_finite() || impulse.abs() < physprop.min_impactable_at(hops) {
+            return None;
+        }
+        if guard == CycleGuard::VisitOnce
+            && let Some(index) = board.loc_index(at)
+        {
+            match visited.visit(index) {
+                Some(false) => {}
+                Some(true) => return None,
+                // Going on without knowing where it's been could take it round in circles for ever
+                None => {
+                    observer.truncated(at);
+                    return None;
+                }
+            }
+        }
+        let mut frame = Frame {
+            at,
+            impulse,
+            hops,
+            dirs: came_from.other_directions(),
+            dir: came_from,
+            branches_from,
+            cell_props: board.props_for(at, physprop),
+            scale: S::ONE,
+        };
+        let goes_on = hops < physprop.max_propagation_hops;
+        let mut kept = JigglePropagation {
+            impulse,
+            ..propagation
+        };
+        if conserving && goes_on {
+            // Whatever it passes on comes out of what it keeps, so the wave never adds up to more
+            // than it started with
+            let onward = frame.onward_total(board, came_from, physprop, &mut probe);
+            if onward > S::ZERO {
+                let passed = (onward / impulse.abs()).min(S::ONE);
+                frame.scale = passed * impulse.abs() / onward;
+                kept.impulse = impulse * (S::ONE - passed);
+            }
+        }
+        if !visit(board, observer, kept, &frame.cell_props) || !goes_on {
+            return None;
+        }
+        Some(frame)
+    };
+    let shrinks = |frame: &Frame<_, _, _, S>, next: &JigglePropagation<_, _, S>| {
+        guard != CycleGuard::StrictDecrease || next.impulse.abs() < frame.impulse.abs()
+    };
+    // Where the branches a split gives wait to be followed. Depth first, every frame's still to come
+    // wait here last first, each frame's above its parent's
+    let mut branches = SmallDeque::<_, INLINE_FRAMES>::new();
+    if physprop.propagation_order == PropagationOrder::BreadthFirst {
+        let mut queue = SmallDeque::<_, INLINE_FRAMES>::new();
+        let frame = enter(board, observer, propagation, 0);
+        keep(&mut queue, frame, observer);
+        while let Some(mut frame) = queue.pop_front() {
+            while let Some(dir) = frame.dirs.next() {
+                split(board, &frame, dir, &mut branches, observer);
+                if branches.is_empty() {
+                    if let Some(escaped) = frame.escaped(dir) {
+                        observer.escaped(escaped);
+                    }
+                    if let Some(next) = frame.reflected(dir)
+                        && shrinks(&frame, &next)
+                    {
+                        let next = enter(board, observer, next, 0);
+                        keep(&mut queue, next, observer);
+                    }
+                    continue;
+                }
+                for (at, impulse) in branches.drain() {
+                    let next = frame.onward(dir, at, impulse);
+                    if shrinks(&frame, &next) {
+                        let next = enter(board, observer, next, 0);
+                        keep(&mut queue, next, observer);
+                    }
+                }
+            }
+        }
+        return;
+    }
+    let mut stack = SmallDeque::<_, INLINE_FRAMES>::new();
+    let frame = enter(board, observer, propagation, 0);
+    keep(&mut stack, frame, observer);
+    while let Some(frame) = stack.back_mut() {
+        let next = if branches.len() > frame.branches_from
+            && let Some((at, impulse)) = branches.pop_back()
+        {
+            frame.onward(frame.dir, at, impulse)
+        } else {
+            let Some(dir) = frame.dirs.next() else {
+                stack.pop_back();
+                continue;
+            };
+            split(board, frame, dir, &mut branches, observer);
+            if branches.len() > frame.branches_from {
+                frame.dir = dir;
+                branches.reverse_from(frame.branches_from);
+                continue;
+            }
+            if let Some(escaped) = frame.escaped(dir) {
+                observer.escaped(escaped);
+            }
+            match frame.reflected(dir) {
+                Some(next) => next,
+                None => continue,
+            }
+        };
+        if !shrinks(frame, &next) {
+            continue;
+        }
+        let branches_from = branches.len();
+        let next = enter(board, observer, next, branches_from);
+        keep(&mut stack, next, observer);
+    }
+}
+
+/// Add `frame`, if the wave goes on from there, to those still to come back to, telling `observer` if
+/// there's no room left for it.
+fn keep<Loc: Copy, Dir, I, S, const N: usize>(
+    frames: &mut SmallDeque<Frame<Loc, Dir, I, S>, N>,
+    frame: Option<Frame<Loc, Dir, I, S>>,
+    observer: &mut impl PhysicsObserver<Loc, Dir, S>,
+) {
+    if let Some(frame) = frame
+        && let Err(frame) = frames.try_push_back(frame)
+    {
+        observer.truncated(frame.at);
+    }
+}
+
+/// Add every branch [`JigglyBoard::apply_dir_to_loc_multi`] sends `frame`'s impulse down in `dir` to
+/// `branches`, telling `observer` about any there's no room left for.
+fn split<S: Scalar, B: JigglyBoard<S> + ?Sized, I, const N: usize>(
+    board: &B,
+    frame: &Frame<B::Loc, B::Dir, I, S>,
+    dir: B::Dir,
+    branches: &mut SmallDeque<(B::Loc, S), N>,
+    observer: &mut impl PhysicsObserver<B::Loc, B::Dir, S>,
+) {
+    let mut branches = Overflowing {
+        into: branches,
+        dropped: |(at, _)| observer.truncated(at),
+    };
+    board.apply_dir_to_loc_multi(dir, frame.at, frame.impulse, &mut branches);
+}
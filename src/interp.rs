@@ -0,0 +1,123 @@
+//! Remembering what each slime looked like last step, so rendering can blend between steps.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{JigglyBoard, Scalar, SlimePropsOut, cast};
+
+/// The parts of a [`SlimePropsOut`] that say where and how to draw a slime.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RenderProps<S = f32> {
+    pub y_bottom: S,
+    pub y_scale: S,
+    pub x_scale: S,
+    pub x_offset: S,
+}
+
+impl<S: Scalar> RenderProps<S> {
+    /// Blend from `self` towards `to`, e.g. with [`StepReport::alpha`](crate::StepReport::alpha).
+    pub fn lerp(self, to: RenderProps<S>, alpha: S) -> RenderProps<S> {
+        let mix = |a: S, b: S| a + (b - a) * alpha;
+        RenderProps {
+            y_bottom: mix(self.y_bottom, to.y_bottom),
+            y_scale: mix(self.y_scale, to.y_scale),
+            x_scale: mix(self.x_scale, to.x_scale),
+            x_offset: mix(self.x_offset, to.x_offset),
+        }
+    }
+    /// The same props in another [`Scalar`], e.g. `f32` for drawing a board stepped in [`Fixed`](crate::Fixed).
+    pub fn cast<T: Scalar>(self) -> RenderProps<T> {
+        RenderProps {
+            y_bottom: cast(self.y_bottom),
+            y_scale: cast(self.y_scale),
+            x_scale: cast(self.x_scale),
+            x_offset: cast(self.x_offset),
+        }
+    }
+}
+
+impl<S: Copy> From<&SlimePropsOut<S>> for RenderProps<S> {
+    fn from(out: &SlimePropsOut<S>) -> Self {
+        RenderProps {
+            y_bottom: out.y_bottom,
+            y_scale: out.y_scale,
+            x_scale: out.x_scale,
+            x_offset: out.x_offset,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+struct Slot<S> {
+    /// Which step `current` was written in.
+    step: u32,
+    prev: RenderProps<S>,
+    current: RenderProps<S>,
+}
+
+/// The last two outputs of every slime, by [`JigglyBoard::loc_index`]. Never enabled without the
+/// `alloc` feature.
+pub(crate) struct RenderHistory<S> {
+    enabled: bool,
+    step: u32,
+    #[cfg(feature = "alloc")]
+    slots: Vec<Option<Slot<S>>>,
+    #[cfg(not(feature = "alloc"))]
+    slots: core::marker::PhantomData<S>,
+}
+
+impl<S: Scalar> RenderHistory<S> {
+    pub fn new(enabled: bool) -> Self {
+        RenderHistory {
+            enabled,
+            step: 0,
+            slots: Default::default(),
+        }
+    }
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+    /// Everything written from here on belongs to a new step.
+    pub fn begin_step(&mut self) {
+        self.step = self.step.wrapping_add(1);
+    }
+    /// Note down that the slime at `loc` was just given `out`, and tell the board what it had before
+    /// this step. A slime seen for the first time had what it has now.
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    pub fn record<B: JigglyBoard<S> + ?Sized>(
+        &mut self,
+        board: &mut B,
+        loc: B::Loc,
+        out: RenderProps<S>,
+    ) {
+        #[cfg(feature = "alloc")]
+        if self.enabled {
+            self.remember(board, loc, out);
+        }
+    }
+    #[cfg(feature = "alloc")]
+    fn remember<B: JigglyBoard<S> + ?Sized>(
+        &mut self,
+        board: &mut B,
+        loc: B::Loc,
+        out: RenderProps<S>,
+    ) {
+        let Some(index) = board.loc_index(loc) else {
+            return;
+        };
+        if self.slots.len() <= index {
+            self.slots.resize_with(index + 1, || None);
+        }
+        let slot = self.slots[index].get_or_insert(Slot {
+            step: self.step,
+            prev: out,
+            current: out,
+        });
+        if slot.step != self.step {
+            slot.prev = slot.current;
+            slot.step = self.step;
+        }
+        slot.current = out;
+        board.on_previous_output(loc, slot.prev);
+    }
+}
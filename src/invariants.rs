@@ -0,0 +1,214 @@
+//! Checking a board's slimes are somewhere the physics could have put them, for tracking down bugs.
+
+use alloc::vec::Vec;
+
+use crate::{GravityDirection, JigglyBoard, PhysicsProperties, Scalar, SlimeState};
+
+/// Something wrong with a slime, from [`JigglyBoard::check_invariants`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvariantViolation<Loc, S = f32> {
+    /// Its `y_bottom`, or something in its state, is NaN or infinite.
+    NotFinite { loc: Loc },
+    /// Its jiggle offset, up and down or sideways, is past `PhysicsProperties::max_offset`.
+    OffsetOutOfRange { loc: Loc, offset: S, max: S },
+    /// It's below the slime under it in its column.
+    OutOfOrder { loc: Loc, y_bottom: S, below: S },
+    /// It's resting inside the slime under it, which goes up to `floor` even squashed as far as it is.
+    Overlapping { loc: Loc, y_bottom: S, floor: S },
+    /// It's falling, but has gone through whatever it should have landed on at `floor`.
+    BelowFloor { loc: Loc, y_bottom: S, floor: S },
+}
+
+impl<Loc: Copy, S> InvariantViolation<Loc, S> {
+    /// Which slime it's about.
+    pub fn loc(&self) -> Loc {
+        use InvariantViolation::*;
+        match *self {
+            NotFinite { loc }
+            | OffsetOutOfRange { loc, .. }
+            | OutOfOrder { loc, .. }
+            | Overlapping { loc, .. }
+            | BelowFloor { loc, .. } => loc,
+        }
+    }
+}
+
+fn is_finite<S: Scalar>(state: &SlimeState<S>) -> bool {
+    use SlimeState::*;
+    match *state {
+        Settled | Frozen => true,
+        AboutToFall { remaining: a }
+        | Squished { amount: a }
+        | Spawning { progress: a }
+        | Popping { progress: a }
+        | Held {
+            buffered_impulse: a,
+        } => a.is_finite(),
+        Falling {
+            velocity: a,
+            fallen: b,
+        }
+        | Bouncing {
+            velocity: a,
+            fallen: b,
+            ..
+        }
+        | Launched {
+            velocity: a,
+            height: b,
+        }
+        | Sliding {
+            x_offset: a,
+            x_velocity: b,
+            ..
+        } => a.is_finite() && b.is_finite(),
+        Merging {
+            progress,
+            into_x,
+            into_y,
+        } => [progress, into_x, into_y].iter().all(|v| v.is_finite()),
+        Swapping {
+            progress,
+            from_x,
+            from_y,
+            buffered_impulse,
+        } => [progress, from_x, from_y, buffered_impulse]
+            .iter()
+            .all(|v| v.is_finite()),
+        Jiggling {
+            momentum,
+            offset,
+            life,
+            momentum_x,
+            offset_x,
+        } => [momentum, offset, life, momentum_x, offset_x]
+            .iter()
+            .all(|v| v.is_finite()),
+    }
+}
+
+/// The least of a cell the slime can take up in its stack, as a fraction of `cell_height`, or `None`
+/// if it's in the air.
+fn least_height<S: Scalar>(state: &SlimeState<S>, physprop: &PhysicsProperties<S>) -> Option<S> {
+    use SlimeState::*;
+    Some(match *state {
+        Falling { .. } | Bouncing { .. } | Launched { .. } => return None,
+        // Could have landed this step, which leaves the next slime up stacking from its bottom until the
+        // next step
+        Jiggling { .. } => S::ZERO,
+        Squished { amount } => (S::ONE - amount).max(physprop.min_y_scale),
+        Spawning { progress } => progress,
+        Popping { progress } | Merging { progress, .. } => S::ONE - progress,
+        // Could have been grabbed mid-squash
+        Held { .. } => physprop.min_y_scale,
+        _ => S::ONE,
+    })
+}
+
+/// What the slime under the next one in a column leaves for it.
+#[derive(Clone, Copy)]
+struct Under<S> {
+    y_bottom: S,
+    /// The least it goes up to, which anything resting on it must be above.
+    top: S,
+    /// What a slime falling onto it must be above. One falling itself can have the next one a step's
+    /// fall inside it, before that one catches up and rides on it.
+    landing: S,
+}
+
+pub(crate) fn check<S: Scalar, B: JigglyBoard<S> + ?Sized>(
+    board: &B,
+    physprop: &PhysicsProperties<S>,
+) -> Vec<InvariantViolation<B::Loc, S>> {
+    let mut violations = Vec::new();
+    let cols: Vec<Vec<B::Loc>> = board.cols().map(Iterator::collect).collect();
+    for (col_index, mut col) in cols.into_iter().enumerate() {
+        if board.gravity_dir() == GravityDirection::Up {
+            col.reverse();
+        }
+        let base = board.column_base(col_index);
+        // Unless there's a slime we couldn't look at in the way
+        let mut under = Some(Under {
+            y_bottom: base,
+            top: base,
+            landing: base,
+        });
+        for loc in col {
+            let props = board.props_for(loc, physprop);
+            let gap = board.gap_before(loc);
+            let Some((state, y_bottom)) =
+                board.peek_slime(loc, |state, y_bottom| (*state, y_bottom))
+            else {
+                under = None;
+                continue;
+            };
+            if !y_bottom.is_finite() || !is_finite(&state) {
+                violations.push(InvariantViolation::NotFinite { loc });
+                under = None;
+                continue;
+            }
+            if let SlimeState::Jiggling {
+                offset, offset_x, ..
+            } = state
+            {
+                for offset in [offset, offset_x] {
+                    if offset.abs() > props.max_offset {
+                        violations.push(InvariantViolation::OffsetOutOfRange {
+                            loc,
+                            offset,
+                            max: props.max_offset,
+                        });
+                    }
+                }
+            }
+            // Everything past here means walking the whole column
+            if !cfg!(debug_assertions) {
+                continue;
+            }
+            let tolerance = props.jiggle_offset_epsilon * props.cell_height;
+            let height = least_height(&state, &props);
+            if let Some(under) = under {
+                if y_bottom < under.y_bottom - tolerance {
+                    violations.push(InvariantViolation::OutOfOrder {
+                        loc,
+                        y_bottom,
+                        below: under.y_bottom,
+                    });
+                } else if height.is_some() && y_bottom < under.top + gap - tolerance {
+                    violations.push(InvariantViolation::Overlapping {
+                        loc,
+                        y_bottom,
+                        floor: under.top + gap,
+                    });
+                } else if height.is_none() && y_bottom < under.landing + gap - tolerance {
+                    violations.push(InvariantViolation::BelowFloor {
+                        loc,
+                        y_bottom,
+                        floor: under.landing + gap,
+                    });
+                }
+            }
+            let floor = under.map_or(y_bottom, |under| under.top + gap);
+            under = Some(match (state, height) {
+                // Its slot is still there under it, holding up whatever's above
+                (SlimeState::Launched { .. }, _) => Under {
+                    y_bottom: under.map_or(y_bottom, |under| under.y_bottom),
+                    top: floor + props.cell_height,
+                    landing: floor + props.cell_height,
+                },
+                (_, Some(height)) => Under {
+                    y_bottom,
+                    top: y_bottom + height * props.cell_height,
+                    landing: y_bottom + height * props.cell_height,
+                },
+                // Anything falling above it can't pass through it
+                (_, None) => Under {
+                    y_bottom,
+                    top: floor.max(y_bottom + props.cell_height),
+                    landing: y_bottom,
+                },
+            });
+        }
+    }
+    violations
+}
@@ -0,0 +1,180 @@
+//! Grids stacked one behind another, with jiggles leaking between them.
+
+use alloc::vec::Vec;
+
+use crate::{
+    Cardinal4, DenseGridBoard, GridDirection, JigglyBoard, Layered, Scalar, SlimePropsIn,
+    SlimePropsOut, SlimeState,
+};
+
+/// `depth` [`DenseGridBoard`]s of the same size, one behind another, at `(x, y, layer)` with layer 0 at
+/// the front.
+///
+/// Each layer falls and jiggles as a board of its own, and impulses pass between slimes at the same
+/// `(x, y)` in neighbouring layers, multiplied by `transfer_layer`. `cols` gives every column of layer 0,
+/// then every column of layer 1, and so on.
+pub struct LayeredGridBoard<S = f32, D = Cardinal4> {
+    layers: Vec<DenseGridBoard<S, D>>,
+    /// What impulses are multiplied by going from one layer to the next. 0 keeps the layers apart.
+    pub transfer_layer: S,
+}
+
+impl<S: Scalar> LayeredGridBoard<S> {
+    /// `depth` empty layers, passing on half of each impulse within them and nothing between them.
+    pub fn new(width: usize, height: usize, depth: usize) -> Self {
+        Self::with_directions(width, height, depth)
+    }
+}
+
+impl<S: Scalar, D: GridDirection> LayeredGridBoard<S, D> {
+    /// [`LayeredGridBoard::new`] with neighbours in `D` within each layer.
+    pub fn with_directions(width: usize, height: usize, depth: usize) -> Self {
+        LayeredGridBoard {
+            layers: (0..depth)
+                .map(|_| DenseGridBoard::with_directions(width, height))
+                .collect(),
+            transfer_layer: S::ZERO,
+        }
+    }
+    pub fn depth(&self) -> usize {
+        self.layers.len()
+    }
+    /// Layer `index`, e.g. to fill it or set its transfers. `None` past the back.
+    pub fn layer(&self, index: usize) -> Option<&DenseGridBoard<S, D>> {
+        self.layers.get(index)
+    }
+    pub fn layer_mut(&mut self, index: usize) -> Option<&mut DenseGridBoard<S, D>> {
+        self.layers.get_mut(index)
+    }
+    /// The state of the slime at `(x, y, layer)`, if there is one.
+    pub fn get(&self, (x, y, layer): (usize, usize, usize)) -> Option<&SlimeState<S>> {
+        self.layer(layer)?.get(x, y)
+    }
+}
+
+impl<S: Scalar, D: GridDirection> JigglyBoard<S> for LayeredGridBoard<S, D> {
+    type Dir = Layered<D>;
+    type Loc = (usize, usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Layered<D>,
+        (x, y, layer): (usize, usize, usize),
+        impulse: S,
+    ) -> Option<((usize, usize, usize), S)> {
+        let to = match dir {
+            Layered::Within(dir) => {
+                let ((x, y), impulse) =
+                    self.layer(layer)?.apply_dir_to_loc(dir, (x, y), impulse)?;
+                return Some(((x, y, layer), impulse));
+            }
+            Layered::Front => layer.checked_sub(1)?,
+            Layered::Back => layer + 1,
+        };
+        (self.transfer_layer != S::ZERO && self.layer(to)?.is_reachable(x, y))
+            .then(|| ((x, y, to), impulse * self.transfer_layer))
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize, usize)>> {
+        self.layers.iter().enumerate().flat_map(|(layer, grid)| {
+            grid.cols()
+                .map(move |col| col.map(move |(x, y)| (x, y, layer)))
+        })
+    }
+    /// Whatever slime is in the next cell over, falling or not, whatever `transfer_layer` is.
+    fn neighbor(
+        &self,
+        (x, y, layer): (usize, usize, usize),
+        dir: Layered<D>,
+    ) -> Option<(usize, usize, usize)> {
+        let (x, y, layer) = match dir {
+            Layered::Within(dir) => {
+                let (x, y) = self.layer(layer)?.neighbor((x, y), dir)?;
+                (x, y, layer)
+            }
+            Layered::Front => (x, y, layer.checked_sub(1)?),
+            Layered::Back => (x, y, layer + 1),
+        };
+        self.layer(layer)?
+            .cell(x, y)
+            .is_some()
+            .then_some((x, y, layer))
+    }
+    fn peek_slime<R>(
+        &self,
+        (x, y, layer): (usize, usize, usize),
+        f: impl FnOnce(&SlimeState<S>, S) -> R,
+    ) -> Option<R> {
+        self.layer(layer)?.peek_slime((x, y), f)
+    }
+    fn mut_slime_with(
+        &mut self,
+        (x, y, layer): (usize, usize, usize),
+        f: impl FnOnce(SlimePropsIn<S>) -> SlimePropsOut<S>,
+    ) {
+        if let Some(grid) = self.layer_mut(layer) {
+            grid.mut_slime_with((x, y), f);
+        }
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        (x, y, layer): (usize, usize, usize),
+        f: impl FnOnce(SlimeState<S>) -> SlimeState<S>,
+    ) {
+        if let Some(grid) = self.layer_mut(layer) {
+            grid.impulse_jiggle_with((x, y), f);
+        }
+    }
+    fn on_popped(&mut self, (x, y, layer): (usize, usize, usize)) {
+        if let Some(grid) = self.layer_mut(layer) {
+            grid.on_popped((x, y));
+        }
+    }
+    /// Only within a layer.
+    fn exchange_slimes(
+        &mut self,
+        (ax, ay, a_layer): (usize, usize, usize),
+        (bx, by, b_layer): (usize, usize, usize),
+    ) -> Option<(S, S)> {
+        if a_layer != b_layer {
+            return None;
+        }
+        self.layer_mut(a_layer)?.exchange_slimes((ax, ay), (bx, by))
+    }
+    fn remove_slime(&mut self, (x, y, layer): (usize, usize, usize)) -> bool {
+        self.layer_mut(layer)
+            .is_some_and(|grid| grid.remove(x, y).is_some())
+    }
+    fn take_slime(&mut self, (x, y, layer): (usize, usize, usize)) -> Option<(SlimeState<S>, S)> {
+        self.layer_mut(layer)?.take_slime((x, y))
+    }
+    fn set_slime(
+        &mut self,
+        (x, y, layer): (usize, usize, usize),
+        state: SlimeState<S>,
+        y_bottom: S,
+    ) -> bool {
+        self.layer_mut(layer)
+            .is_some_and(|grid| grid.set_slime((x, y), state, y_bottom))
+    }
+    /// Fills layer 0's columns first, then layer 1's, and so on, and fails if any layer would.
+    fn push_bottom_row(&mut self, row: impl Iterator<Item = SlimeState<S>>, lift: S) -> bool {
+        let mut row: Vec<_> = row.collect();
+        let width = self.layers.first().map_or(0, DenseGridBoard::width);
+        row.truncate(width * self.depth());
+        let counts = |layer: usize| row.len().saturating_sub(layer * width).min(width);
+        if !(0..self.depth())
+            .all(|layer| counts(layer) == 0 || self.layers[layer].has_room_below(counts(layer)))
+        {
+            return false;
+        }
+        let mut row = row.into_iter();
+        for grid in &mut self.layers {
+            grid.push_bottom_row(row.by_ref().take(width), lift);
+        }
+        true
+    }
+    fn loc_index(&self, (x, y, layer): (usize, usize, usize)) -> Option<usize> {
+        let grid = self.layer(layer)?;
+        let index = grid.loc_index((x, y))?;
+        Some(layer * grid.width() * grid.height() + index)
+    }
+}
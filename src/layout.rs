@@ -0,0 +1,104 @@
+//! Remembering where a board's slimes are between steps, for boards that rarely change shape.
+
+use alloc::vec::Vec;
+
+use crate::{GravityDirection, JigglyBoard, Scalar, StaleLayout};
+
+/// Every column of a board, bottom first, worked out once rather than from [`JigglyBoard::cols`] every
+/// step. Pass it to [`JigglyBoard::run_physics_cached`].
+///
+/// It's filled the first time it's used, and again after [`BoardLayoutCache::invalidate`], which the
+/// board has to call whenever `cols` would give something different, like when a slime is added or
+/// removed. Each use still checks that the board has as many columns as it remembers, and as many
+/// slimes in each column whose iterator knows its exact length, so forgetting to is an error rather than
+/// slimes being stepped in the wrong place. Gravity flipping is picked up without any of that.
+pub struct BoardLayoutCache<Loc> {
+    /// Every column's slimes, bottom first, one column after another.
+    locs: Vec<Loc>,
+    /// Where each column is in `locs`.
+    cols: Vec<(usize, usize)>,
+    /// What `locs` counts as the bottom.
+    gravity_dir: GravityDirection,
+    filled: bool,
+}
+
+impl<Loc: Copy> BoardLayoutCache<Loc> {
+    pub fn new() -> Self {
+        BoardLayoutCache {
+            locs: Vec::new(),
+            cols: Vec::new(),
+            gravity_dir: GravityDirection::Down,
+            filled: false,
+        }
+    }
+    /// Have the next step lay the board out again, after it's changed shape.
+    pub fn invalidate(&mut self) {
+        self.filled = false;
+    }
+    /// Whether it's been filled since it was made or last invalidated.
+    pub fn is_filled(&self) -> bool {
+        self.filled
+    }
+    /// Fill it from `board` if it isn't, otherwise check it still fits.
+    pub(crate) fn refresh<S: Scalar, B: JigglyBoard<S, Loc = Loc> + ?Sized>(
+        &mut self,
+        board: &B,
+    ) -> Result<(), StaleLayout> {
+        let gravity_dir = board.gravity_dir();
+        if !self.filled {
+            self.locs.clear();
+            self.cols.clear();
+            for col in board.cols() {
+                let start = self.locs.len();
+                self.locs.extend(col);
+                if gravity_dir == GravityDirection::Up {
+                    self.locs[start..].reverse();
+                }
+                self.cols.push((start, self.locs.len()));
+            }
+            self.gravity_dir = gravity_dir;
+            self.filled = true;
+            return Ok(());
+        }
+        let mut count = 0;
+        for (column, col) in board.cols().enumerate() {
+            count += 1;
+            let Some(&(start, end)) = self.cols.get(column) else {
+                continue;
+            };
+            if let (lower, Some(upper)) = col.size_hint()
+                && lower == upper
+                && lower != end - start
+            {
+                return Err(StaleLayout::ColumnLength {
+                    column,
+                    cached: end - start,
+                    actual: lower,
+                });
+            }
+        }
+        if count != self.cols.len() {
+            return Err(StaleLayout::ColumnCount {
+                cached: self.cols.len(),
+                actual: count,
+            });
+        }
+        if gravity_dir != self.gravity_dir {
+            for &(start, end) in &self.cols {
+                self.locs[start..end].reverse();
+            }
+            self.gravity_dir = gravity_dir;
+        }
+        Ok(())
+    }
+    /// Every column's slimes, bottom first, one column after another, and where each column is in them.
+    pub(crate) fn columns(&self) -> (&[Loc], &[(usize, usize)]) {
+        (&self.locs, &self.cols)
+    }
+}
+
+impl<Loc: Copy> Default for BoardLayoutCache<Loc> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
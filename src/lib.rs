@@ -1,68 +1,975 @@
 #![no_std]
 
-use core::ops::{Add, Mul};
-
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
-pub struct PhysicsProperties {
-    pub gravity: f32,
-    pub velocity_to_impact: f32,
-    pub min_impactable: f32,
-    pub jiggle_stiff: f32,
-    pub jiggle_damp: f32,
-    pub jiggle_life_decrease_rate: f32,
-    pub jiggle_life_threshold: f32,
-    pub jiggle_life_threshold_inverse: f32,
-    pub jiggle_offset_epsilon: f32,
-    pub jiggle_momentum_epsilon: f32,
+use scalar::cast;
+
+#[cfg(feature = "alloc")]
+mod active;
+mod agitation;
+mod bridge;
+mod builder;
+#[cfg(feature = "alloc")]
+mod dense;
+mod directions;
+#[cfg(feature = "alloc")]
+mod dump;
+#[cfg(feature = "alloc")]
+mod erased;
+mod error;
+mod ext;
+mod fixed;
+#[cfg(feature = "alloc")]
+mod grid;
+mod group;
+#[cfg(feature = "alloc")]
+mod hex;
+mod impulse;
+mod interp;
+#[cfg(feature = "alloc")]
+mod invariants;
+#[cfg(feature = "alloc")]
+mod layered;
+#[cfg(feature = "alloc")]
+mod layout;
+mod material;
+mod math;
+mod observe;
+#[cfg(feature = "alloc")]
+mod par;
+mod pending;
+#[cfg(feature = "alloc")]
+mod rollback;
+mod scalar;
+#[cfg(feature = "alloc")]
+mod shake;
+mod small;
+#[cfg(feature = "alloc")]
+mod snapshot;
+mod spring;
+mod step;
+mod stepper;
+mod storage;
+mod validate;
+
+pub use agitation::{Agitation, AgitationWeights};
+pub use bridge::{BoardBridge, EscapedImpulse};
+pub use builder::PhysicsPropertiesBuilder;
+#[cfg(feature = "alloc")]
+pub use dense::{ColumnResult, step_column_slice};
+pub use directions::{Cardinal4, Diag8, GridDirection, Layered};
+#[cfg(feature = "alloc")]
+pub use erased::{JiggleImpulsableDyn, JigglyBoardDyn, MutSlimeDyn};
+pub use error::{PhysicsPropertiesError, RollbackError, StaleLayout};
+pub use ext::JigglyBoardExt;
+pub use fixed::Fixed;
+#[cfg(feature = "alloc")]
+pub use grid::{DenseGridBoard, GridCell};
+pub use group::GroupId;
+#[cfg(feature = "alloc")]
+pub use hex::{HexBoard, HexDirection};
+pub use interp::RenderProps;
+#[cfg(feature = "alloc")]
+pub use invariants::InvariantViolation;
+#[cfg(feature = "alloc")]
+pub use layered::LayeredGridBoard;
+#[cfg(feature = "alloc")]
+pub use layout::BoardLayoutCache;
+pub use material::Material;
+pub use observe::{PhysicsObserver, PropagationEvent, SlimeEvent, StepStats};
+#[cfg(feature = "parallel")]
+pub use par::ParallelBoard;
+#[cfg(feature = "alloc")]
+pub use par::{ColumnJob, ColumnMut};
+pub use pending::{PhysicsScratch, PropagationOverflow, StepBudget};
+#[cfg(feature = "alloc")]
+pub use rollback::RollbackBuffer;
+pub use scalar::Scalar;
+#[cfg(feature = "alloc")]
+pub use shake::RngSource;
+#[cfg(feature = "alloc")]
+pub use snapshot::{BoardSnapshot, SnapshotEntry};
+pub use stepper::{FixedStepper, StepReport};
+#[cfg(feature = "heapless")]
+pub use storage::ArrayStorage;
+#[cfg(all(feature = "heapless", not(feature = "alloc")))]
+pub use storage::DEFAULT_ARRAY_CAPACITY;
+#[cfg(feature = "alloc")]
+pub use storage::HeapStorage;
+pub use storage::{DefaultStorage, ScratchStorage};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhysicsProperties<S = f32> {
+    pub gravity: S,
+    pub velocity_to_impact: S,
+    pub min_impactable: S,
+    pub jiggle_stiff: S,
+    /// Spring stiffness while stretched (negative offset). `None` uses `jiggle_stiff` both ways.
+    pub jiggle_stiff_stretch: Option<S>,
+    pub jiggle_damp: S,
+    pub jiggle_life_decrease_rate: S,
+    pub jiggle_life_threshold: S,
+    pub jiggle_life_threshold_inverse: S,
+    pub jiggle_offset_epsilon: S,
+    pub jiggle_momentum_epsilon: S,
+    /// How `jiggle_damp` is applied to momentum.
+    pub damping_mode: DampingMode,
+    /// Falling slimes never go faster than this. Infinity means no limit.
+    pub terminal_velocity: S,
+    /// Multiplied onto impulses travelling sideways, after `apply_dir_to_loc`'s own falloff.
+    pub transfer_horizontal: S,
+    /// Multiplied onto impulses travelling `Direction::UP`, after `apply_dir_to_loc`'s own falloff.
+    pub transfer_up: S,
+    /// Multiplied onto impulses travelling opposite to `Direction::UP`, after `apply_dir_to_loc`'s own falloff.
+    pub transfer_down: S,
+    /// Jiggle offset is clamped to `±max_offset`. Infinity means no limit.
+    pub max_offset: S,
+    /// What happens to the momentum that was pushing the offset past `max_offset`.
+    pub offset_limit_mode: OffsetLimitMode,
+    /// Jiggling slimes never report a `y_scale` below this, so they can't go fully flat.
+    pub min_y_scale: S,
+    /// How `x_scale` is derived from `y_scale`.
+    pub squash_mode: SquashMode<S>,
+    /// How much a falling slime stretches with speed.
+    pub fall_stretch: FallStretch<S>,
+    /// What happens to `life` when an already jiggling slime gets another impulse.
+    pub life_refresh: LifeRefresh<S>,
+    /// `run_physics` splits any `dt` longer than this into equal sub-steps. Infinity disables it.
+    pub max_dt: S,
+    /// Upper bound on the number of sub-steps for one `run_physics` call, however big `dt` is.
+    pub max_substeps: u32,
+    /// Jiggle momentum is clamped to `±max_momentum`, both when integrating and when impulses pile up.
+    pub max_momentum: S,
+    /// How tall one slime is in `y_bottom` units. Velocities and gravity stay in cells per second.
+    pub cell_height: S,
+    /// How far settled slimes' `y_scale` drifts from 1.0 while idly breathing. 0.0 turns it off.
+    ///
+    /// The wobble is purely visual: settled slimes still count as settled and still take up a full cell.
+    pub idle_wobble_amplitude: S,
+    /// Breaths per second of the idle wobble, driven by `JigglyBoard::idle_clock`.
+    pub idle_wobble_frequency: S,
+    /// Fraction of its speed a landing slime keeps as it bounces back up. 0.0 disables bouncing.
+    pub restitution: S,
+    /// Landings slower than this stop bouncing and start jiggling.
+    pub bounce_min_velocity: S,
+    /// Seconds a slime hangs in place before it starts to fall, see [`SlimeState::falling`].
+    pub fall_delay: S,
+    /// Whether landing impulses come from speed, distance fallen, or both.
+    pub impact_mode: ImpactMode<S>,
+    /// Upward momentum, before mass, given to the slime below one that [`JigglyBoardExt::begin_fall`]
+    /// drops, as the goo between them parts. 0 turns it off.
+    pub detach_stretch: S,
+    /// Whether landings bounce by restitution or a fixed number of hops.
+    pub bounce_mode: BounceMode<S>,
+    /// Propagation stops this many cells away from where it started, whatever impulse is left.
+    pub max_propagation_hops: u32,
+    /// Jiggle propagations applied per step at most, each along with every slime it spreads to. The rest
+    /// wait in the [`PhysicsScratch`] and go first next step, so a huge cascade is spread over a few
+    /// frames rather than causing a hitch. `None` applies everything straight away.
+    ///
+    /// Only holds across calls given the same scratch, like [`JigglyBoard::run_physics_with_scratch`];
+    /// calls that make their own apply whatever is left before returning, and
+    /// [`JigglyBoard::run_physics_partial`] goes by its [`StepBudget`] instead. Zero counts as one.
+    pub max_propagations_per_step: Option<u32>,
+    /// What stops a wave going round and round a board whose edges wrap.
+    pub cycle_guard: CycleGuard,
+    /// Whether a step's waves each travel on their own, or add up where they meet.
+    pub wave_mode: WaveMode,
+    /// Whether slimes keep everything a wave gives them, or only what they don't pass on.
+    pub conservation_mode: ConservationMode,
+    /// Which cells a wave reaches first as it spreads.
+    pub propagation_order: PropagationOrder,
+    /// How much of a landing's impulse is also sent up into the slimes stacked above it, on top of the
+    /// usual wave that only travels down and across. 0 keeps waves from ever heading up from a landing.
+    ///
+    /// A slime above that's still falling is knocked back up by it rather than set jiggling.
+    pub propagate_up_factor: S,
+    /// Fraction of a wave's impulse that bounces back off the edge of the board, like a ripple in a jelly
+    /// tray. 0 lets waves die at the edge.
+    ///
+    /// Wherever `apply_dir_to_loc` stops a wave heading sideways, it's sent back into the slime it's in as
+    /// if it had arrived from the blocked direction, and so heads back the way it came. The bounce counts
+    /// as a hop and still has to clear `min_impactable`, so it can't ping-pong forever. Waves under
+    /// `CycleGuard::VisitOnce` or `WaveMode::Coalesced` never reach a slime twice, so they don't bounce.
+    pub edge_reflectance: S,
+    /// Raises the `min_impactable` cutoff the further a wave travels, as
+    /// `min_impactable * (1 + hops * cutoff_growth)`, so ripples fade out instead of stopping at a hard edge.
+    pub cutoff_growth: S,
+    /// How `life` runs down, and how the envelope below `jiggle_life_threshold` follows it.
+    pub life_curve: LifeCurve,
+    /// Whether the life envelope scales what is shown, or the spring state itself.
+    pub envelope_mode: EnvelopeMode,
+    /// Momentum per unit of `amount` a squished slime springs back with when it's let go.
+    pub squish_release_momentum: S,
+    /// Seconds a `SlimeState::Spawning` slime takes to grow to full size.
+    pub spawn_duration: S,
+    /// Seconds a `SlimeState::Popping` slime takes to shrink away.
+    pub pop_duration: S,
+    /// Fraction of its sideways speed a sliding slime keeps when it bounces off a blocked column.
+    pub slide_bounce: S,
+    /// How long [`JigglyBoardExt::swap`] takes to move two slimes past each other, in seconds.
+    pub swap_duration: S,
+    /// Momentum both slimes arrive with at the end of a swap.
+    pub swap_momentum: S,
+    /// How long a `SlimeState::Merging` slime takes to shrink into its neighbour, in seconds.
+    pub merge_duration: S,
+    /// Impulse the neighbour receives when a merge into it completes, before mass.
+    pub merge_impulse: S,
+}
+
+/// How the life envelope below `jiggle_life_threshold` is applied to a jiggling slime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnvelopeMode {
+    /// The envelope scales the shown offset and the settle check; the spring itself only loses energy to damping.
+    #[default]
+    Visual,
+    /// The envelope is multiplied into offset and momentum every step. This compounds, so the tail of a
+    /// jiggle decays faster at higher frame rates. Kept for tunings made before `Visual` existed.
+    Legacy,
+}
+
+/// The shape of a jiggle's lifetime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LifeCurve {
+    /// `life` drops by `jiggle_life_decrease_rate` per second and the envelope follows it linearly.
+    #[default]
+    Linear,
+    /// `life` is multiplied by `e^(-jiggle_life_decrease_rate * dt)`, so it tails off instead of stopping.
+    Exponential,
+    /// `life` drops linearly, but the envelope eases into zero instead of cutting off.
+    SmoothStep,
+}
+
+impl LifeCurve {
+    pub fn advance<S: Scalar>(self, life: S, rate: S, dt: S) -> S {
+        match self {
+            LifeCurve::Linear | LifeCurve::SmoothStep => life - rate * dt,
+            LifeCurve::Exponential => life * (-rate * dt).exp(),
+        }
+    }
+    /// How much of the jiggle survives at `life`, for lives below the threshold.
+    pub fn envelope<S: Scalar>(self, life: S, threshold_inverse: S) -> S {
+        let t = life * threshold_inverse;
+        match self {
+            LifeCurve::Linear | LifeCurve::Exponential => t,
+            LifeCurve::SmoothStep => smooth_step(t),
+        }
+    }
+}
+
+/// `3t² - 2t³` of `t` clamped to `[0, 1]`.
+pub(crate) fn smooth_step<S: Scalar>(t: S) -> S {
+    let t = t.clamp(S::ZERO, S::ONE);
+    t * t * (S::from_f64(3.0) - S::from_f64(2.0) * t)
+}
+
+/// How a jiggling slime's `life` is refreshed by a new impulse.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LifeRefresh<S = f32> {
+    /// Back to 1.0 every time. Cells that keep getting poked never die down.
+    #[default]
+    Reset,
+    /// The larger of the current life and `impulse * per_impulse` (capped at 1.0).
+    Max { per_impulse: S },
+    /// `impulse * per_impulse` is added to the current life, capped at `cap`.
+    Add { per_impulse: S, cap: S },
+}
+
+impl<S: Scalar> LifeRefresh<S> {
+    pub fn refresh(self, life: S, impulse: S) -> S {
+        match self {
+            LifeRefresh::Reset => S::ONE,
+            LifeRefresh::Max { per_impulse } => life.max((impulse * per_impulse).min(S::ONE)),
+            LifeRefresh::Add { per_impulse, cap } => (life + impulse * per_impulse).min(cap),
+        }
+    }
+    /// The same in another [`Scalar`].
+    pub fn cast<T: Scalar>(self) -> LifeRefresh<T> {
+        match self {
+            LifeRefresh::Reset => LifeRefresh::Reset,
+            LifeRefresh::Max { per_impulse } => LifeRefresh::Max {
+                per_impulse: cast(per_impulse),
+            },
+            LifeRefresh::Add { per_impulse, cap } => LifeRefresh::Add {
+                per_impulse: cast(per_impulse),
+                cap: cast(cap),
+            },
+        }
+    }
+}
+
+/// The curve from falling velocity to `y_scale`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FallStretch<S = f32> {
+    /// `1.0 + velocity * per_velocity`, clamped to `[1.0, max]`.
+    Linear { per_velocity: S, max: S },
+    /// Eases from 1.0 at rest to `max` at `full_velocity` and beyond.
+    SmoothStep { full_velocity: S, max: S },
+}
+
+impl<S: Scalar> Default for FallStretch<S> {
+    fn default() -> Self {
+        FallStretch::DEFAULT.cast()
+    }
+}
+
+impl FallStretch {
+    pub const DEFAULT: Self = FallStretch::Linear {
+        per_velocity: 1.0 / 9.0,
+        max: 2.0,
+    };
+}
+
+impl<S: Scalar> FallStretch<S> {
+    pub fn y_scale(self, velocity: S) -> S {
+        match self {
+            FallStretch::Linear { per_velocity, max } => {
+                velocity.mul(per_velocity).add(S::ONE).clamp(S::ONE, max)
+            }
+            FallStretch::SmoothStep { full_velocity, max } => {
+                let eased = smooth_step(velocity / full_velocity);
+                S::ONE + eased * (max - S::ONE)
+            }
+        }
+    }
+    /// The same in another [`Scalar`].
+    pub fn cast<T: Scalar>(self) -> FallStretch<T> {
+        match self {
+            FallStretch::Linear { per_velocity, max } => FallStretch::Linear {
+                per_velocity: cast(per_velocity),
+                max: cast(max),
+            },
+            FallStretch::SmoothStep { full_velocity, max } => FallStretch::SmoothStep {
+                full_velocity: cast(full_velocity),
+                max: cast(max),
+            },
+        }
+    }
+}
+
+/// How wide a slime gets as it squashes and stretches.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SquashMode<S = f32> {
+    /// The original formulas, which lose volume on big squashes.
+    #[default]
+    Legacy,
+    /// `x_scale = 1.0 / y_scale`, clamped to the given range.
+    PreserveArea { min_x_scale: S, max_x_scale: S },
+}
+
+impl<S: Scalar> SquashMode<S> {
+    /// `None` means use the legacy formula for that state.
+    pub(crate) fn preserve_area(self, y_scale: S) -> Option<S> {
+        match self {
+            SquashMode::Legacy => None,
+            SquashMode::PreserveArea {
+                min_x_scale,
+                max_x_scale,
+            } => Some(y_scale.recip().clamp(min_x_scale, max_x_scale)),
+        }
+    }
+    /// The same in another [`Scalar`].
+    pub fn cast<T: Scalar>(self) -> SquashMode<T> {
+        match self {
+            SquashMode::Legacy => SquashMode::Legacy,
+            SquashMode::PreserveArea {
+                min_x_scale,
+                max_x_scale,
+            } => SquashMode::PreserveArea {
+                min_x_scale: cast(min_x_scale),
+                max_x_scale: cast(max_x_scale),
+            },
+        }
+    }
+}
+
+/// How big a landing impulse is, before mass.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ImpactMode<S = f32> {
+    /// `PhysicsProperties::velocity_to_impact` times the landing speed.
+    #[default]
+    Velocity,
+    /// `per_cell` times the number of cells fallen, so it scales with the drop even under low gravity.
+    Distance { per_cell: S },
+    /// Both of the above added together.
+    Combined { per_cell: S },
+}
+
+impl<S: Scalar> ImpactMode<S> {
+    /// The impulse for a landing with `velocity_impact` from the speed, after falling `cells_fallen`.
+    pub fn impact(self, velocity_impact: S, cells_fallen: S) -> S {
+        match self {
+            ImpactMode::Velocity => velocity_impact,
+            ImpactMode::Distance { per_cell } => per_cell * cells_fallen,
+            ImpactMode::Combined { per_cell } => velocity_impact + per_cell * cells_fallen,
+        }
+    }
+    /// The same in another [`Scalar`].
+    pub fn cast<T: Scalar>(self) -> ImpactMode<T> {
+        match self {
+            ImpactMode::Velocity => ImpactMode::Velocity,
+            ImpactMode::Distance { per_cell } => ImpactMode::Distance {
+                per_cell: cast(per_cell),
+            },
+            ImpactMode::Combined { per_cell } => ImpactMode::Combined {
+                per_cell: cast(per_cell),
+            },
+        }
+    }
+}
+
+/// How a falling slime decides whether to bounce when it lands.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum BounceMode<S = f32> {
+    /// Bounce back at `PhysicsProperties::restitution` of the landing speed for as long as that's above
+    /// `PhysicsProperties::bounce_min_velocity`.
+    #[default]
+    Restitution,
+    /// Always do exactly `bounces` hops, each leaving at `factor` (0 to 1) of the speed it landed with,
+    /// then settle. Every contact sends a landing impulse.
+    Count { bounces: u32, factor: S },
+}
+
+impl<S: Scalar> BounceMode<S> {
+    /// The same in another [`Scalar`].
+    pub fn cast<T: Scalar>(self) -> BounceMode<T> {
+        match self {
+            BounceMode::Restitution => BounceMode::Restitution,
+            BounceMode::Count { bounces, factor } => BounceMode::Count {
+                bounces,
+                factor: cast(factor),
+            },
+        }
+    }
+}
+
+/// How jiggle propagation avoids running in circles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CycleGuard {
+    /// Nothing beyond never heading straight back where it came from; waves only die out by falling below
+    /// `PhysicsProperties::min_impactable` or running out of `PhysicsProperties::max_propagation_hops`.
+    #[default]
+    None,
+    /// Stop wherever `apply_dir_to_loc` and the transfer don't leave the impulse any smaller than it came in.
+    StrictDecrease,
+    /// Reach each slime at most once per wave, so a wave from one landing never comes back round to
+    /// anything it already shook. Only slimes with a [`JigglyBoard::loc_index`] are kept track of.
+    /// Without the `alloc` feature there's only room for 64 of them, and a wave stops at any slime past
+    /// that, telling [`PhysicsObserver::truncated`].
+    VisitOnce,
+}
+
+/// How the jiggle propagations sent out in one step combine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WaveMode {
+    /// Every propagation spreads out across the board on its own.
+    #[default]
+    Separate,
+    /// All of a step's propagations travel as one wave front. Whatever reaches a slime on the same hop
+    /// is added up and applied once, and it passes the total on once, only if that's above
+    /// `PhysicsProperties::min_impactable`, and no slime is reached twice. This keeps dense cascades from
+    /// doing the same work over and over. Needs [`JigglyBoard::loc_index`]; boards without it work as
+    /// `Separate`.
+    Coalesced,
+}
+
+/// How a wave's impulse is shared between the slimes it reaches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConservationMode {
+    /// Every slime keeps all it receives, and passes it on as well, so a landing on a dense board can
+    /// set far more jiggling than its impact had in it.
+    #[default]
+    Additive,
+    /// Every slime keeps only what it doesn't pass on. If its neighbours would get more between them than
+    /// it received, they share out all of it in proportion and it keeps nothing. A wave then never gives
+    /// out more than it started with. `WaveMode::Coalesced` waves add up as usual.
+    Conserving,
 }
 
-pub enum SlimeState {
+/// The order a spreading wave reaches slimes in.
+///
+/// A wave reaches the same slimes with the same impulses either way; only the order it gets to them in
+/// changes. `WaveMode::Coalesced` waves always spread out a hop at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PropagationOrder {
+    /// Follow each direction out as far as it goes before trying the next, so a slime two cells one way
+    /// can be reached after one nine cells the other.
+    #[default]
+    DepthFirst,
+    /// Reach every slime one hop away, then every slime two hops away, and so on, so the wave expands
+    /// outwards evenly.
+    BreadthFirst,
+}
+
+/// What a jiggling slime does when it hits `PhysicsProperties::max_offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OffsetLimitMode {
+    /// The momentum heading further out is thrown away.
+    #[default]
+    Discard,
+    /// Only the momentum that would have carried it past the limit that step is kept, turned back towards
+    /// rest, so it rebounds softer the less it overshot, and never harder than it came in.
+    Bleed,
+}
+
+/// How `jiggle_damp` is interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DampingMode {
+    /// Momentum is multiplied by `jiggle_damp` once per call to `run_physics`, whatever the dt.
+    ///
+    /// This is what older tunings were made with, but it damps twice as fast at 120Hz as at 60Hz.
+    #[default]
+    PerStep,
+    /// `jiggle_damp` is the fraction of momentum retained after one second, applied as `jiggle_damp^dt`.
+    PerSecond,
+}
+
+impl DampingMode {
+    pub(crate) fn factor<S: Scalar>(self, damp: S, dt: S) -> S {
+        match self {
+            DampingMode::PerStep => damp,
+            DampingMode::PerSecond => damp.powf(dt),
+        }
+    }
+}
+
+impl<S: Scalar> Default for PhysicsProperties<S> {
+    fn default() -> Self {
+        PhysicsProperties::gelatin().cast()
+    }
+}
+
+impl<S: Scalar> PhysicsProperties<S> {
+    /// The smallest impulse that still does anything after travelling `hops` cells.
+    pub fn min_impactable_at(&self, hops: u32) -> S {
+        self.min_impactable * (S::ONE + S::from_u32(hops) * self.cutoff_growth)
+    }
+    /// How many sub-steps `run_physics` splits `dt` into.
+    pub fn substeps_for(&self, dt: S) -> u32 {
+        if dt <= self.max_dt || dt.is_nan() {
+            return 1;
+        }
+        let whole = (dt / self.max_dt).to_u32();
+        let needed = if S::from_u32(whole) * self.max_dt < dt {
+            whole.saturating_add(1)
+        } else {
+            whole
+        };
+        needed.clamp(1, self.max_substeps.max(1))
+    }
+    /// The transfer coefficient for an impulse travelling in `dir`.
+    pub fn transfer_for<D: Direction + PartialEq>(&self, dir: D) -> S {
+        if dir == D::UP {
+            self.transfer_up
+        } else if dir == D::UP.opposite() {
+            self.transfer_down
+        } else {
+            self.transfer_horizontal
+        }
+    }
+    /// The same tuning in another [`Scalar`], e.g. `PhysicsProperties::bouncy().cast::<f64>()`.
+    ///
+    /// `jiggle_life_threshold_inverse` is worked out again in `T`, so it still passes `validate` when `T`
+    /// rounds `jiggle_life_threshold` differently, as [`Fixed`] does.
+    pub fn cast<T: Scalar>(&self) -> PhysicsProperties<T> {
+        PhysicsProperties {
+            gravity: cast(self.gravity),
+            velocity_to_impact: cast(self.velocity_to_impact),
+            min_impactable: cast(self.min_impactable),
+            jiggle_stiff: cast(self.jiggle_stiff),
+            jiggle_stiff_stretch: self.jiggle_stiff_stretch.map(cast),
+            jiggle_damp: cast(self.jiggle_damp),
+            jiggle_life_decrease_rate: cast(self.jiggle_life_decrease_rate),
+            jiggle_life_threshold: cast(self.jiggle_life_threshold),
+            jiggle_life_threshold_inverse: cast::<S, T>(self.jiggle_life_threshold).recip(),
+            jiggle_offset_epsilon: cast(self.jiggle_offset_epsilon),
+            jiggle_momentum_epsilon: cast(self.jiggle_momentum_epsilon),
+            damping_mode: self.damping_mode,
+            terminal_velocity: cast(self.terminal_velocity),
+            transfer_horizontal: cast(self.transfer_horizontal),
+            transfer_up: cast(self.transfer_up),
+            transfer_down: cast(self.transfer_down),
+            max_offset: cast(self.max_offset),
+            offset_limit_mode: self.offset_limit_mode,
+            min_y_scale: cast(self.min_y_scale),
+            squash_mode: self.squash_mode.cast(),
+            fall_stretch: self.fall_stretch.cast(),
+            life_refresh: self.life_refresh.cast(),
+            max_dt: cast(self.max_dt),
+            max_substeps: self.max_substeps,
+            max_momentum: cast(self.max_momentum),
+            cell_height: cast(self.cell_height),
+            idle_wobble_amplitude: cast(self.idle_wobble_amplitude),
+            idle_wobble_frequency: cast(self.idle_wobble_frequency),
+            restitution: cast(self.restitution),
+            bounce_min_velocity: cast(self.bounce_min_velocity),
+            fall_delay: cast(self.fall_delay),
+            impact_mode: self.impact_mode.cast(),
+            detach_stretch: cast(self.detach_stretch),
+            bounce_mode: self.bounce_mode.cast(),
+            max_propagation_hops: self.max_propagation_hops,
+            max_propagations_per_step: self.max_propagations_per_step,
+            cycle_guard: self.cycle_guard,
+            wave_mode: self.wave_mode,
+            conservation_mode: self.conservation_mode,
+            propagation_order: self.propagation_order,
+            propagate_up_factor: cast(self.propagate_up_factor),
+            edge_reflectance: cast(self.edge_reflectance),
+            cutoff_growth: cast(self.cutoff_growth),
+            life_curve: self.life_curve,
+            envelope_mode: self.envelope_mode,
+            squish_release_momentum: cast(self.squish_release_momentum),
+            spawn_duration: cast(self.spawn_duration),
+            pop_duration: cast(self.pop_duration),
+            slide_bounce: cast(self.slide_bounce),
+            swap_duration: cast(self.swap_duration),
+            swap_momentum: cast(self.swap_momentum),
+            merge_duration: cast(self.merge_duration),
+            merge_impulse: cast(self.merge_impulse),
+        }
+    }
+}
+
+/// The presets are all `f32`; [`PhysicsProperties::cast`] them for anything else.
+impl PhysicsProperties {
+    pub fn builder() -> PhysicsPropertiesBuilder {
+        PhysicsPropertiesBuilder::new()
+    }
+    /// A soft, wobbly general-purpose tuning. This is what `Default` gives you.
+    pub const fn gelatin() -> Self {
+        let jiggle_life_threshold = 0.3;
+        Self {
+            gravity: 40.0,
+            velocity_to_impact: 0.5,
+            min_impactable: 0.1,
+            jiggle_stiff: 400.0,
+            jiggle_stiff_stretch: None,
+            jiggle_damp: 0.92,
+            jiggle_life_decrease_rate: 1.0,
+            jiggle_life_threshold,
+            jiggle_life_threshold_inverse: 1.0 / jiggle_life_threshold,
+            jiggle_offset_epsilon: 0.01,
+            jiggle_momentum_epsilon: 0.05,
+            damping_mode: DampingMode::PerStep,
+            terminal_velocity: f32::INFINITY,
+            transfer_horizontal: 1.0,
+            transfer_up: 1.0,
+            transfer_down: 1.0,
+            max_offset: f32::INFINITY,
+            offset_limit_mode: OffsetLimitMode::Discard,
+            min_y_scale: 0.0,
+            squash_mode: SquashMode::Legacy,
+            fall_stretch: FallStretch::DEFAULT,
+            life_refresh: LifeRefresh::Reset,
+            max_dt: f32::INFINITY,
+            max_substeps: 8,
+            max_momentum: f32::INFINITY,
+            cell_height: 1.0,
+            idle_wobble_amplitude: 0.0,
+            idle_wobble_frequency: 0.5,
+            restitution: 0.0,
+            bounce_min_velocity: 2.0,
+            fall_delay: 0.0,
+            impact_mode: ImpactMode::Velocity,
+            detach_stretch: 0.0,
+            bounce_mode: BounceMode::Restitution,
+            max_propagation_hops: u32::MAX,
+            max_propagations_per_step: None,
+            cycle_guard: CycleGuard::None,
+            wave_mode: WaveMode::Separate,
+            conservation_mode: ConservationMode::Additive,
+            propagation_order: PropagationOrder::DepthFirst,
+            propagate_up_factor: 0.0,
+            edge_reflectance: 0.0,
+            cutoff_growth: 0.0,
+            life_curve: LifeCurve::Linear,
+            envelope_mode: EnvelopeMode::Visual,
+            squish_release_momentum: 10.0,
+            spawn_duration: 0.25,
+            pop_duration: 0.2,
+            slide_bounce: 0.5,
+            swap_duration: 0.15,
+            swap_momentum: 0.4,
+            merge_duration: 0.25,
+            merge_impulse: 2.0,
+        }
+    }
+    /// Firm slimes that snap back quickly and barely ripple.
+    pub const fn stiff() -> Self {
+        let jiggle_life_threshold = 0.4;
+        Self {
+            gravity: 50.0,
+            velocity_to_impact: 0.3,
+            min_impactable: 0.2,
+            jiggle_stiff: 1200.0,
+            jiggle_damp: 0.85,
+            jiggle_life_decrease_rate: 2.0,
+            jiggle_life_threshold,
+            jiggle_life_threshold_inverse: 1.0 / jiggle_life_threshold,
+            ..Self::gelatin()
+        }
+    }
+    /// Loose slimes that keep wobbling for a while and spread ripples far.
+    pub const fn bouncy() -> Self {
+        let jiggle_life_threshold = 0.25;
+        Self {
+            gravity: 35.0,
+            velocity_to_impact: 0.8,
+            min_impactable: 0.05,
+            jiggle_stiff: 300.0,
+            jiggle_damp: 0.97,
+            jiggle_life_decrease_rate: 0.7,
+            jiggle_life_threshold,
+            jiggle_life_threshold_inverse: 1.0 / jiggle_life_threshold,
+            ..Self::gelatin()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SlimeState<S = f32> {
+    /// At rest on the top of its stack. If it finds itself well above that, because whatever was under it
+    /// has been removed, it starts falling by itself.
     Settled,
-    Falling {
-        velocity: f32,
+    /// Hanging where it is, still holding up the slimes above, until `remaining` runs out and it falls.
+    AboutToFall { remaining: S },
+    /// Dropping at `velocity` cells per second, having come `fallen` cells since it started falling.
+    ///
+    /// Start falls with `fallen: 0.0`, or use [`SlimeState::falling`].
+    Falling { velocity: S, fallen: S },
+    /// Between hops of a `BounceMode::Count` bounce, with `bounces_left` more to go after the next landing.
+    ///
+    /// Moves just like `Falling`; negative `velocity` is upwards.
+    Bouncing {
+        velocity: S,
+        fallen: S,
+        bounces_left: u32,
     },
+    /// Wobbling on its springs. `momentum` and `offset` squash and stretch it vertically; `momentum_x`
+    /// and `offset_x` lean it sideways, with positive towards `Direction::RIGHT`.
     Jiggling {
-        momentum: f32,
-        offset: f32,
-        life: f32,
+        momentum: S,
+        offset: S,
+        life: S,
+        momentum_x: S,
+        offset_x: S,
     },
+    /// Knocked up out of its slot, `height` cells above it and rising at `velocity` cells per second.
+    ///
+    /// The slot stays reserved while it's in the air, and it lands back in it. See [`JigglyBoardExt::launch`].
+    Launched { velocity: S, height: S },
+    /// Held compressed by `amount` (0 to 1) under something pressing down on it, without jiggling.
+    ///
+    /// Counts as at rest. See [`JigglyBoardExt::squish`] and [`JigglyBoardExt::unsquish`].
+    Squished { amount: S },
+    /// Growing in from nothing; `progress` goes from 0 to 1 over `PhysicsProperties::spawn_duration`.
+    ///
+    /// Insert a slime in this state to spawn it. It takes up `progress` of a cell while growing, then
+    /// settles, or falls if it was spawned above the top of its stack.
+    Spawning { progress: S },
+    /// Cleared and shrinking away; `progress` goes from 0 to 1 over `PhysicsProperties::pop_duration`.
+    ///
+    /// It takes up `1 - progress` of a cell, so whatever is stacked on it sinks as it shrinks. When it
+    /// finishes, [`JigglyBoard::on_popped`] is called and the board should remove the slime.
+    Popping { progress: S },
+    /// An ice block: at rest like `Settled`, but it never jiggles and jiggle waves stop dead at it.
+    Frozen,
+    /// Grabbed by the player: physics is paused in place, and incoming impulses pile up in
+    /// `buffered_impulse` until [`JigglyBoardExt::release`]. Nothing about it moves while it's held, so
+    /// it counts as at rest.
+    Held { buffered_impulse: S },
+    /// Moving sideways towards the neighbouring column, `x_offset` cells of the way there so far.
+    ///
+    /// Positive `x_velocity` is towards [`JigglyBoard::try_slide`]'s `positive` side. When it gets a whole
+    /// cell across the board is asked to move it; if it can't, the slime bounces back with `returning` set.
+    /// Start one with [`JigglyBoardExt::shove`].
+    Sliding {
+        x_offset: S,
+        x_velocity: S,
+        returning: bool,
+    },
+    /// Moving into this slot from the one `from_x`, `from_y` cells away as part of a
+    /// [`JigglyBoardExt::swap`]; `progress` goes from 0 to 1 over `PhysicsProperties::swap_duration`.
+    ///
+    /// It holds up its new slot throughout. Impulses arriving mid-swap pile up in `buffered_impulse`
+    /// and are added to the arrival jiggle.
+    Swapping {
+        progress: S,
+        from_x: S,
+        from_y: S,
+        buffered_impulse: S,
+    },
+    /// Shrinking into the neighbour `into_x`, `into_y` cells away; `progress` goes from 0 to 1 over
+    /// `PhysicsProperties::merge_duration`.
+    ///
+    /// It takes up `1 - progress` of a cell. When it finishes, [`JigglyBoard::on_merged`] is called, and
+    /// the neighbour gets `PhysicsProperties::merge_impulse` through the usual jiggle propagation.
+    /// Start one with [`JigglyBoardExt::merge`].
+    Merging { progress: S, into_x: S, into_y: S },
 }
 
-pub struct SlimePropsIn {
-    pub state: SlimeState,
-    pub y_bottom: f32,
+impl<S: Scalar> SlimeState<S> {
+    /// What a slime becomes the moment it lands, before its landing impulse arrives.
+    pub fn landed() -> Self {
+        SlimeState::Jiggling {
+            momentum: S::ZERO,
+            offset: S::ZERO,
+            life: S::ONE,
+            momentum_x: S::ZERO,
+            offset_x: S::ZERO,
+        }
+    }
+    /// The state to put a slime into when its support is gone, honouring `PhysicsProperties::fall_delay`.
+    pub fn falling(physprop: &PhysicsProperties<S>) -> Self {
+        if physprop.fall_delay > S::ZERO {
+            SlimeState::AboutToFall {
+                remaining: physprop.fall_delay,
+            }
+        } else {
+            SlimeState::Falling {
+                velocity: S::ZERO,
+                fallen: S::ZERO,
+            }
+        }
+    }
+    /// Whether a slime in this state is done moving, as far as `run_physics`'s settled result is concerned.
+    pub fn is_at_rest(&self) -> bool {
+        match self {
+            SlimeState::Settled
+            | SlimeState::Squished { .. }
+            | SlimeState::Frozen
+            | SlimeState::Held { .. } => true,
+            SlimeState::Popping { progress } | SlimeState::Merging { progress, .. } => {
+                *progress >= S::ONE
+            }
+            _ => false,
+        }
+    }
 }
 
-pub struct SlimePropsOut {
-    pub state: SlimeState,
-    pub y_bottom: f32,
-    pub y_scale: f32,
-    pub x_scale: f32,
+pub struct SlimePropsIn<S = f32> {
+    pub state: SlimeState<S>,
+    pub y_bottom: S,
 }
 
-pub trait MutSlime {
-    fn modify_slime(&mut self, f: impl FnOnce(SlimePropsIn) -> SlimePropsOut);
+pub struct SlimePropsOut<S = f32> {
+    pub state: SlimeState<S>,
+    pub y_bottom: S,
+    pub y_scale: S,
+    pub x_scale: S,
+    /// How far the slime is drawn across from its column, in cells. Sliding, swapping, merging and sideways jiggling slimes leave it.
+    pub x_offset: S,
 }
 
-pub trait JiggleImpulsable {
-    fn modify_state(&mut self, f: impl FnOnce(SlimeState) -> SlimeState);
+pub trait MutSlime<S = f32> {
+    fn modify_slime(&mut self, f: impl FnOnce(SlimePropsIn<S>) -> SlimePropsOut<S>);
 }
 
-pub trait Direction {
+pub trait JiggleImpulsable<S = f32> {
+    fn modify_state(&mut self, f: impl FnOnce(SlimeState<S>) -> SlimeState<S>);
+}
+
+pub trait Direction: Sized {
     fn other_directions(self) -> impl Iterator<Item = Self>;
     fn opposite(self) -> Self;
     const UP: Self;
+    /// The direction `x_offset` and sideways jiggle count as positive, if the board has one.
+    ///
+    /// Without it, impulses arriving sideways still lean slimes, but always towards positive.
+    const RIGHT: Option<Self> = None;
+    /// Whether a neighbour in this direction is on the positive side, for boards with more than one
+    /// direction that way, like the diagonals of [`HexDirection`]. By default only `RIGHT` is.
+    fn points_right(self) -> bool
+    where
+        Self: PartialEq,
+    {
+        Some(self) == Self::RIGHT
+    }
+    /// Whether an impulse arriving from this direction squashes a slime rather than leaning it over. By
+    /// default only `UP` and its opposite do.
+    fn squashes(self) -> bool
+    where
+        Self: PartialEq,
+    {
+        self == Self::UP || self == Self::UP.opposite()
+    }
+}
+
+/// Which end of its columns a board's slimes settle against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GravityDirection {
+    /// Slimes settle against the start of each column from `JigglyBoard::cols`.
+    #[default]
+    Down,
+    /// Slimes settle against the end of each column from `JigglyBoard::cols`.
+    ///
+    /// `y_bottom` is then measured from that end too, so it grows towards the start of the column.
+    Up,
+}
+
+/// How a [`JigglyBoard::run_physics_steps`] fast-forward went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SettleOutcome {
+    /// How many steps actually ran.
+    pub steps: u32,
+    pub settled: bool,
+}
+
+/// How many slimes are still doing something, from [`JigglyBoard::count_active`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ActiveCounts {
+    /// Falling or bouncing.
+    pub falling: u32,
+    pub jiggling: u32,
+    /// Anything else that isn't `SlimeState::Settled`, like popping or sliding, or that
+    /// [`JigglyBoard::peek_slime`] couldn't look at.
+    pub other: u32,
+}
+
+impl ActiveCounts {
+    pub fn total(&self) -> u32 {
+        self.falling + self.jiggling + self.other
+    }
+}
+
+/// What a slime that isn't settled is doing, from [`JigglyBoard::active_cells`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActiveKind {
+    /// Falling or bouncing.
+    Falling,
+    Jiggling,
+    /// Anything else, like popping or sliding.
+    Other,
 }
 
-pub struct JigglePropagation<Loc, Dir> {
+impl ActiveKind {
+    /// What a slime in `state` is doing, or `None` if it's [at rest](SlimeState::is_at_rest).
+    pub fn of<S: Scalar>(state: &SlimeState<S>) -> Option<Self> {
+        match state {
+            state if state.is_at_rest() => None,
+            SlimeState::Falling { .. } | SlimeState::Bouncing { .. } => Some(ActiveKind::Falling),
+            SlimeState::Jiggling { .. } => Some(ActiveKind::Jiggling),
+            _ => Some(ActiveKind::Other),
+        }
+    }
+}
+
+pub struct JigglePropagation<Loc, Dir, S = f32> {
     pub at: Loc,
-    pub impulse: f32,
+    /// Positive squashes the slimes it reaches, negative stretches them, e.g. for something slamming into
+    /// a stack from underneath. Landings are always positive.
+    ///
+    /// Cutoffs like `PhysicsProperties::min_impactable` go by its size, and `apply_dir_to_loc` is handed it
+    /// with its sign, so falloffs should scale it rather than subtract from it.
+    pub impulse: S,
     pub came_from: Dir,
+    /// How many cells this propagation has already travelled. Landings start at 0.
+    pub hops: u32,
 }
 
-pub trait JigglyBoard {
-    type Dir: Direction + Copy + Clone;
+pub trait JigglyBoard<S: Scalar = f32> {
+    type Dir: Direction + Copy + Clone + PartialEq;
     type Loc: Copy + Clone;
     ///This acts as the "Jiggle Allowed in Direction", "Get new Location", and "Jiggle Falloff/Transfer" function
     ///
@@ -73,179 +980,666 @@ pub trait JigglyBoard {
         &self,
         dir: Self::Dir,
         loc: Self::Loc,
-        impulse: f32,
-    ) -> Option<(Self::Loc, f32)>;
+        impulse: S,
+    ) -> Option<(Self::Loc, S)>;
+    /// [`JigglyBoard::apply_dir_to_loc`] for boards where a slime can have more than one neighbour in the
+    /// same direction, e.g. at a fork. Add every neighbour the impulse reaches, and what reaches it, to
+    /// `out`; jiggle propagation follows each branch on its own, dropping any below `min_impactable`.
+    ///
+    /// Adding nothing is the same as `apply_dir_to_loc` returning `None`. By default this gives whatever
+    /// `apply_dir_to_loc` does.
+    fn apply_dir_to_loc_multi(
+        &self,
+        dir: Self::Dir,
+        loc: Self::Loc,
+        impulse: S,
+        out: &mut impl Extend<(Self::Loc, S)>,
+    ) {
+        out.extend(self.apply_dir_to_loc(dir, loc, impulse));
+    }
     fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = Self::Loc>>;
-    fn mut_slime_with(&mut self, loc: Self::Loc, f: impl FnOnce(SlimePropsIn) -> SlimePropsOut);
-    fn impulse_jiggle_with(&mut self, loc: Self::Loc, f: impl FnOnce(SlimeState) -> SlimeState);
+    /// Every row, bottom first, each going across in the same order as `cols`.
+    ///
+    /// By default row `n` is the `n`th slime up each column that has that many, so boards whose columns
+    /// can have gaps in them should give their own.
+    #[cfg(feature = "alloc")]
+    fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = Self::Loc>> {
+        let gravity_up = self.gravity_dir() == GravityDirection::Up;
+        let mut rows: alloc::vec::Vec<alloc::vec::Vec<Self::Loc>> = alloc::vec::Vec::new();
+        for col in self.cols() {
+            let mut col: alloc::vec::Vec<_> = col.collect();
+            if gravity_up {
+                col.reverse();
+            }
+            for (row, location) in col.into_iter().enumerate() {
+                if row == rows.len() {
+                    rows.push(alloc::vec::Vec::new());
+                }
+                rows[row].push(location);
+            }
+        }
+        rows.into_iter().map(alloc::vec::Vec::into_iter)
+    }
+    /// The slime next to `loc` in `dir`, if there is one.
+    ///
+    /// By default it's wherever [`JigglyBoard::apply_dir_to_loc`] sends an impulse, so there's none
+    /// wherever a jiggle can't go, like into a falling slime. Boards that know better should say.
+    fn neighbor(&self, loc: Self::Loc, dir: Self::Dir) -> Option<Self::Loc> {
+        self.apply_dir_to_loc(dir, loc, S::ONE).map(|(to, _)| to)
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: Self::Loc,
+        f: impl FnOnce(SlimePropsIn<S>) -> SlimePropsOut<S>,
+    );
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: Self::Loc,
+        f: impl FnOnce(SlimeState<S>) -> SlimeState<S>,
+    );
+    /// Look at the slime at `loc` without changing it, handing `f` its state and `y_bottom`.
+    ///
+    /// `None` if there's no slime there, or by default, since a board that can only hand its slimes out
+    /// through [`JigglyBoard::mut_slime_with`] can't. Boards that can should give this, for
+    /// [`JigglyBoard::is_settled`] and the other queries that don't need `&mut self`.
+    fn peek_slime<R>(&self, loc: Self::Loc, f: impl FnOnce(&SlimeState<S>, S) -> R) -> Option<R> {
+        let _ = (loc, f);
+        None
+    }
+    /// How heavy the slime at `loc` is. Heavy slimes land harder and are harder to set jiggling.
+    ///
+    /// Landing impulses are multiplied by this, incoming impulses are divided by it.
+    fn mass(&self, loc: Self::Loc) -> S {
+        let _ = loc;
+        S::ONE
+    }
+    /// How much empty space there is between the slime at `loc` and the one before it in its column, in
+    /// `y_bottom` units, e.g. `cell_height` for a blocked tile no slime can go in.
+    ///
+    /// The column pass stacks the slime at `loc` that much higher, so slimes settle on top of the gap and
+    /// anything falling lands there rather than in it. Columns count from whichever end gravity pulls
+    /// towards.
+    fn gap_before(&self, loc: Self::Loc) -> S {
+        let _ = loc;
+        S::ZERO
+    }
+    /// Where the floor of column `col_index` (counting from the first `cols` yields) is, in `y_bottom`
+    /// units, for ground that isn't flat. Its first slime stacks from here rather than 0.0.
+    fn column_base(&self, col_index: usize) -> S {
+        let _ = col_index;
+        S::ZERO
+    }
+    /// What the cell at `loc` is made of, which scales every jiggle reaching it, both what its slime
+    /// gets and what it passes on.
+    fn cell_material(&self, loc: Self::Loc) -> Material<S> {
+        let _ = loc;
+        Material::default()
+    }
+    /// The properties to use for the slime at `loc`, e.g. stiffer for frozen tiles or damper for goo.
+    ///
+    /// Both the column pass and jiggle propagation consult this per cell.
+    fn props_for(&self, loc: Self::Loc, base: &PhysicsProperties<S>) -> PhysicsProperties<S> {
+        let _ = loc;
+        *base
+    }
+    /// Seconds of simulated time, used to drive `PhysicsProperties::idle_wobble_amplitude`.
+    ///
+    /// Boards that want idle wobble should keep a clock and return it here; wrap it now and then to keep precision.
+    fn idle_clock(&self) -> S {
+        S::ZERO
+    }
+    /// Called once for each slime that finished `SlimeState::Popping` during a step, after that step's
+    /// column pass and jiggle propagation.
+    ///
+    /// The board should remove the slime at `loc`, and start whatever was stacked on it falling if it wants
+    /// a drop rather than the sink the pop already did. Until it's removed it stays as a finished,
+    /// zero height `Popping { progress: 1.0 }` that counts as at rest.
+    fn on_popped(&mut self, loc: Self::Loc) {
+        let _ = loc;
+    }
+    /// Called once for each slime that finished `SlimeState::Merging` during a step, after that step's
+    /// column pass.
+    ///
+    /// The board should remove the slime at `from`, start whatever was stacked on it falling if it wants,
+    /// and return the slime it merged into plus the direction from `from` to it, so the merge impulse can
+    /// be sent there. Returning `None` skips the impulse. Until it's removed it stays as a finished, zero
+    /// height `Merging { progress: 1.0, .. }` that counts as at rest.
+    fn on_merged(&mut self, from: Self::Loc) -> Option<(Self::Loc, Self::Dir)> {
+        let _ = from;
+        None
+    }
+    /// A slime sliding sideways out of `from` has got a whole cell across, towards the `positive` side
+    /// if that's the sign of its `x_velocity`.
+    ///
+    /// If the neighbouring slot is free, the board should move the slime there (state and all) and return
+    /// the new location plus the direction it travelled; it then jiggles on arrival and sends a horizontal
+    /// propagation into its new column. Returning `None` means the way is blocked and it bounces back.
+    fn try_slide(&mut self, from: Self::Loc, positive: bool) -> Option<(Self::Loc, Self::Dir)> {
+        let _ = (from, positive);
+        None
+    }
+    /// Exchange the slimes at `a` and `b`, state and all, for [`JigglyBoardExt::swap`].
+    ///
+    /// Return where `b` is relative to `a`, in cells (rightwards and upwards), so the crate can animate
+    /// them across; or `None` if they can't be swapped, in which case nothing should change.
+    fn exchange_slimes(&mut self, a: Self::Loc, b: Self::Loc) -> Option<(S, S)> {
+        let _ = (a, b);
+        None
+    }
+    /// Take the slime at `loc` off the board, for [`JigglyBoardExt::clear_cells`]. Return false if there
+    /// wasn't one or it can't be removed.
+    ///
+    /// Slimes stacked on it can keep their locations or move down into its place; either way they keep
+    /// their states and `y_bottom`s.
+    fn remove_slime(&mut self, loc: Self::Loc) -> bool {
+        let _ = loc;
+        false
+    }
+    /// Lift the slime at `loc` out, returning its state and `y_bottom`, for
+    /// [`JigglyBoardExt::swap_cells`]. Its slot stays where it is, empty, until
+    /// [`JigglyBoard::set_slime`] fills it again.
+    fn take_slime(&mut self, loc: Self::Loc) -> Option<(SlimeState<S>, S)> {
+        let _ = loc;
+        None
+    }
+    /// Put a slime in `state` at `y_bottom` in the slot at `loc`, replacing whatever's there. Return false
+    /// if there's no such slot.
+    fn set_slime(&mut self, loc: Self::Loc, state: SlimeState<S>, y_bottom: S) -> bool {
+        let _ = (loc, state, y_bottom);
+        false
+    }
+    /// Slot a new slime in under each column, for [`JigglyBoardExt::insert_bottom_row`]: the first column
+    /// `cols` gives gets the first of `row`, and so on until `row` or the columns run out.
+    ///
+    /// Every slime in a column that gets one moves up a slot, keeping its state, with `lift` added to its
+    /// `y_bottom`. The new one goes in at the bottom with a `y_bottom` of 0.0. Return false, changing
+    /// nothing, if that can't be done, e.g. because a column is full.
+    fn push_bottom_row(&mut self, row: impl Iterator<Item = SlimeState<S>>, lift: S) -> bool {
+        let _ = (row, lift);
+        false
+    }
+    /// The rigid group the slime at `loc` is part of, if any, for blocks that span several cells.
+    ///
+    /// Settled, jiggling and falling members of a group fall with one shared velocity, land together as
+    /// soon as any of them reaches its floor (so a block over an uneven floor rests on the tallest column),
+    /// send one landing impulse as heavy as the whole group, and jiggle in unison. As with single slimes,
+    /// it's up to the board to start a group falling when its support goes; dropping any one member is
+    /// enough. Members in any other state are simulated individually, and groups don't bounce.
+    fn group_of(&self, loc: Self::Loc) -> Option<GroupId> {
+        let _ = loc;
+        None
+    }
+    /// A number unique to `loc` on this board, like `x + y * width`, if the board can give one.
+    ///
+    /// With it, all of a step's jiggle propagations are added up per slime and applied once each, so
+    /// slimes landing in the same step affect each other the same whatever order `cols` yields them in,
+    /// down to the bit. Without it they're applied one after another.
+    fn loc_index(&self, loc: Self::Loc) -> Option<usize> {
+        let _ = loc;
+        None
+    }
+    /// Return false to have the column pass skip column `col_index` (counting from the first `cols` yields)
+    /// entirely, e.g. on a big board that's mostly idle.
+    ///
+    /// Only safe while every slime in it is at rest, by `SlimeState::is_at_rest`. Anything that changes that must make the
+    /// column dirty again before the next step, including a jiggle propagation reaching one of its slimes
+    /// through `impulse_jiggle_with`, and adding or removing slimes. [`JigglyBoard::on_column_at_rest`] says
+    /// when a column can be marked clean. Ignored while `PhysicsProperties::idle_wobble_amplitude` is set.
+    fn column_dirty_hint(&self, col_index: usize) -> bool {
+        let _ = col_index;
+        true
+    }
+    /// What the slime at `loc` looked like before this call to `run_physics_with_scratch`, given right
+    /// after it's stepped when the scratch has [`PhysicsScratch::with_interpolation`]. Keep it alongside
+    /// what `mut_slime_with` gave it and draw [`RenderProps::lerp`] of the two.
+    fn on_previous_output(&mut self, loc: Self::Loc, prev: RenderProps<S>) {
+        let _ = (loc, prev);
+    }
+    /// Called once the column pass finds every slime in column `col_index` at rest, by
+    /// `SlimeState::is_at_rest`, with nothing sent out.
+    /// Later parts of the same step can still set some jiggling again.
+    fn on_column_at_rest(&mut self, col_index: usize) {
+        let _ = col_index;
+    }
+    /// Step each of `jobs` against its own column for [`JigglyBoard::run_physics_par`]: `jobs[n]` is for
+    /// the `n`th column `cols` yields, and wants [`ColumnJob::step`] called with that column.
+    ///
+    /// A job only touches slimes in its own column, so a board that can lend its columns out separately
+    /// can run them all at once, as `ParallelBoard::step_columns_par` does with the `parallel` feature.
+    /// By default they go one after another, through `mut_slime_with`.
+    #[cfg(feature = "alloc")]
+    fn par_columns_mut(&mut self, jobs: &mut [ColumnJob<Self::Loc, S>]) {
+        par::step_in_turn(self, jobs);
+    }
+    /// Which way slimes fall. Flip this to make stacks settle against the other end of each column.
+    fn gravity_dir(&self) -> GravityDirection {
+        GravityDirection::Down
+    }
 
     /// If this returns true, the board is settled
-    fn run_physics(&mut self, dt: f32, physprop: &PhysicsProperties) -> bool {
-        let mut jiggle_propagations: alloc::vec::Vec<JigglePropagation<Self::Loc, Self::Dir>> =
-            alloc::vec![];
-        let mut settled = true;
-        let cols = self
-            .cols()
-            .map(|col| col.collect::<alloc::vec::Vec<_>>())
-            .collect::<alloc::vec::Vec<_>>();
-        for col in cols {
-            let mut jiggle_offset = 0.0;
-            for location in col {
-                self.mut_slime_with(location, |SlimePropsIn { state, y_bottom }| {
-                    use SlimeState::*;
-                    match state {
-                        Settled => {
-                            let out = SlimePropsOut {
-                                state,
-                                y_bottom: jiggle_offset,
-                                y_scale: 1.0,
-                                x_scale: 1.0,
-                            };
-                            jiggle_offset += 1.0;
-                            out
-                        }
-                        Falling { velocity } => {
-                            settled = false;
-                            let velocity = velocity + dt * physprop.gravity;
-
-                            if y_bottom <= jiggle_offset {
-                                jiggle_propagations.push(JigglePropagation {
-                                    at: location,
-                                    impulse: physprop.velocity_to_impact * velocity,
-                                    came_from: Self::Dir::UP,
-                                });
-                                let y_bottom = jiggle_offset;
-                                // jiggle_offset += 1.0;
-                                SlimePropsOut {
-                                    state: Jiggling {
-                                        momentum: 0.0,
-                                        offset: 0.0,
-                                        life: 1.0,
-                                    },
-                                    x_scale: 1.0,
-                                    y_scale: 1.0,
-                                    y_bottom,
-                                }
-                            } else {
-                                let y_bottom = y_bottom - velocity * dt;
-                                let clamped_vel = velocity.mul(1.0 / 9.0).add(1.0).clamp(1.0, 2.0);
-                                let x_scale = 1.0 / clamped_vel;
-                                let y_scale = 1.0 * clamped_vel;
-                                SlimePropsOut {
-                                    state: Falling { velocity },
-                                    y_bottom,
-                                    y_scale,
-                                    x_scale,
-                                }
-                            }
-                        }
-                        Jiggling {
-                            momentum,
-                            offset,
-                            life,
-                        } => {
-                            settled = false;
-                            let y_bottom = jiggle_offset;
-                            let accdt = physprop.jiggle_stiff * -offset * dt;
-                            let mut momentum = (momentum + accdt) * physprop.jiggle_damp;
-                            let mut offset = offset + momentum * dt;
-                            if life < physprop.jiggle_life_threshold {
-                                offset *= life * physprop.jiggle_life_threshold_inverse;
-                                momentum *= life * physprop.jiggle_life_threshold_inverse;
-                            }
-                            if (life <= 0.0)
-                                || (offset.abs() < physprop.jiggle_offset_epsilon
-                                    && momentum.abs() < physprop.jiggle_momentum_epsilon)
-                            {
-                                jiggle_offset += 1.0;
-                                SlimePropsOut {
-                                    state: Settled,
-                                    y_bottom,
-                                    y_scale: 1.0,
-                                    x_scale: 1.0,
-                                }
-                            } else {
-                                let life = life - physprop.jiggle_life_decrease_rate * dt;
-                                let y_scale = (1.0 - offset).max(0.0);
-                                let x_scale = y_scale.max(0.5).recip();
-                                jiggle_offset += y_scale;
-                                SlimePropsOut {
-                                    state: Jiggling {
-                                        momentum,
-                                        offset,
-                                        life,
-                                    },
-                                    x_scale,
-                                    y_scale,
-                                    y_bottom,
-                                }
-                            }
-                        }
-                    }
-                });
+    ///
+    /// A `dt` longer than `physprop.max_dt` is run as several equal sub-steps, and only counts as settled if
+    /// every one of them does. A step that sends out any jiggle propagation never counts as settled, so
+    /// it's safe to stop stepping as soon as this is true.
+    ///
+    /// A `dt` of zero, or one that's negative or not finite, leaves the board untouched and just reports
+    /// whether it's already at rest, so a paused game loop can keep calling this. Debug builds panic on a
+    /// `physprop` that fails [`PhysicsProperties::validate`]; release builds don't check, so use
+    /// [`JigglyBoard::try_run_physics`] there for tuning that comes from outside.
+    ///
+    /// Without the `alloc` feature it steps in an `ArrayStorage` of `DEFAULT_ARRAY_CAPACITY` slimes, groups
+    /// don't move as one, and a jiggle wave drops whatever branches off it past 16 cells in hand at once,
+    /// telling [`PhysicsObserver::truncated`]; give [`JigglyBoard::run_physics_with_scratch`] a bigger
+    /// scratch for bigger boards.
+    fn run_physics(&mut self, dt: S, physprop: &PhysicsProperties<S>) -> bool {
+        self.run_physics_observed(dt, physprop, &mut ())
+    }
+    /// [`JigglyBoard::run_physics`] with the column pass handed to [`JigglyBoard::par_columns_mut`] as a
+    /// job per column, giving exactly the same result.
+    ///
+    /// Boards with groups step as `run_physics` does, since a group is stepped as one across columns.
+    #[cfg(feature = "alloc")]
+    fn run_physics_par(&mut self, dt: S, physprop: &PhysicsProperties<S>) -> bool {
+        par::run_physics_par(self, dt, physprop)
+    }
+    /// [`JigglyBoard::run_physics`], failing without stepping anything if `physprop`, or what
+    /// [`JigglyBoard::props_for`] makes of it for any slime, fails [`PhysicsProperties::validate`].
+    fn try_run_physics(
+        &mut self,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+    ) -> Result<bool, PhysicsPropertiesError> {
+        self.validate_props(physprop)?;
+        Ok(self.run_physics(dt, physprop))
+    }
+    /// Check `physprop`, then what [`JigglyBoard::props_for`] makes of it for every slime, with
+    /// [`PhysicsProperties::validate`], stopping at the first that fails.
+    fn validate_props(
+        &self,
+        physprop: &PhysicsProperties<S>,
+    ) -> Result<(), PhysicsPropertiesError> {
+        physprop.validate()?;
+        self.cols()
+            .flatten()
+            .try_for_each(|location| self.props_for(location, physprop).validate())
+    }
+    /// Call [`JigglyBoard::run_physics`] up to `steps` times, stopping early once the board settles, e.g.
+    /// to skip an animation or look ahead.
+    fn run_physics_steps(
+        &mut self,
+        steps: u32,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+    ) -> SettleOutcome {
+        let mut scratch: PhysicsScratch<Self::Loc, Self::Dir, S> = PhysicsScratch::new();
+        for step in 1..=steps {
+            if self.run_physics_with_scratch(dt, physprop, &mut scratch, &mut ()) {
+                return SettleOutcome {
+                    steps: step,
+                    settled: true,
+                };
             }
         }
-        //Now run through jiggle propagations
-        for propagation in jiggle_propagations {
-            self.propagate_jiggle(propagation, physprop);
+        let flushed = pending::flush_carried(self, physprop, &mut scratch, &mut ());
+        SettleOutcome {
+            steps,
+            settled: steps == 0 && !flushed && self.is_at_rest(),
         }
-        settled
     }
-    fn propagate_jiggle(
+    /// [`JigglyBoard::run_physics`], also counting up what happened, e.g. to drive audio intensity.
+    fn run_physics_stats(&mut self, dt: S, physprop: &PhysicsProperties<S>) -> StepStats<S> {
+        let mut stats = StepStats::default();
+        stats.settled = self.run_physics_observed(dt, physprop, &mut stats);
+        stats
+    }
+    /// [`JigglyBoard::run_physics`], also adding a [`SlimeEvent`] to `events` for everything that a game
+    /// might want to play a sound or spawn particles for.
+    fn run_physics_with_events(
         &mut self,
-        propagation: JigglePropagation<Self::Loc, Self::Dir>,
-        physprop: &PhysicsProperties,
-    ) {
-        let JigglePropagation {
-            at,
-            impulse,
-            came_from,
-        } = propagation;
-        let PhysicsProperties {
-            min_impactable,
-            velocity_to_impact,
-            ..
-        } = physprop;
-        if impulse < *min_impactable {
-            return;
-        }
-        use SlimeState::*;
-
-        self.impulse_jiggle_with(at, |state| {
-            match state {
-                Settled => Jiggling {
-                    momentum: impulse,
-                    offset: 0.0,
-                    life: 1.0,
-                },
-                //Note: this really should not be encountered, but it will have defined behaviour in the case it is.
-                Falling { velocity } => Jiggling {
-                    momentum: impulse + velocity * *velocity_to_impact,
-                    offset: 0.0,
-                    life: 1.0,
-                },
-                Jiggling {
-                    momentum, offset, ..
-                } => Jiggling {
-                    momentum: momentum + impulse,
-                    offset,
-                    life: 1.0,
-                },
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+        events: &mut impl Extend<SlimeEvent<Self::Loc, Self::Dir, S>>,
+    ) -> bool {
+        self.run_physics_observed(dt, physprop, &mut observe::Events(events))
+    }
+    /// [`JigglyBoard::run_physics`], calling `listener` for every slime a jiggle wave reaches, e.g. to play
+    /// a squish pitched to its impulse.
+    fn run_physics_with_listener(
+        &mut self,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+        listener: &mut impl FnMut(PropagationEvent<Self::Loc, Self::Dir, S>),
+    ) -> bool {
+        self.run_physics_observed(dt, physprop, &mut observe::Listener(listener))
+    }
+    /// [`JigglyBoard::run_physics`], adding every jiggle that runs off the side of the board to `escaped`,
+    /// to pass on to a board next to it with [`JigglyBoard::inject_impulses`].
+    ///
+    /// Only sideways escapes count: floors and stack tops swallow jiggles as usual.
+    fn run_physics_collect_escaped(
+        &mut self,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+        escaped: &mut impl Extend<EscapedImpulse<Self::Loc, Self::Dir, S>>,
+    ) -> bool {
+        self.run_physics_observed(dt, physprop, &mut bridge::Escapes(escaped))
+    }
+    /// [`JigglyBoard::run_physics`], telling `observer` what happens along the way.
+    fn run_physics_observed(
+        &mut self,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+        observer: &mut impl PhysicsObserver<Self::Loc, Self::Dir, S>,
+    ) -> bool {
+        let mut scratch: PhysicsScratch<Self::Loc, Self::Dir, S> = PhysicsScratch::new();
+        let settled = self.run_physics_with_scratch(dt, physprop, &mut scratch, observer);
+        // Nothing can be carried over to a next call that won't get this scratch
+        !pending::flush_carried(self, physprop, &mut scratch, observer) && settled
+    }
+    /// [`JigglyBoard::run_physics_observed`], working in `scratch` so that stepping doesn't allocate once
+    /// it has grown to fit the board. Groups and boards with `loc_index` still allocate while they're
+    /// in motion. One made with `ArrayStorage` keeps its own buffers off the heap.
+    fn run_physics_with_scratch<M: ScratchStorage>(
+        &mut self,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+        scratch: &mut PhysicsScratch<Self::Loc, Self::Dir, S, M>,
+        observer: &mut impl PhysicsObserver<Self::Loc, Self::Dir, S>,
+    ) -> bool {
+        pending::run_physics(self, dt, physprop, scratch, None, observer)
+    }
+    /// [`JigglyBoard::run_physics_with_scratch`], taking the board's columns from `layout` instead of
+    /// going through [`JigglyBoard::cols`] every step.
+    ///
+    /// Fills `layout` if it's empty or invalidated. Fails without stepping anything if the board has
+    /// changed shape since, as far as [`BoardLayoutCache`] can tell.
+    #[cfg(feature = "alloc")]
+    fn run_physics_cached<M: ScratchStorage>(
+        &mut self,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+        layout: &mut BoardLayoutCache<Self::Loc>,
+        scratch: &mut PhysicsScratch<Self::Loc, Self::Dir, S, M>,
+    ) -> Result<bool, StaleLayout> {
+        layout.refresh(self)?;
+        Ok(pending::run_physics(
+            self,
+            dt,
+            physprop,
+            scratch,
+            Some(layout),
+            &mut (),
+        ))
+    }
+    /// One [`JigglyBoard::physics_step`] spread over several calls, each doing no more than `budget`
+    /// allows, for boards too big to step in one frame.
+    ///
+    /// Returns `None` while the step is still under way, and whether the board is settled once it's done.
+    /// `dt` is only read by the call that starts a step, and isn't split into sub-steps, so count it once
+    /// per finished step and keep it under `physprop.max_dt`. Don't add or remove slimes until it finishes.
+    /// A `dt` that [`JigglyBoard::run_physics`] wouldn't step with starts nothing, and reports whether the
+    /// board is at rest straight away.
+    fn run_physics_partial<M: ScratchStorage>(
+        &mut self,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+        budget: &mut StepBudget<Self::Loc, Self::Dir, S, M>,
+    ) -> Option<bool> {
+        budget.spend(self, dt, physprop)
+    }
+    /// Whether every slime is [at rest](SlimeState::is_at_rest), without stepping or changing anything.
+    ///
+    /// Slimes that [`JigglyBoard::peek_slime`] can't look at count as not settled, so on boards that
+    /// don't give it this is only ever false; use [`JigglyBoard::is_at_rest`] there. Otherwise, once
+    /// `run_physics` says the board is settled, so does this, until something changes it.
+    fn is_settled(&self) -> bool {
+        self.cols().flatten().all(|location| {
+            self.peek_slime(location, |state, _| state.is_at_rest())
+                .unwrap_or(false)
+        })
+    }
+    /// How many slimes are falling, jiggling or otherwise not at rest, using [`JigglyBoard::peek_slime`].
+    fn count_active(&self) -> ActiveCounts {
+        let mut counts = ActiveCounts::default();
+        for (_, kind) in self.active_cells() {
+            match kind {
+                ActiveKind::Falling => counts.falling += 1,
+                ActiveKind::Jiggling => counts.jiggling += 1,
+                ActiveKind::Other => counts.other += 1,
             }
+        }
+        counts
+    }
+    /// Every slime that isn't [at rest](SlimeState::is_at_rest), and what it's doing, using
+    /// [`JigglyBoard::peek_slime`]. Slimes it can't look at count as [`ActiveKind::Other`].
+    ///
+    /// This looks at every slime on the board. [`PhysicsScratch::with_active_tracking`] keeps the same
+    /// list up to date as the board is stepped, for boards with only a few slimes moving at a time.
+    fn active_cells(&self) -> impl Iterator<Item = (Self::Loc, ActiveKind)> {
+        self.cols().flatten().filter_map(|location| {
+            let kind = self
+                .peek_slime(location, |state, _| ActiveKind::of(state))
+                .unwrap_or(Some(ActiveKind::Other))?;
+            Some((location, kind))
+        })
+    }
+    /// How agitated the slimes are, jiggling or in the air, by `weights`, and which is the most, using
+    /// [`JigglyBoard::peek_slime`].
+    fn agitation(&self, weights: &AgitationWeights<S>) -> Agitation<Self::Loc, S> {
+        agitation::measure(self, weights)
+    }
+    /// Every slime's state and `y_bottom`, using [`JigglyBoard::peek_slime`], to put back later with
+    /// [`JigglyBoard::restore`]. Use [`BoardSnapshot::capture`] to reuse one.
+    #[cfg(feature = "alloc")]
+    fn snapshot(&self) -> BoardSnapshot<Self::Loc, S> {
+        let mut snap = BoardSnapshot::default();
+        snap.capture(self);
+        snap
+    }
+    /// Put the board's slimes back how they were in `snap`, using [`JigglyBoard::take_slime`] and
+    /// [`JigglyBoard::set_slime`]. Slimes that weren't there when it was taken are taken out.
+    ///
+    /// Stepping afterwards goes exactly as it did from the snapshot, as long as the rest of the board, and
+    /// the `PhysicsScratch`, are how they were too: see [`BoardSnapshot`] for what isn't in one.
+    #[cfg(feature = "alloc")]
+    fn restore(&mut self, snap: &BoardSnapshot<Self::Loc, S>) {
+        snapshot::restore(self, snap);
+    }
+    /// Every column on a line of its own, bottom to top, a character a slime, using
+    /// [`JigglyBoard::peek_slime`]:
+    ///
+    /// - `.` settled, `!` about to fall, `v` falling, `b` bouncing, `^` launched
+    /// - `~` jiggling, or `1` to `9` for how far it's squashed or leaning, in tenths of a cell
+    /// - `_` squished, `+` spawning, `*` popping, `#` frozen, `H` held, `>` sliding, `s` swapping,
+    ///   `m` merging
+    /// - `?` for slimes it can't look at
+    #[cfg(feature = "alloc")]
+    fn debug_dump(&self) -> alloc::string::String {
+        dump::dump(self, false)
+    }
+    /// Like [`JigglyBoard::debug_dump`], but a line a slime, with its `y_bottom` and the whole of its
+    /// state.
+    #[cfg(feature = "alloc")]
+    fn debug_dump_verbose(&self) -> alloc::string::String {
+        dump::dump(self, true)
+    }
+    /// Look for slimes the physics couldn't have left where they are, using [`JigglyBoard::peek_slime`]:
+    /// NaNs and infinities, jiggles past `max_offset`, and, in debug builds only, slimes out of order or
+    /// overlapping in their column and falling slimes that have gone through what they should have
+    /// landed on.
+    ///
+    /// Slimes squashed or stretched mid-jiggle are allowed as much room as their state could give them,
+    /// so a board that was fine when last stepped passes.
+    #[cfg(feature = "alloc")]
+    fn check_invariants(
+        &self,
+        physprop: &PhysicsProperties<S>,
+    ) -> Result<(), alloc::vec::Vec<InvariantViolation<Self::Loc, S>>> {
+        let violations = invariants::check(self, physprop);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+    /// [`JigglyBoard::is_settled`] for any board: slimes [`JigglyBoard::peek_slime`] can't look at are
+    /// looked at through [`JigglyBoard::impulse_jiggle_with`] instead, handing them straight back.
+    fn is_at_rest(&mut self) -> bool {
+        let mut unseen = false;
+        let seen_at_rest = self.cols().flatten().all(|location| {
+            self.peek_slime(location, |state, _| state.is_at_rest())
+                .unwrap_or_else(|| {
+                    unseen = true;
+                    true
+                })
         });
-
-        for dir in came_from.other_directions() {
-            let Some((at, impulse)) = self.apply_dir_to_loc(dir, at, impulse) else {
-                continue;
+        if !seen_at_rest || !unseen {
+            return seen_at_rest;
+        }
+        let mut at_rest = true;
+        let mut look = |board: &mut Self, location| {
+            board.impulse_jiggle_with(location, |state| {
+                at_rest &= state.is_at_rest();
+                state
+            })
+        };
+        #[cfg(feature = "alloc")]
+        for location in self.cols().flatten().collect::<alloc::vec::Vec<_>>() {
+            look(self, location);
+        }
+        // Without anywhere to keep them, each slime is found again from the start
+        #[cfg(not(feature = "alloc"))]
+        for n in 0.. {
+            let Some(location) = self.cols().flatten().nth(n) else {
+                break;
             };
-            self.propagate_jiggle(
-                JigglePropagation {
+            look(self, location);
+        }
+        at_rest
+    }
+    /// A single step of the column pass plus jiggle propagation, with no sub-stepping.
+    ///
+    /// If this returns true, the board is settled. A `dt` that [`JigglyBoard::run_physics`] wouldn't step
+    /// with isn't stepped with here either.
+    fn physics_step(&mut self, dt: S, physprop: &PhysicsProperties<S>) -> bool {
+        self.physics_step_observed(dt, physprop, &mut ())
+    }
+    /// [`JigglyBoard::physics_step`], telling `observer` what happens along the way.
+    fn physics_step_observed(
+        &mut self,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+        observer: &mut impl PhysicsObserver<Self::Loc, Self::Dir, S>,
+    ) -> bool {
+        pending::assert_valid(physprop);
+        if !pending::steppable(dt) {
+            return self.is_at_rest();
+        }
+        let mut scratch: PhysicsScratch<Self::Loc, Self::Dir, S> = PhysicsScratch::new();
+        let settled = pending::run_step(self, dt, physprop, &mut scratch, None, observer);
+        !pending::flush_carried(self, physprop, &mut scratch, observer) && settled
+    }
+    /// The column pass for just the one column `col`, the `col_index`th that `cols` yields, e.g. to only
+    /// step what's on screen. Returns whether it's settled.
+    ///
+    /// The jiggle propagations it sends out are added to `out_propagations` rather than applied, since
+    /// they may reach columns that haven't been stepped yet; hand them to
+    /// [`JigglyBoard::apply_propagations`] once every column is done. Groups only move as one within the
+    /// column, and slimes that finished popping are handed to `on_popped` straight away.
+    #[cfg(feature = "alloc")]
+    fn step_column(
+        &mut self,
+        col_index: usize,
+        col: impl Iterator<Item = Self::Loc>,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+        out_propagations: &mut alloc::vec::Vec<JigglePropagation<Self::Loc, Self::Dir, S>>,
+    ) -> bool {
+        pending::assert_valid(physprop);
+        if !pending::steppable(dt) {
+            return self.is_at_rest();
+        }
+        let mut pending: pending::PendingStep<_, _, S> =
+            pending::PendingStep::single(self, dt, physprop, col_index, col);
+        pending.step_column(self, physprop, &mut ());
+        pending.finish_columns(self, physprop, &mut ());
+        out_propagations.extend(pending.drain_propagations());
+        pending.finish(self).0
+    }
+    /// Apply the jiggle propagations gathered from [`JigglyBoard::step_column`].
+    #[cfg(feature = "alloc")]
+    fn apply_propagations(
+        &mut self,
+        propagations: alloc::vec::Vec<JigglePropagation<Self::Loc, Self::Dir, S>>,
+        physprop: &PhysicsProperties<S>,
+    ) {
+        pending::apply(self, propagations, physprop, &mut ());
+    }
+    /// Send the jiggles that escaped another board into this one, wherever `bridge` says they come in.
+    /// Call it before this board's next step.
+    #[cfg(feature = "alloc")]
+    fn inject_impulses<FromLoc, FromDir>(
+        &mut self,
+        escaped: impl IntoIterator<Item = EscapedImpulse<FromLoc, FromDir, S>>,
+        bridge: &impl BoardBridge<FromLoc, FromDir, Self::Loc, Self::Dir, S>,
+        physprop: &PhysicsProperties<S>,
+    ) {
+        let propagations: alloc::vec::Vec<_> = escaped
+            .into_iter()
+            .filter_map(|escaped| bridge.cross(escaped))
+            .collect();
+        if !propagations.is_empty() {
+            self.apply_propagations(propagations, physprop);
+        }
+    }
+    fn propagate_jiggle(
+        &mut self,
+        propagation: JigglePropagation<Self::Loc, Self::Dir, S>,
+        physprop: &PhysicsProperties<S>,
+    ) {
+        self.propagate_jiggle_observed(propagation, physprop, &mut ());
+    }
+    /// [`JigglyBoard::propagate_jiggle`], calling `listener` for every slime it reaches.
+    fn propagate_jiggle_with_listener(
+        &mut self,
+        propagation: JigglePropagation<Self::Loc, Self::Dir, S>,
+        physprop: &PhysicsProperties<S>,
+        listener: &mut impl FnMut(PropagationEvent<Self::Loc, Self::Dir, S>),
+    ) {
+        self.propagate_jiggle_observed(propagation, physprop, &mut observe::Listener(listener));
+    }
+    /// [`JigglyBoard::propagate_jiggle`], telling `observer` about every slime it reaches.
+    fn propagate_jiggle_observed(
+        &mut self,
+        propagation: JigglePropagation<Self::Loc, Self::Dir, S>,
+        physprop: &PhysicsProperties<S>,
+        observer: &mut impl PhysicsObserver<Self::Loc, Self::Dir, S>,
+    ) {
+        impulse::spread(
+            self,
+            propagation,
+            physprop,
+            observer,
+            |board, observer, reached, cell_props| {
+                let JigglePropagation {
                     at,
                     impulse,
-                    came_from: dir.opposite(),
-                },
-                physprop,
-            );
-        }
+                    came_from,
+                    hops,
+                } = reached;
+                let arriving = impulse::Impulse::arriving(impulse / board.mass(at), came_from);
+                let mut blocked = false;
+                board.impulse_jiggle_with(at, |state| {
+                    impulse::receive(state, arriving, cell_props, &mut blocked)
+                });
+                observer.propagated(PropagationEvent {
+                    loc: at,
+                    impulse,
+                    came_from,
+                    hops,
+                });
+                !blocked
+            },
+        );
     }
 }
@@ -0,0 +1,34 @@
+//! What a cell is made of, for tiles that soak up or boost the jiggles passing through them.
+
+use crate::Scalar;
+
+/// How the cell at a location changes every jiggle that reaches it, from [`JigglyBoard::cell_material`].
+///
+/// Both what the slime there receives and what it passes on are scaled, by `1 - absorb` and then
+/// `1 + amplify`. The default changes nothing.
+///
+/// [`JigglyBoard::cell_material`]: crate::JigglyBoard::cell_material
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Material<S = f32> {
+    /// How much of each jiggle it soaks up, from 0 to 1, e.g. for sticky goo. 1 stops them dead.
+    pub absorb: S,
+    /// How much it adds to each jiggle, e.g. 0.1 for rubber. Keep it small, or waves going round a
+    /// loop of it grow until `PhysicsProperties::max_propagation_hops` stops them.
+    pub amplify: S,
+}
+
+impl<S: Scalar> Default for Material<S> {
+    fn default() -> Self {
+        Material {
+            absorb: S::ZERO,
+            amplify: S::ZERO,
+        }
+    }
+}
+
+impl<S: Scalar> Material<S> {
+    /// What jiggles reaching it are multiplied by.
+    pub fn factor(&self) -> S {
+        (S::ONE - self.absorb) * (S::ONE + self.amplify)
+    }
+}
@@ -0,0 +1,167 @@
+//! Small float approximations for things core doesn't give us without libm.
+
+/// The same approximations for each float type, differing only in how the bits are laid out.
+macro_rules! float_math {
+    (
+        $module:ident: $float:ident as $bits:ident,
+        mantissa_bits: $mantissa:literal,
+        exponent_bias: $bias:literal,
+        exp_range: ($exp_min:literal, $exp_max:literal),
+        ln_2_split: ($ln_2_hi:literal, $ln_2_lo:literal),
+        exp_terms: $exp_terms:literal $(,)?
+    ) => {
+        pub(crate) mod $module {
+            use core::$float::consts::{FRAC_PI_2, LN_2, LOG2_E, PI, SQRT_2, TAU};
+
+            const MANTISSA_MASK: $bits = (1 << $mantissa) - 1;
+
+            /// Natural log. Accurate to a few ulps for normal positive inputs.
+            pub(crate) fn ln(x: $float) -> $float {
+                if x.is_nan() || x < 0.0 {
+                    return $float::NAN;
+                }
+                if x == 0.0 {
+                    return $float::NEG_INFINITY;
+                }
+                if x == $float::INFINITY {
+                    return x;
+                }
+                // Lift subnormals into the normal range so the exponent bits mean something
+                let (x, bias) = if x < $float::MIN_POSITIVE {
+                    (x * ((1 as $bits) << $mantissa) as $float, -$mantissa)
+                } else {
+                    (x, 0)
+                };
+                let bits = x.to_bits();
+                let mut exponent = (bits >> $mantissa) as i32 - $bias + bias;
+                let mut mantissa =
+                    $float::from_bits((bits & MANTISSA_MASK) | (1.0 as $float).to_bits());
+                if mantissa > SQRT_2 {
+                    mantissa *= 0.5;
+                    exponent += 1;
+                }
+                // ln(m) = 2 * atanh((m - 1) / (m + 1))
+                let s = (mantissa - 1.0) / (mantissa + 1.0);
+                let s2 = s * s;
+                let series = s
+                    * (2.0
+                        + s2 * (2.0 / 3.0
+                            + s2 * (2.0 / 5.0
+                                + s2 * (2.0 / 7.0
+                                    + s2 * (2.0 / 9.0 + s2 * (2.0 / 11.0 + s2 * (2.0 / 13.0)))))));
+                series + exponent as $float * LN_2
+            }
+
+            /// e^x
+            pub(crate) fn exp(x: $float) -> $float {
+                if x.is_nan() {
+                    return x;
+                }
+                if x > $exp_max {
+                    return $float::INFINITY;
+                }
+                if x < $exp_min {
+                    return 0.0;
+                }
+                let t = x * LOG2_E;
+                let k = if t >= 0.0 {
+                    (t + 0.5) as i32
+                } else {
+                    (t - 0.5) as i32
+                };
+                // ln2 split in two so the reduction stays exact for large k
+                let r = (x - k as $float * $ln_2_hi) - k as $float * $ln_2_lo;
+                let mut term = 1.0;
+                let mut sum = 1.0;
+                for i in 1..=$exp_terms {
+                    term *= r / i as $float;
+                    sum += term;
+                }
+                scale_by_pow2(sum, k)
+            }
+
+            /// Sine, accurate to a few ulps of 1.0 for inputs within a few hundred radians of zero.
+            pub(crate) fn sin(x: $float) -> $float {
+                if !x.is_finite() {
+                    return $float::NAN;
+                }
+                let turns = x / TAU;
+                let k = if turns >= 0.0 {
+                    (turns + 0.5) as i32
+                } else {
+                    (turns - 0.5) as i32
+                };
+                let mut r = x - k as $float * TAU;
+                // Fold [-π, π] into [-π/2, π/2], where the series converges quickly
+                if r > FRAC_PI_2 {
+                    r = PI - r;
+                } else if r < -FRAC_PI_2 {
+                    r = -PI - r;
+                }
+                let r2 = r * r;
+                let mut term = r;
+                let mut sum = r;
+                for i in 1..=9 {
+                    term *= -r2 / ((2 * i) * (2 * i + 1)) as $float;
+                    sum += term;
+                }
+                sum
+            }
+
+            /// `base^exponent` for positive bases.
+            pub(crate) fn powf(base: $float, exponent: $float) -> $float {
+                if exponent == 0.0 {
+                    return 1.0;
+                }
+                exp(exponent * ln(base))
+            }
+
+            /// Square root, accurate to about an ulp. Zero for anything that isn't positive.
+            pub(crate) fn sqrt(x: $float) -> $float {
+                if x.is_nan() || x <= 0.0 {
+                    return 0.0;
+                }
+                if x.is_infinite() {
+                    return x;
+                }
+                // Halving the exponent gets within a few percent, which Newton's method quickly closes
+                let mut r = $float::from_bits((x.to_bits() >> 1) + ($bias << ($mantissa - 1)));
+                for _ in 0..5 {
+                    r = 0.5 * (r + x / r);
+                }
+                r
+            }
+
+            fn scale_by_pow2(mut value: $float, mut k: i32) -> $float {
+                // Step in chunks so each factor is a representable normal power of two
+                while k > $bias {
+                    value *= $float::from_bits((2 * $bias) << $mantissa);
+                    k -= $bias;
+                }
+                while k < 1 - $bias {
+                    value *= $float::from_bits(1 << $mantissa);
+                    k += $bias - 1;
+                }
+                value * $float::from_bits(((k + $bias) as $bits) << $mantissa)
+            }
+        }
+    };
+}
+
+float_math! {
+    single: f32 as u32,
+    mantissa_bits: 23,
+    exponent_bias: 127,
+    exp_range: (-103.97, 88.72),
+    ln_2_split: (0.693_359_4, -2.121_944_4e-4),
+    exp_terms: 8,
+}
+
+float_math! {
+    double: f64 as u64,
+    mantissa_bits: 52,
+    exponent_bias: 1023,
+    exp_range: (-745.13, 709.78),
+    ln_2_split: (6.931_457_519_531_25e-1, 1.428_606_820_309_417_3e-6),
+    exp_terms: 13,
+}
@@ -0,0 +1,159 @@
+//! Hooks for watching what a physics step does.
+
+use crate::{EscapedImpulse, Scalar, SlimeState};
+
+/// Told about things that happen during [`JigglyBoard::run_physics_observed`](crate::JigglyBoard::run_physics_observed).
+///
+/// Every method does nothing by default, so implement just the ones you need. `()` ignores everything.
+pub trait PhysicsObserver<Loc, Dir, S = f32> {
+    /// The slime at `loc` had a non-finite value in its state or position, and was reset to `SlimeState::Settled`
+    /// rather than left to wedge the board.
+    fn recovered(&mut self, loc: Loc) {
+        let _ = loc;
+    }
+    /// A jiggle wave had to stop short at `loc`, or drop the branch of it heading there, having more to
+    /// keep track of than fits without the heap. Only ever happens without the `alloc` feature.
+    fn truncated(&mut self, loc: Loc) {
+        let _ = loc;
+    }
+    /// A sub-step is starting. `run_physics` may run several per call; see `PhysicsProperties::max_dt`.
+    fn step_begun(&mut self) {}
+    /// The column pass just stepped the slime at `loc`, leaving it in `state`.
+    fn stepped(&mut self, loc: Loc, state: &SlimeState<S>) {
+        let _ = (loc, state);
+    }
+    /// The slime at `loc` just came to rest, having been doing something else.
+    fn settled(&mut self, loc: Loc) {
+        let _ = loc;
+    }
+    /// The slime at `loc` landed, sending out a jiggle propagation of `impulse`.
+    fn landed(&mut self, loc: Loc, impulse: S) {
+        let _ = (loc, impulse);
+    }
+    /// A jiggle propagation reached the slime at `loc` with `impulse`, before mass, arriving from `came_from`.
+    fn impulsed(&mut self, loc: Loc, impulse: S, came_from: Dir) {
+        let _ = (loc, impulse, came_from);
+    }
+    /// [`PhysicsObserver::impulsed`], along with how far the wave had come. By default this just calls
+    /// `impulsed`, so implement whichever has what you need.
+    fn propagated(&mut self, event: PropagationEvent<Loc, Dir, S>) {
+        self.impulsed(event.loc, event.impulse, event.came_from);
+    }
+    /// A jiggle propagation had nowhere to go sideways from the slime it was in. See
+    /// [`JigglyBoard::run_physics_collect_escaped`](crate::JigglyBoard::run_physics_collect_escaped).
+    fn escaped(&mut self, escaped: EscapedImpulse<Loc, Dir, S>) {
+        let _ = escaped;
+    }
+}
+
+impl<Loc, Dir, S> PhysicsObserver<Loc, Dir, S> for () {}
+
+/// Something a game might want to react to, from [`JigglyBoard::run_physics_with_events`](crate::JigglyBoard::run_physics_with_events).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SlimeEvent<Loc, Dir, S = f32> {
+    /// Hit the ground or the slime below, sending out a jiggle propagation of `impulse`.
+    Landed { loc: Loc, impulse: S },
+    /// Came to rest, e.g. once its jiggle has died down.
+    SettledAt { loc: Loc },
+    /// Reached by a jiggle propagation with `impulse`, before mass, arriving from `dir`.
+    Impulsed { loc: Loc, impulse: S, dir: Dir },
+}
+
+/// A jiggle wave reaching one slime, for [`JigglyBoard::run_physics_with_listener`](crate::JigglyBoard::run_physics_with_listener).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PropagationEvent<Loc, Dir, S = f32> {
+    pub loc: Loc,
+    /// Before mass, negative if it stretches.
+    pub impulse: S,
+    pub came_from: Dir,
+    /// How many cells it had travelled to get there, 0 where the wave started.
+    pub hops: u32,
+}
+
+/// Hands every [`PropagationEvent`] to a closure, and ignores everything else.
+pub(crate) struct Listener<'a, F>(pub &'a mut F);
+
+impl<Loc, Dir, S, F: FnMut(PropagationEvent<Loc, Dir, S>)> PhysicsObserver<Loc, Dir, S>
+    for Listener<'_, F>
+{
+    fn propagated(&mut self, event: PropagationEvent<Loc, Dir, S>) {
+        (self.0)(event);
+    }
+}
+
+/// Turns observer calls into [`SlimeEvent`]s.
+pub(crate) struct Events<'a, E>(pub &'a mut E);
+
+impl<Loc, Dir, S, E: Extend<SlimeEvent<Loc, Dir, S>>> PhysicsObserver<Loc, Dir, S>
+    for Events<'_, E>
+{
+    fn settled(&mut self, loc: Loc) {
+        self.0.extend([SlimeEvent::SettledAt { loc }]);
+    }
+    fn landed(&mut self, loc: Loc, impulse: S) {
+        self.0.extend([SlimeEvent::Landed { loc, impulse }]);
+    }
+    fn impulsed(&mut self, loc: Loc, impulse: S, came_from: Dir) {
+        self.0.extend([SlimeEvent::Impulsed {
+            loc,
+            impulse,
+            dir: came_from,
+        }]);
+    }
+}
+
+/// How much happened during a call to [`JigglyBoard::run_physics_stats`](crate::JigglyBoard::run_physics_stats).
+///
+/// `falling`, `jiggling`, `max_velocity` and `max_offset` describe the slimes as of the last sub-step;
+/// `landings` and `propagations` add up over all of them.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct StepStats<S = f32> {
+    /// What `run_physics` would have returned.
+    pub settled: bool,
+    /// Slimes falling or bouncing.
+    pub falling: u32,
+    pub jiggling: u32,
+    pub landings: u32,
+    /// Slimes reached by a jiggle propagation, counting each time one is reached.
+    pub propagations: u32,
+    /// Times a jiggle wave was cut short for want of room, without the `alloc` feature. See
+    /// [`PhysicsObserver::truncated`].
+    pub truncated: u32,
+    /// Fastest fall or bounce, in cells per second.
+    pub max_velocity: S,
+    /// Largest jiggle offset on either axis.
+    pub max_offset: S,
+}
+
+impl<Loc, Dir, S: Scalar> PhysicsObserver<Loc, Dir, S> for StepStats<S> {
+    fn step_begun(&mut self) {
+        self.falling = 0;
+        self.jiggling = 0;
+        self.max_velocity = S::ZERO;
+        self.max_offset = S::ZERO;
+    }
+    fn stepped(&mut self, _loc: Loc, state: &SlimeState<S>) {
+        match *state {
+            SlimeState::Falling { velocity, .. } | SlimeState::Bouncing { velocity, .. } => {
+                self.falling += 1;
+                self.max_velocity = self.max_velocity.max(velocity.abs());
+            }
+            SlimeState::Jiggling {
+                offset, offset_x, ..
+            } => {
+                self.jiggling += 1;
+                self.max_offset = self.max_offset.max(offset.abs()).max(offset_x.abs());
+            }
+            _ => {}
+        }
+    }
+    fn landed(&mut self, _loc: Loc, _impulse: S) {
+        self.landings += 1;
+    }
+    fn impulsed(&mut self, _loc: Loc, _impulse: S, _came_from: Dir) {
+        self.propagations += 1;
+    }
+    fn truncated(&mut self, _loc: Loc) {
+        self.truncated += 1;
+    }
+}
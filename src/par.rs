@@ -0,0 +1,225 @@
+//! Stepping a board's columns all at once.
+//!
+//! Columns only affect each other through the jiggle propagations they send out, so the column pass
+//! can be handed to [`JigglyBoard::par_columns_mut`] as a [`ColumnJob`] per column, to run on as many
+//! threads as there are columns. Everything after it, groups, slides, merges and the propagations
+//! themselves, stays on the calling thread and goes in column order, so a board comes out exactly the
+//! same as [`JigglyBoard::run_physics`] would leave it.
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::pending::{self, PendingStep};
+use crate::step::{self, StepContext, StepOutcome};
+use crate::{JigglyBoard, PhysicsProperties, PhysicsScratch, Scalar, SlimePropsIn, SlimePropsOut};
+
+/// One column of a board, lent out to have a [`ColumnJob`] stepped against it.
+pub trait ColumnMut<S = f32> {
+    type Loc: Copy;
+    /// [`JigglyBoard::mut_slime_with`], for a slime in this column.
+    fn mut_slime_with(
+        &mut self,
+        loc: Self::Loc,
+        f: impl FnOnce(SlimePropsIn<S>) -> SlimePropsOut<S>,
+    );
+}
+
+/// A column's share of the column pass, handed out by [`JigglyBoard::run_physics_par`].
+///
+/// Everything about its slimes that has to come from the board as a whole, `mass`, `props_for` and
+/// friends, was asked beforehand, so stepping it only needs the column itself.
+pub struct ColumnJob<Loc, S = f32> {
+    /// `None` once stepped, or for a column the board said is clean.
+    job: Option<Job<Loc, S>>,
+    stepped: Option<Stepped<Loc, S>>,
+}
+
+impl<Loc: Copy, S: Scalar> ColumnJob<Loc, S> {
+    /// Step every slime in the column through `column`, which has to be the column this job is for.
+    /// Stepping it again does nothing.
+    pub fn step(&mut self, column: &mut impl ColumnMut<S, Loc = Loc>) {
+        if let Some(job) = self.job.take() {
+            self.stepped = Some(step_alone(column, &job));
+        }
+    }
+}
+
+/// What a [`ColumnJob`] needs to step its column.
+struct Job<Loc, S> {
+    ctx: StepContext<S>,
+    /// Where the column's floor is.
+    base: S,
+    /// Bottom first, with their gaps below, masses and props.
+    slimes: Vec<(Loc, S, S, PhysicsProperties<S>)>,
+}
+
+/// What stepping a column on its own did, to be recorded back on the calling thread.
+struct Stepped<Loc, S> {
+    outcomes: Vec<(Loc, StepOutcome<S>, S)>,
+    /// Whether every slime in it ended up at rest.
+    at_rest: bool,
+}
+
+/// A column of `board`, stepped through the board's own `mut_slime_with`.
+struct OnBoard<'a, B: ?Sized>(&'a mut B);
+
+impl<S: Scalar, B: JigglyBoard<S> + ?Sized> ColumnMut<S> for OnBoard<'_, B> {
+    type Loc = B::Loc;
+    fn mut_slime_with(
+        &mut self,
+        loc: Self::Loc,
+        f: impl FnOnce(SlimePropsIn<S>) -> SlimePropsOut<S>,
+    ) {
+        self.0.mut_slime_with(loc, f);
+    }
+}
+
+/// The default [`JigglyBoard::par_columns_mut`]: every job in turn, on this thread.
+pub(crate) fn step_in_turn<S: Scalar, B: JigglyBoard<S> + ?Sized>(
+    board: &mut B,
+    jobs: &mut [ColumnJob<B::Loc, S>],
+) {
+    for job in jobs {
+        job.step(&mut OnBoard(&mut *board));
+    }
+}
+
+/// [`JigglyBoard::run_physics_par`].
+pub(crate) fn run_physics_par<S: Scalar, B: JigglyBoard<S> + ?Sized>(
+    board: &mut B,
+    dt: S,
+    physprop: &PhysicsProperties<S>,
+) -> bool {
+    pending::assert_valid(physprop);
+    if !pending::steppable(dt) {
+        return board.is_at_rest();
+    }
+    let grouped = board
+        .cols()
+        .flatten()
+        .any(|loc| board.group_of(loc).is_some());
+    if grouped {
+        return board.run_physics(dt, physprop);
+    }
+    let substeps = physprop.substeps_for(dt);
+    let sub_dt = dt / S::from_u32(substeps);
+    let mut scratch = PhysicsScratch::new();
+    let mut settled = true;
+    for _ in 0..substeps {
+        settled &= run_step(board, sub_dt, physprop, &mut scratch);
+    }
+    !pending::flush_carried(board, physprop, &mut scratch, &mut ()) && settled
+}
+
+/// [`pending::run_step`], with the column pass handed to [`JigglyBoard::par_columns_mut`].
+fn run_step<S: Scalar, B: JigglyBoard<S> + ?Sized>(
+    board: &mut B,
+    dt: S,
+    physprop: &PhysicsProperties<S>,
+    scratch: &mut PhysicsScratch<B::Loc, B::Dir, S>,
+) -> bool {
+    let mut pending = PendingStep::begin(board, dt, physprop, core::mem::take(scratch));
+    pending.propagate_carried(board, physprop, &mut ());
+    let mut jobs: Vec<_> = pending
+        .take_columns()
+        .map(|col| ColumnJob {
+            job: col.map(|(ctx, locs)| Job {
+                base: board.column_base(ctx.col_index),
+                ctx,
+                slimes: locs
+                    .iter()
+                    .map(|&loc| {
+                        let props = board.props_for(loc, physprop);
+                        (loc, board.gap_before(loc), board.mass(loc), props)
+                    })
+                    .collect(),
+            }),
+            stepped: None,
+        })
+        .collect();
+    board.par_columns_mut(&mut jobs);
+    for (col_index, mut job) in jobs.into_iter().enumerate() {
+        // Whatever the board left unstepped still has to be
+        job.step(&mut OnBoard(&mut *board));
+        let Some(Stepped {
+            outcomes,
+            mut at_rest,
+        }) = job.stepped
+        else {
+            continue;
+        };
+        for (location, outcome, up_factor) in outcomes {
+            if pending.record_outcome(board, location, &outcome, up_factor, &mut ()) {
+                at_rest = false;
+            }
+        }
+        if at_rest {
+            board.on_column_at_rest(col_index);
+        }
+    }
+    pending.finish_columns(board, physprop, &mut ());
+    pending.propagate_within_budget(board, physprop, &mut ());
+    let (settled, buf) = pending.finish(board);
+    *scratch = buf;
+    settled
+}
+
+/// The column pass for one column, away from the rest of the board.
+fn step_alone<S: Scalar, C: ColumnMut<S> + ?Sized>(
+    column: &mut C,
+    job: &Job<C::Loc, S>,
+) -> Stepped<C::Loc, S> {
+    let mut jiggle_offset = job.base;
+    let mut falling_below = None;
+    let mut at_rest = true;
+    let mut outcomes = Vec::with_capacity(job.slimes.len());
+    for (location, gap, mass, cell_props) in &job.slimes {
+        jiggle_offset += *gap;
+        let mut outcome = StepOutcome::default();
+        column.mut_slime_with(*location, |props_in| {
+            let out = step::step_slime(
+                props_in,
+                &mut jiggle_offset,
+                &mut falling_below,
+                *mass,
+                cell_props,
+                &job.ctx,
+                &mut outcome,
+            );
+            at_rest &= out.state.is_at_rest();
+            out
+        });
+        outcomes.push((*location, outcome, cell_props.propagate_up_factor));
+    }
+    Stepped { outcomes, at_rest }
+}
+
+/// A [`JigglyBoard`] that can lend out each of its columns separately, so that
+/// [`ParallelBoard::step_columns_par`] can step them on rayon's threads.
+///
+/// A `Vec` of columns, say, can hand out `iter_mut()` over them. Nothing calls it on its own: have
+/// [`JigglyBoard::par_columns_mut`] call [`ParallelBoard::step_columns_par`], and step with
+/// [`JigglyBoard::run_physics_par`].
+#[cfg(feature = "parallel")]
+pub trait ParallelBoard<S: Scalar + Send + Sync = f32>: JigglyBoard<S, Loc: Send + Sync> {
+    type ColumnMut<'a>: ColumnMut<S, Loc = Self::Loc> + Send
+    where
+        Self: 'a;
+    /// Every column, in the same order as [`JigglyBoard::cols`].
+    fn columns_mut(&mut self) -> impl Iterator<Item = Self::ColumnMut<'_>>;
+    /// Step each of `jobs` against its column from `columns_mut`, spread over rayon's threads.
+    fn step_columns_par(&mut self, jobs: &mut [ColumnJob<Self::Loc, S>]) {
+        let columns: Vec<_> = self.columns_mut().collect();
+        assert_eq!(
+            columns.len(),
+            jobs.len(),
+            "columns_mut gave a different number of columns than cols"
+        );
+        columns
+            .into_par_iter()
+            .zip(jobs.par_iter_mut())
+            .for_each(|(mut column, job)| job.step(&mut column));
+    }
+}
@@ -0,0 +1,962 @@
+//! One physics step, broken into pieces that can be spread over several calls.
+
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+use crate::active::ActiveSet;
+use crate::group::Groups;
+use crate::interp::{RenderHistory, RenderProps};
+#[cfg(feature = "alloc")]
+use crate::layout::BoardLayoutCache;
+use crate::small::SmallDeque;
+use crate::step::{self, StepContext, StepOutcome};
+use crate::storage::DefaultStorage;
+use crate::storage::sealed::{List, Queue};
+#[cfg(feature = "alloc")]
+use crate::{ActiveKind, WaveMode, impulse};
+use crate::{
+    Direction, GravityDirection, JigglePropagation, JigglyBoard, PhysicsObserver,
+    PhysicsProperties, Scalar, ScratchStorage, SlimeState,
+};
+
+/// The buffers a physics step works in, kept from one step to the next so that stepping a board
+/// doesn't allocate once they've grown big enough for it.
+///
+/// Pass the same one to every call of [`JigglyBoard::run_physics_with_scratch`].
+///
+/// Made [`PhysicsScratch::with_capacity`], everything is allocated up front and the propagation queues
+/// never grow past it, so stepping stays within a fixed amount of memory however big a cascade gets.
+/// With `ArrayStorage` it doesn't need a heap at all.
+pub struct PhysicsScratch<Loc, Dir, S = f32, M: ScratchStorage = DefaultStorage> {
+    /// Every column's slimes, bottom first, one column after another.
+    locs: M::List<Loc>,
+    /// Where each column is in `locs`, or `None` for ones the board said are clean or that didn't fit.
+    cols: M::List<Option<(usize, usize)>>,
+    propagations: M::Queue<JigglePropagation<Loc, Dir, S>>,
+    /// Propagations `PhysicsProperties::max_propagations_per_step` left for the next step, oldest first.
+    carried: M::Queue<JigglePropagation<Loc, Dir, S>>,
+    popped: M::List<Loc>,
+    merged: M::List<Loc>,
+    slides: M::List<(Loc, S)>,
+    pub(crate) render: RenderHistory<S>,
+    /// The slimes still moving after the last call to `run_physics_with_scratch`, if they're being tracked.
+    #[cfg(feature = "alloc")]
+    active: Option<ActiveSet<Loc>>,
+    /// The most `propagations` and `carried` may each hold, if they're bounded.
+    max_propagations: Option<usize>,
+    overflow: PropagationOverflow,
+    /// Propagations dropped for want of room since the last step began.
+    truncated: usize,
+    /// Columns left unstepped for want of room since the last step began.
+    skipped: usize,
+}
+
+/// What a [`PhysicsScratch::with_capacity`] does with a propagation that doesn't fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PropagationOverflow {
+    /// Drop whichever of the queued propagations and the new one has the smallest impulse, the newest of
+    /// those if it's a tie.
+    #[default]
+    DropWeakest,
+    /// Drop the new one, keeping what was queued first.
+    DropNewest,
+}
+
+impl<Loc, Dir, S: Scalar> PhysicsScratch<Loc, Dir, S> {
+    pub fn new() -> Self {
+        Self::new_in()
+    }
+    /// Room for `slimes` slimes over `columns` columns, and at most `propagations` jiggle propagations
+    /// queued at once, with anything more dropped as `overflow` says.
+    ///
+    /// `propagations` bounds the queue for this step and the one carried over to the next separately. The
+    /// waves those propagations spread into, and a board's groups, still get their own buffers.
+    pub fn with_capacity(
+        slimes: usize,
+        columns: usize,
+        propagations: usize,
+        overflow: PropagationOverflow,
+    ) -> Self {
+        Self::with_capacity_in(slimes, columns, propagations, overflow)
+    }
+}
+
+impl<Loc, Dir, S: Scalar, M: ScratchStorage> PhysicsScratch<Loc, Dir, S, M> {
+    /// [`PhysicsScratch::new`], keeping its buffers in `M`, e.g.
+    /// `PhysicsScratch::<_, _, f32, ArrayStorage<64>>::new_in()`.
+    pub fn new_in() -> Self {
+        PhysicsScratch {
+            locs: Default::default(),
+            cols: Default::default(),
+            propagations: Default::default(),
+            carried: Default::default(),
+            popped: Default::default(),
+            merged: Default::default(),
+            slides: Default::default(),
+            render: RenderHistory::new(false),
+            #[cfg(feature = "alloc")]
+            active: None,
+            max_propagations: None,
+            overflow: PropagationOverflow::DropWeakest,
+            truncated: 0,
+            skipped: 0,
+        }
+    }
+    /// [`PhysicsScratch::with_capacity`], keeping its buffers in `M`. With
+    /// `ArrayStorage` the room is fixed already, and `propagations` can only bound
+    /// the queues tighter.
+    pub fn with_capacity_in(
+        slimes: usize,
+        columns: usize,
+        propagations: usize,
+        overflow: PropagationOverflow,
+    ) -> Self {
+        PhysicsScratch {
+            locs: List::with_capacity(slimes),
+            cols: List::with_capacity(columns),
+            propagations: Queue::with_capacity(propagations),
+            carried: Queue::with_capacity(propagations),
+            popped: List::with_capacity(slimes),
+            merged: List::with_capacity(slimes),
+            slides: List::with_capacity(slimes),
+            render: RenderHistory::new(false),
+            #[cfg(feature = "alloc")]
+            active: None,
+            max_propagations: Some(propagations),
+            overflow,
+            truncated: 0,
+            skipped: 0,
+        }
+    }
+    /// Also remember what every slime looked like one call to `run_physics_with_scratch` ago, handing it
+    /// to [`JigglyBoard::on_previous_output`] whenever a slime is stepped so rendering can blend between
+    /// the two. Needs [`JigglyBoard::loc_index`].
+    #[cfg(feature = "alloc")]
+    pub fn with_interpolation(mut self) -> Self {
+        self.render = RenderHistory::new(true);
+        self
+    }
+    /// Also keep a list of the slimes that aren't settled, up to date as of the end of each call to
+    /// [`JigglyBoard::run_physics_with_scratch`] or [`JigglyBoard::run_physics_cached`], so
+    /// [`PhysicsScratch::active_cells`] only costs as much as there are slimes moving. Needs
+    /// [`JigglyBoard::loc_index`].
+    ///
+    /// It only sees what stepping does, so after changing slimes some other way, e.g. setting one
+    /// jiggling by hand, it won't include them until a step touches them. Slimes that change places
+    /// between steps can leave it out of date the same way.
+    #[cfg(feature = "alloc")]
+    pub fn with_active_tracking(mut self) -> Self {
+        self.active = Some(ActiveSet::new());
+        self
+    }
+    /// The slimes that weren't settled at the end of the last step, and what they were doing, in no
+    /// particular order. Empty unless made [`PhysicsScratch::with_active_tracking`].
+    #[cfg(feature = "alloc")]
+    pub fn active_cells(&self) -> impl Iterator<Item = (Loc, ActiveKind)> + '_
+    where
+        Loc: Copy,
+    {
+        self.active
+            .iter()
+            .flat_map(|active| active.cells().iter().copied())
+    }
+    /// How many jiggle propagations are waiting for the next step, having gone over
+    /// `PhysicsProperties::max_propagations_per_step`.
+    pub fn carried_propagations(&self) -> usize {
+        self.carried.len()
+    }
+    /// How many jiggle propagations the last step dropped for lack of room. Always zero unless made
+    /// [`PhysicsScratch::with_capacity`].
+    pub fn truncated_propagations(&self) -> usize {
+        self.truncated
+    }
+    /// How many columns the last step left unstepped for lack of room, leaving the board unsettled. Always
+    /// zero unless made with `ArrayStorage`.
+    pub fn skipped_columns(&self) -> usize {
+        self.skipped
+    }
+    /// Queue `propagation` for this step, making room for it as `overflow` says if it's full.
+    fn push_propagation(&mut self, propagation: JigglePropagation<Loc, Dir, S>) {
+        push_bounded(
+            &mut self.propagations,
+            propagation,
+            self.max_propagations,
+            self.overflow,
+            &mut self.truncated,
+        );
+    }
+    /// Carry `propagation` over to the next step, making room for it as `overflow` says if it's full.
+    fn carry(&mut self, propagation: JigglePropagation<Loc, Dir, S>) {
+        push_bounded(
+            &mut self.carried,
+            propagation,
+            self.max_propagations,
+            self.overflow,
+            &mut self.truncated,
+        );
+    }
+    /// Note down the next column as the slimes in `range` of `locs`, or `None` for one the board said is
+    /// clean.
+    fn push_col(&mut self, range: Option<(usize, usize)>) {
+        if self.cols.push(range).is_err() {
+            self.skipped += 1;
+        }
+    }
+    /// [`PhysicsScratch::push_col`] for a column that needs stepping, and so is skipped if its slimes
+    /// didn't fit.
+    fn push_dirty_col(&mut self, range: Option<(usize, usize)>) {
+        if range.is_none() {
+            self.skipped += 1;
+        }
+        self.push_col(range);
+    }
+    // Carried propagations outlive the step that sent them, so they're left alone
+    fn clear(&mut self) {
+        self.truncated = 0;
+        self.skipped = 0;
+        self.locs.clear();
+        self.cols.clear();
+        self.propagations.clear();
+        self.popped.clear();
+        self.merged.clear();
+        self.slides.clear();
+    }
+}
+
+impl<Loc, Dir, S: Scalar, M: ScratchStorage> Default for PhysicsScratch<Loc, Dir, S, M> {
+    fn default() -> Self {
+        Self::new_in()
+    }
+}
+
+/// Everything a step has gathered so far, until the propagations it sends out have been applied.
+pub(crate) struct PendingStep<Loc, Dir, S, M: ScratchStorage = DefaultStorage> {
+    dt: S,
+    idle_phase: S,
+    landed_from: Dir,
+    buf: PhysicsScratch<Loc, Dir, S, M>,
+    /// The next entry of `buf.cols` to step.
+    next_col: usize,
+    /// What `cols` counts the first entry of `buf.cols` as.
+    first_col_index: usize,
+    groups: Groups<Loc, S>,
+    /// Whether [`PendingStep::finish_columns`] has run.
+    columns_finished: bool,
+    /// How much of `PhysicsProperties::max_propagations_per_step` has gone so far.
+    spent: usize,
+    settled: bool,
+    /// Where [`PendingStep::propagate`] has traced propagations to on `loc_index` boards so far.
+    #[cfg(feature = "alloc")]
+    traced: impulse::Traced<Loc, S>,
+}
+
+/// How much of a step [`JigglyBoard::run_physics_partial`] may do per call, and how far it has got.
+///
+/// Keep using the same budget until the step finishes; it holds on to the step in between.
+pub struct StepBudget<Loc, Dir, S = f32, M: ScratchStorage = DefaultStorage> {
+    /// Columns to step per call.
+    pub columns: usize,
+    /// Jiggle propagations to apply per call, each along with every slime it spreads to.
+    pub propagations: usize,
+    pending: Option<PendingStep<Loc, Dir, S, M>>,
+    scratch: Option<PhysicsScratch<Loc, Dir, S, M>>,
+}
+
+impl<Loc, Dir, S> StepBudget<Loc, Dir, S> {
+    /// Zero is treated as one, so every call gets something done.
+    pub fn new(columns: usize, propagations: usize) -> Self {
+        Self::new_in(columns, propagations)
+    }
+}
+
+impl<Loc, Dir, S, M: ScratchStorage> StepBudget<Loc, Dir, S, M> {
+    /// [`StepBudget::new`], stepping in a [`PhysicsScratch`] that keeps its buffers in `M`.
+    pub fn new_in(columns: usize, propagations: usize) -> Self {
+        StepBudget {
+            columns,
+            propagations,
+            pending: None,
+            scratch: None,
+        }
+    }
+    /// Whether a step has been started and not yet finished.
+    pub fn in_progress(&self) -> bool {
+        self.pending.is_some()
+    }
+}
+
+impl<Loc: Copy, Dir: Direction + Copy + PartialEq, S: Scalar, M: ScratchStorage>
+    StepBudget<Loc, Dir, S, M>
+{
+    /// Do this call's share of the current step, starting one of `dt` if there isn't one.
+    pub(crate) fn spend<B: JigglyBoard<S, Loc = Loc, Dir = Dir> + ?Sized>(
+        &mut self,
+        board: &mut B,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+    ) -> Option<bool> {
+        if self.pending.is_none() {
+            assert_valid(physprop);
+            if !steppable(dt) {
+                return Some(board.is_at_rest());
+            }
+        }
+        let scratch = &mut self.scratch;
+        let pending = self.pending.get_or_insert_with(|| {
+            let scratch = scratch.take().unwrap_or_else(PhysicsScratch::new_in);
+            PendingStep::begin(board, dt, physprop, scratch)
+        });
+        if !pending.columns_finished {
+            for _ in 0..self.columns.max(1) {
+                if !pending.step_column(board, physprop, &mut ()) {
+                    break;
+                }
+            }
+            if pending.next_col < pending.buf.cols.len() {
+                return None;
+            }
+            pending.finish_columns(board, physprop, &mut ());
+        }
+        if !pending.propagate(board, physprop, &mut (), Some(self.propagations.max(1))) {
+            return None;
+        }
+        let pending = self.pending.take()?;
+        let (settled, scratch) = pending.finish(board);
+        self.scratch = Some(scratch);
+        Some(settled)
+    }
+}
+
+impl<Loc: Copy, Dir: Direction + Copy + PartialEq, S: Scalar, M: ScratchStorage>
+    PendingStep<Loc, Dir, S, M>
+{
+    /// Start a step of `dt`, taking a snapshot of the board's columns and idle clock into `buf`.
+    pub fn begin<B: JigglyBoard<S, Loc = Loc, Dir = Dir> + ?Sized>(
+        board: &B,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+        mut buf: PhysicsScratch<Loc, Dir, S, M>,
+    ) -> Self {
+        buf.clear();
+        let gravity_dir = board.gravity_dir();
+        // Idle wobble moves even settled slimes
+        let skip_clean = physprop.idle_wobble_amplitude <= S::ZERO;
+        for (col_index, col) in board.cols().enumerate() {
+            if skip_clean && !board.column_dirty_hint(col_index) {
+                buf.push_col(None);
+                continue;
+            }
+            let range = push_bottom_first(&mut buf.locs, col, gravity_dir);
+            buf.push_dirty_col(range);
+        }
+        Self::with_buffers(board, dt, physprop, buf, 0)
+    }
+    /// [`PendingStep::begin`], with the columns from `layout` rather than the board.
+    #[cfg(feature = "alloc")]
+    pub fn begin_with_layout<B: JigglyBoard<S, Loc = Loc, Dir = Dir> + ?Sized>(
+        board: &B,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+        layout: &BoardLayoutCache<Loc>,
+        mut buf: PhysicsScratch<Loc, Dir, S, M>,
+    ) -> Self {
+        buf.clear();
+        let skip_clean = physprop.idle_wobble_amplitude <= S::ZERO;
+        let (locs, cols) = layout.columns();
+        for (col_index, &(start, end)) in cols.iter().enumerate() {
+            if skip_clean && !board.column_dirty_hint(col_index) {
+                buf.push_col(None);
+                continue;
+            }
+            let range = push_all(&mut buf.locs, locs[start..end].iter().copied());
+            buf.push_dirty_col(range);
+        }
+        Self::with_buffers(board, dt, physprop, buf, 0)
+    }
+    /// Start a step of `dt` over just the one column `col`, which is the `col_index`th.
+    #[cfg(feature = "alloc")]
+    pub fn single<B: JigglyBoard<S, Loc = Loc, Dir = Dir> + ?Sized>(
+        board: &B,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+        col_index: usize,
+        col: impl Iterator<Item = Loc>,
+    ) -> Self {
+        let mut buf = PhysicsScratch::new_in();
+        let range = push_bottom_first(&mut buf.locs, col, board.gravity_dir());
+        buf.push_dirty_col(range);
+        Self::with_buffers(board, dt, physprop, buf, col_index)
+    }
+    fn with_buffers<B: JigglyBoard<S, Loc = Loc, Dir = Dir> + ?Sized>(
+        board: &B,
+        dt: S,
+        physprop: &PhysicsProperties<S>,
+        buf: PhysicsScratch<Loc, Dir, S, M>,
+        first_col_index: usize,
+    ) -> Self {
+        let landed_from = match board.gravity_dir() {
+            GravityDirection::Down => Dir::UP,
+            GravityDirection::Up => Dir::UP.opposite(),
+        };
+        // Columns left out for lack of room still need stepping
+        let settled = buf.skipped == 0;
+        PendingStep {
+            dt,
+            idle_phase: S::TAU * physprop.idle_wobble_frequency * board.idle_clock(),
+            landed_from,
+            buf,
+            next_col: 0,
+            first_col_index,
+            groups: Groups::new(),
+            columns_finished: false,
+            spent: 0,
+            settled,
+            #[cfg(feature = "alloc")]
+            traced: impulse::Traced::default(),
+        }
+    }
+    /// Step the next column, returning false if there were none left.
+    pub fn step_column<B: JigglyBoard<S, Loc = Loc, Dir = Dir> + ?Sized>(
+        &mut self,
+        board: &mut B,
+        physprop: &PhysicsProperties<S>,
+        observer: &mut impl PhysicsObserver<Loc, Dir, S>,
+    ) -> bool {
+        let Some(&col) = self.buf.cols.get(self.next_col) else {
+            return false;
+        };
+        let ctx = StepContext {
+            dt: self.dt,
+            idle_phase: self.idle_phase,
+            col_index: self.first_col_index + self.next_col,
+        };
+        self.next_col += 1;
+        let Some((start, end)) = col else {
+            return true;
+        };
+        let mut at_rest = true;
+        let mut jiggle_offset = board.column_base(ctx.col_index);
+        let mut falling_below = None;
+        let mut below_group = None;
+        for i in start..end {
+            let Some(&location) = self.buf.locs.get(i) else {
+                break;
+            };
+            jiggle_offset += board.gap_before(location);
+            let mass = board.mass(location);
+            let cell_props = board.props_for(location, physprop);
+            let group = board.group_of(location);
+            let mut claimed = false;
+            let mut outcome = StepOutcome::default();
+            let mut written = None;
+            let track = self.buf.render.enabled();
+            board.mut_slime_with(location, |props_in| {
+                let props_in = match group {
+                    Some(id) => match self.groups.claim(
+                        id,
+                        location,
+                        below_group == group,
+                        props_in,
+                        (&mut jiggle_offset, &mut falling_below),
+                        &cell_props,
+                    ) {
+                        Ok(out) => {
+                            at_rest &= out.state.is_at_rest();
+                            claimed = true;
+                            if track {
+                                written = Some(RenderProps::from(&out));
+                            }
+                            return out;
+                        }
+                        Err(props_in) => props_in,
+                    },
+                    None => props_in,
+                };
+                let was_settled = matches!(props_in.state, SlimeState::Settled);
+                let out = step::step_slime(
+                    props_in,
+                    &mut jiggle_offset,
+                    &mut falling_below,
+                    mass,
+                    &cell_props,
+                    &ctx,
+                    &mut outcome,
+                );
+                observer.stepped(location, &out.state);
+                if !was_settled && matches!(out.state, SlimeState::Settled) {
+                    observer.settled(location);
+                }
+                at_rest &= out.state.is_at_rest();
+                if track {
+                    written = Some(RenderProps::from(&out));
+                }
+                out
+            });
+            if let Some(written) = written {
+                self.buf.render.record(board, location, written);
+            }
+            below_group = if claimed { group } else { None };
+            if self.record_outcome(
+                board,
+                location,
+                &outcome,
+                cell_props.propagate_up_factor,
+                observer,
+            ) {
+                at_rest = false;
+            }
+        }
+        if at_rest {
+            board.on_column_at_rest(ctx.col_index);
+        }
+        true
+    }
+    /// Deal with what stepping the slime at `location` did besides changing it, queueing up any
+    /// propagations it sends out. Returns whether that keeps its column from being at rest.
+    pub fn record_outcome<B: JigglyBoard<S, Loc = Loc, Dir = Dir> + ?Sized>(
+        &mut self,
+        board: &B,
+        location: Loc,
+        outcome: &StepOutcome<S>,
+        up_factor: S,
+        observer: &mut impl PhysicsObserver<Loc, Dir, S>,
+    ) -> bool {
+        if outcome.active {
+            self.settled = false;
+        }
+        // None of these hold more than the slimes being stepped, which all fit in `locs`
+        if outcome.popped {
+            let _ = self.buf.popped.push(location);
+        }
+        if outcome.recovered {
+            observer.recovered(location);
+        }
+        if outcome.merged {
+            let _ = self.buf.merged.push(location);
+        }
+        if let Some(x_velocity) = outcome.slid_across {
+            let _ = self.buf.slides.push((location, x_velocity));
+        }
+        if let Some(impulse) = outcome.landing {
+            observer.landed(location, impulse);
+            self.buf.push_propagation(JigglePropagation {
+                at: location,
+                impulse,
+                came_from: self.landed_from,
+                hops: 0,
+            });
+            if up_factor > S::ZERO
+                && let Some((above, impulse)) =
+                    board.apply_dir_to_loc(self.landed_from, location, impulse * up_factor)
+            {
+                self.buf.push_propagation(JigglePropagation {
+                    at: above,
+                    impulse,
+                    came_from: self.landed_from.opposite(),
+                    hops: 1,
+                });
+            }
+        }
+        outcome.landing.is_some() || outcome.slid_across.is_some() || outcome.merged
+    }
+    /// Once every column is stepped, move the groups and deal with slides and merges, queueing up
+    /// whatever propagations they send out.
+    pub fn finish_columns<B: JigglyBoard<S, Loc = Loc, Dir = Dir> + ?Sized>(
+        &mut self,
+        board: &mut B,
+        physprop: &PhysicsProperties<S>,
+        observer: &mut impl PhysicsObserver<Loc, Dir, S>,
+    ) {
+        self.columns_finished = true;
+        let group_ctx = StepContext {
+            dt: self.dt,
+            idle_phase: self.idle_phase,
+            col_index: 0,
+        };
+        let mut group_propagations = SmallDeque::<_, 4>::new();
+        if !self.groups.resolve(
+            board,
+            physprop,
+            &group_ctx,
+            self.landed_from,
+            &mut group_propagations,
+            &mut self.buf.render,
+            observer,
+        ) {
+            self.settled = false;
+        }
+        for propagation in group_propagations.drain() {
+            self.buf.push_propagation(propagation);
+        }
+        // Taken out for the loop and put back empty, so they keep their capacity
+        let mut slides = core::mem::take(&mut self.buf.slides);
+        for (location, x_velocity) in slides.drain() {
+            let props = board.props_for(location, physprop);
+            let positive = x_velocity > S::ZERO;
+            match board.try_slide(location, positive) {
+                Some((arrived, dir)) => {
+                    let impulse = props.velocity_to_impact * x_velocity.abs();
+                    board.impulse_jiggle_with(arrived, |_| SlimeState::landed());
+                    self.buf.push_propagation(JigglePropagation {
+                        at: arrived,
+                        impulse,
+                        came_from: dir.opposite(),
+                        hops: 0,
+                    });
+                }
+                None => board.impulse_jiggle_with(location, |_| SlimeState::Sliding {
+                    x_offset: if positive { S::ONE } else { -S::ONE },
+                    x_velocity: -x_velocity * props.slide_bounce,
+                    returning: true,
+                }),
+            }
+        }
+        self.buf.slides = slides;
+        let mut merged = core::mem::take(&mut self.buf.merged);
+        for location in merged.drain() {
+            let impulse = board.props_for(location, physprop).merge_impulse;
+            if let Some((into, dir)) = board.on_merged(location) {
+                self.buf.push_propagation(JigglePropagation {
+                    at: into,
+                    impulse,
+                    came_from: dir.opposite(),
+                    hops: 0,
+                });
+            }
+        }
+        self.buf.merged = merged;
+        // Propagation runs after the column pass saw everything at rest, and can set slimes jiggling again
+        if !self.buf.propagations.is_empty() {
+            self.settled = false;
+        }
+    }
+    /// Apply up to `max` of the queued propagations, or all of them if `None`. Returns true once none
+    /// are left.
+    ///
+    /// Boards with [`JigglyBoard::loc_index`] trace up to `max` of them per call, and only once the last
+    /// has been traced does each slime get everything that reached it, so they come out the same as all
+    /// at once. Under `WaveMode::Coalesced` they travel as one wave front, so they all go at once.
+    pub fn propagate<B: JigglyBoard<S, Loc = Loc, Dir = Dir> + ?Sized>(
+        &mut self,
+        board: &mut B,
+        physprop: &PhysicsProperties<S>,
+        observer: &mut impl PhysicsObserver<Loc, Dir, S>,
+        max: Option<usize>,
+    ) -> bool {
+        let queued = self.buf.propagations.len();
+        let count = max.map_or(queued, |max| max.min(queued));
+        #[cfg(feature = "alloc")]
+        if self
+            .buf
+            .propagations
+            .front()
+            .is_some_and(|first| board.loc_index(first.at).is_some())
+        {
+            if physprop.wave_mode == WaveMode::Coalesced {
+                let propagations = self.buf.propagations.drain();
+                apply(board, propagations, physprop, observer);
+                return true;
+            }
+            let propagations = self.buf.propagations.drain_front(count);
+            self.traced.trace(board, propagations, physprop, observer);
+            if !self.buf.propagations.is_empty() {
+                return false;
+            }
+            self.traced.deliver(board, physprop);
+            return true;
+        }
+        apply(
+            board,
+            self.buf.propagations.drain_front(count),
+            physprop,
+            observer,
+        );
+        self.buf.propagations.is_empty()
+    }
+    /// Apply what earlier steps went over `PhysicsProperties::max_propagations_per_step` with, on the board
+    /// as they left it, before this step sends anything new out.
+    pub fn propagate_carried<B: JigglyBoard<S, Loc = Loc, Dir = Dir> + ?Sized>(
+        &mut self,
+        board: &mut B,
+        physprop: &PhysicsProperties<S>,
+        observer: &mut impl PhysicsObserver<Loc, Dir, S>,
+    ) {
+        let carried = &mut self.buf.carried;
+        if carried.is_empty() {
+            return;
+        }
+        self.settled = false;
+        let count = per_step_budget(physprop).min(carried.len());
+        self.spent = count;
+        apply(board, carried.drain_front(count), physprop, observer);
+    }
+    /// Apply the queued propagations with whatever is left of `PhysicsProperties::max_propagations_per_step`,
+    /// carrying the rest over to the next step.
+    ///
+    /// Unlike [`PendingStep::propagate`], this holds boards with [`JigglyBoard::loc_index`] to the budget
+    /// too; only propagations applied in the same go are added up together.
+    pub fn propagate_within_budget<B: JigglyBoard<S, Loc = Loc, Dir = Dir> + ?Sized>(
+        &mut self,
+        board: &mut B,
+        physprop: &PhysicsProperties<S>,
+        observer: &mut impl PhysicsObserver<Loc, Dir, S>,
+    ) {
+        let left = per_step_budget(physprop) - self.spent;
+        // Anything still carried has to go before what's new
+        let count = if self.buf.carried.is_empty() {
+            left.min(self.buf.propagations.len())
+        } else {
+            0
+        };
+        apply(
+            board,
+            self.buf.propagations.drain_front(count),
+            physprop,
+            observer,
+        );
+        while let Some(propagation) = self.buf.propagations.pop_front() {
+            self.buf.carry(propagation);
+        }
+    }
+    /// Hand over the queued propagations instead of applying them.
+    #[cfg(feature = "alloc")]
+    pub fn drain_propagations(
+        &mut self,
+    ) -> impl Iterator<Item = JigglePropagation<Loc, Dir, S>> + '_ {
+        self.buf.propagations.drain()
+    }
+    /// Wrap up once every propagation has been applied, returning whether the board is settled and the
+    /// buffers for next time.
+    pub fn finish<B: JigglyBoard<S, Loc = Loc, Dir = Dir> + ?Sized>(
+        mut self,
+        board: &mut B,
+    ) -> (bool, PhysicsScratch<Loc, Dir, S, M>) {
+        self.groups.unify(board);
+        for location in self.buf.popped.drain() {
+            board.on_popped(location);
+        }
+        // Still owes the board some jiggling
+        let settled = self.settled && self.buf.carried.is_empty();
+        (settled, self.buf)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Loc: Copy, Dir, S: Scalar> PendingStep<Loc, Dir, S, crate::HeapStorage> {
+    /// Every column not yet stepped, with the context to step it in and its slimes bottom first, or `None`
+    /// for the ones the board said are clean. They count as stepped from here on, so the caller has to
+    /// step them itself and hand each slime's outcome to [`PendingStep::record_outcome`].
+    pub fn take_columns(&mut self) -> impl Iterator<Item = Option<(StepContext<S>, &[Loc])>> + '_ {
+        let first = self.next_col;
+        self.next_col = self.buf.cols.len();
+        let (dt, idle_phase) = (self.dt, self.idle_phase);
+        let first_col_index = self.first_col_index;
+        let locs = &self.buf.locs;
+        self.buf.cols[first..]
+            .iter()
+            .enumerate()
+            .map(move |(offset, col)| {
+                let ctx = StepContext {
+                    dt,
+                    idle_phase,
+                    col_index: first_col_index + first + offset,
+                };
+                col.map(|(start, end)| (ctx, &locs[start..end]))
+            })
+    }
+}
+
+/// `PhysicsProperties::max_propagations_per_step`, with zero as one so that every step gets something done.
+fn per_step_budget<S>(physprop: &PhysicsProperties<S>) -> usize {
+    physprop
+        .max_propagations_per_step
+        .map_or(usize::MAX, |max| max.max(1) as usize)
+}
+
+/// Push `propagation` onto the back of `queue`, first dropping one as `overflow` says if it already
+/// holds `max`, or as much as it has room for.
+fn push_bounded<Loc, Dir, S: Scalar, Q: Queue<JigglePropagation<Loc, Dir, S>>>(
+    queue: &mut Q,
+    propagation: JigglePropagation<Loc, Dir, S>,
+    max: Option<usize>,
+    overflow: PropagationOverflow,
+    truncated: &mut usize,
+) {
+    let max = max.map_or(Q::CAPACITY, |max| max.min(Q::CAPACITY));
+    if queue.len() < max {
+        let _ = queue.push_back(propagation);
+        return;
+    }
+    *truncated += 1;
+    if overflow == PropagationOverflow::DropNewest {
+        return;
+    }
+    // The last of the weakest, so ties drop the newest
+    let mut weakest: Option<(usize, S)> = None;
+    for (index, queued) in queue.iter().enumerate() {
+        let impulse = queued.impulse.abs();
+        if weakest.is_none_or(|(_, weakest)| impulse.total_cmp(&weakest) != Ordering::Greater) {
+            weakest = Some((index, impulse));
+        }
+    }
+    if let Some((index, impulse)) = weakest
+        && impulse < propagation.impulse.abs()
+    {
+        queue.remove(index);
+        let _ = queue.push_back(propagation);
+    }
+}
+
+/// Add `col` to the end of `locs` bottom first, returning where it went, or `None` if it doesn't all fit.
+fn push_bottom_first<Loc>(
+    locs: &mut impl List<Loc>,
+    col: impl Iterator<Item = Loc>,
+    gravity_dir: GravityDirection,
+) -> Option<(usize, usize)> {
+    let (start, end) = push_all(locs, col)?;
+    if gravity_dir == GravityDirection::Up {
+        locs.reverse_from(start);
+    }
+    Some((start, end))
+}
+
+/// Add `col` to the end of `locs` as it is, returning where it went, or `None` if it doesn't all fit.
+fn push_all<Loc>(
+    locs: &mut impl List<Loc>,
+    col: impl Iterator<Item = Loc>,
+) -> Option<(usize, usize)> {
+    let start = locs.len();
+    for loc in col {
+        if locs.push(loc).is_err() {
+            locs.truncate(start);
+            return None;
+        }
+    }
+    Some((start, locs.len()))
+}
+
+/// Where a step gets its columns from: `layout` if there is one, or else the board.
+#[cfg(feature = "alloc")]
+pub(crate) type Layout<'a, Loc> = Option<&'a BoardLayoutCache<Loc>>;
+/// Where a step gets its columns from, which without the `alloc` feature is always the board.
+#[cfg(not(feature = "alloc"))]
+pub(crate) type Layout<'a, Loc> = Option<&'a core::marker::PhantomData<Loc>>;
+
+/// Stop development builds at a `physprop` that fails [`PhysicsProperties::validate`], so a broken tuning
+/// shows up straight away rather than as a board that never settles. Release builds leave checking to
+/// [`JigglyBoard::try_run_physics`].
+pub(crate) fn assert_valid<S: Scalar>(physprop: &PhysicsProperties<S>) {
+    if cfg!(debug_assertions)
+        && let Err(err) = physprop.validate()
+    {
+        panic!("invalid PhysicsProperties: {err}");
+    }
+}
+
+/// Whether a step of `dt` can be run: anything that isn't positive and finite leaves the board alone.
+pub(crate) fn steppable<S: Scalar>(dt: S) -> bool {
+    dt.is_finite() && dt > S::ZERO
+}
+
+/// [`JigglyBoard::run_physics_with_scratch`], with the columns from `layout` if there is one.
+pub(crate) fn run_physics<S: Scalar, B: JigglyBoard<S> + ?Sized, M: ScratchStorage>(
+    board: &mut B,
+    dt: S,
+    physprop: &PhysicsProperties<S>,
+    scratch: &mut PhysicsScratch<B::Loc, B::Dir, S, M>,
+    layout: Layout<'_, B::Loc>,
+    observer: &mut impl PhysicsObserver<B::Loc, B::Dir, S>,
+) -> bool {
+    assert_valid(physprop);
+    if !steppable(dt) {
+        return board.is_at_rest();
+    }
+    let substeps = physprop.substeps_for(dt);
+    let sub_dt = dt / S::from_u32(substeps);
+    let mut settled = true;
+    scratch.render.begin_step();
+    #[cfg(feature = "alloc")]
+    if let Some(mut active) = scratch.active.take() {
+        let mut tracking = active.watching(observer);
+        for _ in 0..substeps {
+            settled &= run_step(board, sub_dt, physprop, scratch, layout, &mut tracking);
+        }
+        active.refresh(board);
+        scratch.active = Some(active);
+        return settled;
+    }
+    for _ in 0..substeps {
+        settled &= run_step(board, sub_dt, physprop, scratch, layout, observer);
+    }
+    settled
+}
+
+/// One whole step, done in `scratch`, with the columns from `layout` if there is one.
+pub(crate) fn run_step<S: Scalar, B: JigglyBoard<S> + ?Sized, M: ScratchStorage>(
+    board: &mut B,
+    dt: S,
+    physprop: &PhysicsProperties<S>,
+    scratch: &mut PhysicsScratch<B::Loc, B::Dir, S, M>,
+    layout: Layout<'_, B::Loc>,
+    observer: &mut impl PhysicsObserver<B::Loc, B::Dir, S>,
+) -> bool {
+    observer.step_begun();
+    let buf = core::mem::take(scratch);
+    let mut pending = match layout {
+        #[cfg(feature = "alloc")]
+        Some(layout) => PendingStep::begin_with_layout(board, dt, physprop, layout, buf),
+        _ => PendingStep::begin(board, dt, physprop, buf),
+    };
+    pending.propagate_carried(board, physprop, observer);
+    while pending.step_column(board, physprop, observer) {}
+    pending.finish_columns(board, physprop, observer);
+    pending.propagate_within_budget(board, physprop, observer);
+    let (settled, buf) = pending.finish(board);
+    *scratch = buf;
+    settled
+}
+
+/// Apply everything still carried in `scratch` regardless of budget, for callers about to drop it.
+/// Returns whether there was anything.
+pub(crate) fn flush_carried<S: Scalar, B: JigglyBoard<S> + ?Sized, M: ScratchStorage>(
+    board: &mut B,
+    physprop: &PhysicsProperties<S>,
+    scratch: &mut PhysicsScratch<B::Loc, B::Dir, S, M>,
+    observer: &mut impl PhysicsObserver<B::Loc, B::Dir, S>,
+) -> bool {
+    if scratch.carried.is_empty() {
+        return false;
+    }
+    apply(board, scratch.carried.drain(), physprop, observer);
+    true
+}
+
+/// Apply `propagations`, all at once if the board has [`JigglyBoard::loc_index`] and in order if not.
+///
+/// Without the `alloc` feature they're always applied in order.
+pub(crate) fn apply<S: Scalar, B: JigglyBoard<S> + ?Sized>(
+    board: &mut B,
+    propagations: impl IntoIterator<Item = JigglePropagation<B::Loc, B::Dir, S>>,
+    physprop: &PhysicsProperties<S>,
+    observer: &mut impl PhysicsObserver<B::Loc, B::Dir, S>,
+) {
+    // Without `alloc` there's nowhere to gather waves up, so they go one after another
+    #[cfg(feature = "alloc")]
+    let mut propagations = propagations.into_iter().peekable();
+    #[cfg(feature = "alloc")]
+    if propagations
+        .peek()
+        .is_some_and(|first| board.loc_index(first.at).is_some())
+    {
+        let propagations = propagations.collect();
+        if physprop.wave_mode == WaveMode::Coalesced {
+            impulse::propagate_coalesced(board, propagations, physprop, observer);
+        } else {
+            impulse::propagate_all(board, propagations, physprop, observer);
+        }
+        return;
+    }
+    for propagation in propagations {
+        board.propagate_jiggle_observed(propagation, physprop, observer);
+    }
+}
@@ -0,0 +1,111 @@
+//! The last few frames' snapshots, for rollback netcode.
+
+use alloc::vec::Vec;
+
+use crate::{BoardSnapshot, JigglyBoard, RollbackError, Scalar};
+
+/// Snapshots of the last `capacity` frames saved, oldest thrown away first.
+///
+/// Once it's been filled, saving reuses the oldest snapshot's allocation rather than making a new one.
+pub struct RollbackBuffer<Loc, S = f32> {
+    /// A ring, the oldest at `start`, with `len` of them in use. Ones past `len` are kept for reuse.
+    slots: Vec<(u64, BoardSnapshot<Loc, S>)>,
+    capacity: usize,
+    start: usize,
+    len: usize,
+}
+
+impl<Loc, S> RollbackBuffer<Loc, S> {
+    /// Room for `capacity` frames.
+    ///
+    /// # Panics
+    ///
+    /// If `capacity` is 0.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "rollback buffer needs room for a frame");
+        RollbackBuffer {
+            slots: Vec::with_capacity(capacity),
+            capacity,
+            start: 0,
+            len: 0,
+        }
+    }
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    /// How many frames are saved.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    fn slot(&self, nth: usize) -> &(u64, BoardSnapshot<Loc, S>) {
+        &self.slots[(self.start + nth) % self.capacity]
+    }
+    /// The saved frames, oldest first.
+    pub fn frames(&self) -> impl Iterator<Item = u64> + '_ {
+        (0..self.len).map(|nth| self.slot(nth).0)
+    }
+    pub fn oldest(&self) -> Option<u64> {
+        self.frames().next()
+    }
+    pub fn newest(&self) -> Option<u64> {
+        self.len.checked_sub(1).map(|nth| self.slot(nth).0)
+    }
+    fn find(&self, frame: u64) -> Option<usize> {
+        (0..self.len).find(|&nth| self.slot(nth).0 == frame)
+    }
+    /// The snapshot saved for `frame`, if it still is.
+    pub fn get(&self, frame: u64) -> Option<&BoardSnapshot<Loc, S>> {
+        self.find(frame).map(|nth| &self.slot(nth).1)
+    }
+    /// Forget every frame, keeping the allocations.
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.len = 0;
+    }
+}
+
+impl<Loc: Copy, S: Scalar> RollbackBuffer<Loc, S> {
+    /// Save `board` as it is at `frame`, throwing away the oldest frame if it's full.
+    ///
+    /// Frames saved for `frame` or later are thrown away first, since they're from a timeline that
+    /// didn't happen, so frames should be saved in order.
+    pub fn save<B: JigglyBoard<S, Loc = Loc> + ?Sized>(&mut self, frame: u64, board: &B) {
+        while self.newest().is_some_and(|newest| newest >= frame) {
+            self.len -= 1;
+        }
+        if self.len == self.capacity {
+            self.start = (self.start + 1) % self.capacity;
+            self.len -= 1;
+        }
+        let index = (self.start + self.len) % self.capacity;
+        if index == self.slots.len() {
+            self.slots.push((frame, BoardSnapshot::default()));
+        }
+        let (saved, snap) = &mut self.slots[index];
+        *saved = frame;
+        snap.capture(board);
+        self.len += 1;
+    }
+    /// Put `board` back how it was at `frame`, throwing away every frame saved after it, ready to save
+    /// the new timeline's frames as it's stepped again.
+    ///
+    /// Nothing changes if `frame` isn't saved.
+    pub fn rollback_to<B: JigglyBoard<S, Loc = Loc> + ?Sized>(
+        &mut self,
+        frame: u64,
+        board: &mut B,
+    ) -> Result<(), RollbackError> {
+        let Some(nth) = self.find(frame) else {
+            return Err(match self.oldest() {
+                Some(oldest) if frame < oldest => RollbackError::Evicted { frame, oldest },
+                _ => RollbackError::NotSaved { frame },
+            });
+        };
+        board.restore(&self.slot(nth).1);
+        self.len = nth + 1;
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+//! The number type the simulation runs in.
+
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use core::iter::Sum;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::math;
+
+/// A float the simulation can run in, set by the `S` parameter on [`JigglyBoard`](crate::JigglyBoard)
+/// and everything around it.
+///
+/// `f32` is the default everywhere. `f64` is for games that keep a board running for hours, where the
+/// rounding in `y_bottom` and friends would otherwise add up. [`Fixed`](crate::Fixed) is for lockstep
+/// multiplayer, where every machine has to land on exactly the same bits.
+pub trait Scalar:
+    Copy
+    + Debug
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+    + Sum
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const INFINITY: Self;
+    const PI: Self;
+    const TAU: Self;
+    /// The nearest value to `value`, for constants.
+    fn from_f64(value: f64) -> Self;
+    fn from_u32(value: u32) -> Self;
+    /// The nearest `f64`, e.g. to convert to another `Scalar`.
+    fn to_f64(self) -> f64;
+    /// Rounded towards zero, saturating at the ends of `u32`, with NaN as 0.
+    fn to_u32(self) -> u32;
+    fn abs(self) -> Self;
+    /// The smaller of the two, or whichever isn't NaN.
+    fn min(self, other: Self) -> Self;
+    /// The larger of the two, or whichever isn't NaN.
+    fn max(self, other: Self) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self;
+    fn recip(self) -> Self;
+    fn is_finite(self) -> bool;
+    fn is_nan(self) -> bool;
+    /// An ordering over every value including NaN, for sorting deterministically.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+    fn exp(self) -> Self;
+    /// `self^exponent` for positive `self`.
+    fn powf(self, exponent: Self) -> Self;
+    fn sin(self) -> Self;
+    /// Zero for anything that isn't positive.
+    fn sqrt(self) -> Self;
+}
+
+/// `value` in another [`Scalar`].
+pub(crate) fn cast<S: Scalar, T: Scalar>(value: S) -> T {
+    T::from_f64(value.to_f64())
+}
+
+macro_rules! impl_scalar {
+    ($float:ident, $math:ident) => {
+        impl Scalar for $float {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const INFINITY: Self = $float::INFINITY;
+            const PI: Self = core::$float::consts::PI;
+            const TAU: Self = core::$float::consts::TAU;
+            fn from_f64(value: f64) -> Self {
+                value as $float
+            }
+            fn from_u32(value: u32) -> Self {
+                value as $float
+            }
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+            fn to_u32(self) -> u32 {
+                self as u32
+            }
+            fn abs(self) -> Self {
+                $float::abs(self)
+            }
+            fn min(self, other: Self) -> Self {
+                $float::min(self, other)
+            }
+            fn max(self, other: Self) -> Self {
+                $float::max(self, other)
+            }
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $float::clamp(self, min, max)
+            }
+            fn recip(self) -> Self {
+                $float::recip(self)
+            }
+            fn is_finite(self) -> bool {
+                $float::is_finite(self)
+            }
+            fn is_nan(self) -> bool {
+                $float::is_nan(self)
+            }
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                $float::total_cmp(self, other)
+            }
+            fn exp(self) -> Self {
+                math::$math::exp(self)
+            }
+            fn powf(self, exponent: Self) -> Self {
+                math::$math::powf(self, exponent)
+            }
+            fn sin(self) -> Self {
+                math::$math::sin(self)
+            }
+            fn sqrt(self) -> Self {
+                math::$math::sqrt(self)
+            }
+        }
+    };
+}
+
+impl_scalar!(f32, single);
+impl_scalar!(f64, double);
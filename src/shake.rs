@@ -0,0 +1,51 @@
+//! Wobbling a whole board at once, for earthquakes and big moments.
+
+use alloc::vec::Vec;
+
+use crate::impulse::{self, Impulse};
+use crate::{Direction, GravityDirection, JigglyBoard, PhysicsProperties, Scalar, SlimeState};
+
+/// Where [`JigglyBoardExt::shake`](crate::JigglyBoardExt::shake) gets its randomness, so the crate doesn't
+/// have to pick a random number generator. Closures returning an `f32` are sources.
+pub trait RngSource {
+    /// A number from 0 up to, but not including, 1.
+    fn next_f32(&mut self) -> f32;
+}
+
+impl<F: FnMut() -> f32> RngSource for F {
+    fn next_f32(&mut self) -> f32 {
+        self()
+    }
+}
+
+pub(crate) fn shake<S: Scalar, B: JigglyBoard<S> + ?Sized>(
+    board: &mut B,
+    base_impulse: S,
+    variation: S,
+    rng: &mut impl RngSource,
+    alternate: bool,
+    physprop: &PhysicsProperties<S>,
+) {
+    // Every slime gets it from the same side a landing on it would
+    let from = match board.gravity_dir() {
+        GravityDirection::Down => B::Dir::UP,
+        GravityDirection::Up => B::Dir::UP.opposite(),
+    };
+    let cols: Vec<Vec<B::Loc>> = board.cols().map(Iterator::collect).collect();
+    for (col_index, col) in cols.into_iter().enumerate() {
+        for (slot, location) in col.into_iter().enumerate() {
+            // Drawn for every slime, so which one gets what doesn't depend on what the others are doing
+            let spread = S::from_f64(f64::from(rng.next_f32())) * S::from_f64(2.0) - S::ONE;
+            let mut impulse = base_impulse + variation * spread;
+            if alternate && (col_index + slot) % 2 == 1 {
+                impulse = -impulse;
+            }
+            let props = board.props_for(location, physprop);
+            let arriving = Impulse::arriving(impulse / board.mass(location), from);
+            board.impulse_jiggle_with(location, |state| match state {
+                SlimeState::Falling { .. } | SlimeState::Bouncing { .. } => state,
+                state => impulse::receive(state, arriving, &props, &mut false),
+            });
+        }
+    }
+}
@@ -0,0 +1,327 @@
+//! Queues and lists that keep their entries inline: ones that spill onto the heap, for buffers that are
+//! almost always tiny, and ones that never do, for stepping without a heap at all.
+
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+
+/// A double-ended queue of up to `N` entries, in place. Once it's full, whatever else is pushed is handed
+/// back.
+pub struct ArrayDeque<T, const N: usize> {
+    /// A ring of `len` entries starting at `start`.
+    items: [Option<T>; N],
+    start: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayDeque<T, N> {
+    pub fn new() -> Self {
+        ArrayDeque {
+            items: [const { None }; N],
+            start: 0,
+            len: 0,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Where the `index`th entry is in the ring.
+    fn slot(&self, index: usize) -> usize {
+        (self.start + index) % N
+    }
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        self.items[self.slot(index)].as_ref()
+    }
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let slot = self.slot(index);
+        self.items[slot].as_mut()
+    }
+    pub fn push_back(&mut self, value: T) -> Result<(), T> {
+        if self.len == N {
+            return Err(value);
+        }
+        let slot = self.slot(self.len);
+        self.items[slot] = Some(value);
+        self.len += 1;
+        Ok(())
+    }
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let value = self.items[self.start].take();
+        self.start = self.slot(1);
+        self.len -= 1;
+        value
+    }
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let slot = self.slot(self.len);
+        self.items[slot].take()
+    }
+    /// Take out the `index`th entry, moving everything after it along.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        let slot = self.slot(index);
+        let value = self.items[slot].take();
+        for i in index + 1..self.len {
+            self.swap(i - 1, i);
+        }
+        self.len -= 1;
+        value
+    }
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.slot(a), self.slot(b));
+        self.items.swap(a, b);
+    }
+    pub fn clear(&mut self) {
+        while self.pop_back().is_some() {}
+        self.start = 0;
+    }
+}
+
+impl<T, const N: usize> Default for ArrayDeque<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A list of up to `N` entries, in place. Once it's full, whatever else is pushed is handed back.
+#[cfg(feature = "heapless")]
+pub struct ArrayVec<T, const N: usize> {
+    items: [Option<T>; N],
+    len: usize,
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> ArrayVec<T, N> {
+    pub fn new() -> Self {
+        ArrayVec {
+            items: [const { None }; N],
+            len: 0,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items[..self.len].get(index)?.as_ref()
+    }
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.len == N {
+            return Err(value);
+        }
+        self.items[self.len] = Some(value);
+        self.len += 1;
+        Ok(())
+    }
+    /// Drop everything from the `len`th entry on.
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.len -= 1;
+            self.items[self.len] = None;
+        }
+    }
+    pub fn reverse_from(&mut self, from: usize) {
+        if from < self.len {
+            self.items[from..self.len].reverse();
+        }
+    }
+    /// Take every entry out, first first, as they're iterated over.
+    pub fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        let len = core::mem::replace(&mut self.len, 0);
+        self.items[..len].iter_mut().filter_map(Option::take)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> Default for ArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A double-ended queue holding up to `N` entries in place before it spills onto the heap.
+///
+/// Once spilled it stays spilled, so a buffer kept between steps doesn't go back and forth. Without the
+/// `alloc` feature it can't spill, and drops whatever doesn't fit.
+pub enum SmallDeque<T, const N: usize> {
+    Inline(ArrayDeque<T, N>),
+    #[cfg(feature = "alloc")]
+    Spilled(VecDeque<T>),
+}
+
+// Without `alloc`, only what jiggle propagation needs of it is used
+#[cfg_attr(not(feature = "alloc"), allow(dead_code))]
+impl<T, const N: usize> SmallDeque<T, N> {
+    pub fn new() -> Self {
+        SmallDeque::Inline(ArrayDeque::new())
+    }
+    /// Spilled straight away if `capacity` won't fit inline.
+    pub fn with_capacity(capacity: usize) -> Self {
+        #[cfg(feature = "alloc")]
+        if capacity > N {
+            return SmallDeque::Spilled(VecDeque::with_capacity(capacity));
+        }
+        let _ = capacity;
+        SmallDeque::new()
+    }
+    pub fn len(&self) -> usize {
+        match self {
+            SmallDeque::Inline(items) => items.len(),
+            #[cfg(feature = "alloc")]
+            SmallDeque::Spilled(items) => items.len(),
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match self {
+            SmallDeque::Inline(items) => items.get(index),
+            #[cfg(feature = "alloc")]
+            SmallDeque::Spilled(items) => items.get(index),
+        }
+    }
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match self {
+            SmallDeque::Inline(items) => items.get_mut(index),
+            #[cfg(feature = "alloc")]
+            SmallDeque::Spilled(items) => items.get_mut(index),
+        }
+    }
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        let last = self.len().checked_sub(1)?;
+        self.get_mut(last)
+    }
+    pub fn push_back(&mut self, value: T) {
+        let _ = self.try_push_back(value);
+    }
+    /// [`SmallDeque::push_back`], handing `value` back if there's no room for it. Only ever happens
+    /// without the `alloc` feature.
+    pub fn try_push_back(&mut self, value: T) -> Result<(), T> {
+        match self {
+            SmallDeque::Inline(items) => {
+                let Err(value) = items.push_back(value) else {
+                    return Ok(());
+                };
+                #[cfg(feature = "alloc")]
+                {
+                    let mut spilled = VecDeque::with_capacity(2 * N.max(1));
+                    spilled.extend(self.drain());
+                    spilled.push_back(value);
+                    *self = SmallDeque::Spilled(spilled);
+                    Ok(())
+                }
+                #[cfg(not(feature = "alloc"))]
+                Err(value)
+            }
+            #[cfg(feature = "alloc")]
+            SmallDeque::Spilled(items) => {
+                items.push_back(value);
+                Ok(())
+            }
+        }
+    }
+    pub fn pop_front(&mut self) -> Option<T> {
+        match self {
+            SmallDeque::Inline(items) => items.pop_front(),
+            #[cfg(feature = "alloc")]
+            SmallDeque::Spilled(items) => items.pop_front(),
+        }
+    }
+    pub fn pop_back(&mut self) -> Option<T> {
+        match self {
+            SmallDeque::Inline(items) => items.pop_back(),
+            #[cfg(feature = "alloc")]
+            SmallDeque::Spilled(items) => items.pop_back(),
+        }
+    }
+    /// Take out the `index`th entry, moving everything after it along.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        match self {
+            SmallDeque::Inline(items) => items.remove(index),
+            #[cfg(feature = "alloc")]
+            SmallDeque::Spilled(items) => items.remove(index),
+        }
+    }
+    pub fn swap(&mut self, a: usize, b: usize) {
+        match self {
+            SmallDeque::Inline(items) => items.swap(a, b),
+            #[cfg(feature = "alloc")]
+            SmallDeque::Spilled(items) => items.swap(a, b),
+        }
+    }
+    /// Reverse the order of everything from the `from`th entry on.
+    pub fn reverse_from(&mut self, from: usize) {
+        let len = self.len();
+        for i in 0..len.saturating_sub(from) / 2 {
+            self.swap(from + i, len - 1 - i);
+        }
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        (0..self.len()).filter_map(|index| self.get(index))
+    }
+    /// Take up to `count` entries off the front, as they're iterated over.
+    pub fn drain_front(&mut self, count: usize) -> impl Iterator<Item = T> + '_ {
+        (0..count).map_while(|_| self.pop_front())
+    }
+    /// Take every entry off the front, as they're iterated over.
+    pub fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        core::iter::from_fn(|| self.pop_front())
+    }
+    /// Empty it, keeping whatever the heap gave it.
+    pub fn clear(&mut self) {
+        match self {
+            SmallDeque::Inline(items) => items.clear(),
+            #[cfg(feature = "alloc")]
+            SmallDeque::Spilled(items) => items.clear(),
+        }
+    }
+}
+
+impl<T, const N: usize> Default for SmallDeque<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Extend<T> for SmallDeque<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+/// Extends `into`, handing whatever doesn't fit in it to `dropped` rather than losing it unnoticed.
+pub struct Overflowing<'a, T, F, const N: usize> {
+    pub into: &'a mut SmallDeque<T, N>,
+    pub dropped: F,
+}
+
+impl<T, F: FnMut(T), const N: usize> Extend<T> for Overflowing<'_, T, F, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            if let Err(value) = self.into.try_push_back(value) {
+                (self.dropped)(value);
+            }
+        }
+    }
+}
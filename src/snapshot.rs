@@ -0,0 +1,60 @@
+//! Copying a board's slimes out and putting them back, for save games and rewinding.
+
+use alloc::vec::Vec;
+
+use crate::{JigglyBoard, Scalar, SlimeState};
+
+/// One slime in a [`BoardSnapshot`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnapshotEntry<Loc, S = f32> {
+    pub loc: Loc,
+    pub state: SlimeState<S>,
+    pub y_bottom: S,
+}
+
+/// Every slime on a board as it was, from [`JigglyBoard::snapshot`]. Just data, so it can be saved
+/// however you like.
+///
+/// Only slimes are in it. Propagations carried over in a `PhysicsScratch`, what the board keeps itself
+/// (masses, groups, the idle clock) and render output aren't.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardSnapshot<Loc, S = f32> {
+    pub entries: Vec<SnapshotEntry<Loc, S>>,
+}
+
+impl<Loc, S> Default for BoardSnapshot<Loc, S> {
+    fn default() -> Self {
+        BoardSnapshot {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<Loc: Copy, S: Scalar> BoardSnapshot<Loc, S> {
+    /// Replace what's in this with `board`'s slimes, keeping the allocation.
+    pub fn capture<B: JigglyBoard<S, Loc = Loc> + ?Sized>(&mut self, board: &B) {
+        self.entries.clear();
+        for location in board.cols().flatten() {
+            if let Some(entry) = board.peek_slime(location, |&state, y_bottom| SnapshotEntry {
+                loc: location,
+                state,
+                y_bottom,
+            }) {
+                self.entries.push(entry);
+            }
+        }
+    }
+}
+
+pub(crate) fn restore<S: Scalar, B: JigglyBoard<S> + ?Sized>(
+    board: &mut B,
+    snap: &BoardSnapshot<B::Loc, S>,
+) {
+    let locations: Vec<_> = board.cols().flatten().collect();
+    for location in locations {
+        board.take_slime(location);
+    }
+    for entry in &snap.entries {
+        board.set_slime(entry.loc, entry.state, entry.y_bottom);
+    }
+}
@@ -0,0 +1,47 @@
+use core::f32::consts::PI;
+
+use crate::validate::{finite, positive};
+use crate::{DampingMode, PhysicsProperties, PhysicsPropertiesError, math};
+
+/// How close to the edge of the integrator's stability region `from_spring` is allowed to go.
+const STABILITY_MARGIN: f32 = 0.9;
+
+impl PhysicsProperties {
+    /// Derive `jiggle_stiff` and `jiggle_damp` from how the spring should look, for a fixed step of `dt`.
+    ///
+    /// `damping_ratio` of 1.0 is critically damped, below that it overshoots. The jiggle is integrated
+    /// with semi-implicit Euler, which only stays stable while `2π * frequency_hz * dt < 2`, so the
+    /// frequency is clamped to [`max_stable_frequency`](Self::max_stable_frequency) first.
+    /// Everything else comes from [`PhysicsProperties::default`].
+    ///
+    /// Fails if `dt` isn't positive, or if the result doesn't pass [`validate`](Self::validate), e.g. for
+    /// a `frequency_hz` of zero, which leaves no spring to jiggle with.
+    pub fn from_spring(
+        frequency_hz: f32,
+        damping_ratio: f32,
+        dt: f32,
+    ) -> Result<Self, PhysicsPropertiesError> {
+        finite("dt", dt)?;
+        positive("dt", dt)?;
+        let frequency_hz = Self::clamp_spring_frequency(frequency_hz, dt);
+        let damping_ratio = damping_ratio.max(0.0);
+        let omega = 2.0 * PI * frequency_hz;
+        let props = Self {
+            jiggle_stiff: omega * omega,
+            jiggle_damp: math::single::exp(-2.0 * damping_ratio * omega * dt),
+            jiggle_stiff_stretch: None,
+            damping_mode: DampingMode::PerStep,
+            ..Self::default()
+        };
+        props.validate()?;
+        Ok(props)
+    }
+    /// The highest spring frequency that `from_spring` will use at this step size.
+    pub fn max_stable_frequency(dt: f32) -> f32 {
+        STABILITY_MARGIN / (PI * dt)
+    }
+    /// Clamp `frequency_hz` into the range where the jiggle integrator doesn't blow up at `dt`.
+    pub fn clamp_spring_frequency(frequency_hz: f32, dt: f32) -> f32 {
+        frequency_hz.clamp(0.0, Self::max_stable_frequency(dt))
+    }
+}
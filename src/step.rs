@@ -0,0 +1,832 @@
+//! The per-slime state machine behind the column pass.
+
+use crate::{
+    BounceMode, EnvelopeMode, OffsetLimitMode, PhysicsProperties, Scalar, SlimePropsIn,
+    SlimePropsOut, SlimeState, smooth_step,
+};
+
+/// How far above the top of its stack, in cells, a slime can be and still count as resting on it.
+const SUPPORT_EPSILON: f64 = 1e-3;
+
+/// How far above the top of its stack, in cells, a settled slime has to be before it counts as having had
+/// the slime under it taken away. Smaller gaps, like something beneath squashing, it just follows.
+const VACATED_GAP: f64 = 0.5;
+
+/// Everything about the current step that doesn't change from slime to slime in a column.
+pub(crate) struct StepContext<S = f32> {
+    pub dt: S,
+    pub idle_phase: S,
+    pub col_index: usize,
+}
+
+/// What stepping one slime did, besides producing its new props.
+#[derive(Default)]
+pub(crate) struct StepOutcome<S = f32> {
+    /// The slime is still moving, so the board isn't settled.
+    pub active: bool,
+    /// The slime hit its floor this step with this impulse.
+    pub landing: Option<S>,
+    /// The slime finished popping this step.
+    pub popped: bool,
+    /// The slime slid a whole cell sideways this step, at this velocity.
+    pub slid_across: Option<S>,
+    /// The slime finished merging into its neighbour this step.
+    pub merged: bool,
+    /// The slime's state had gone non-finite, so it was reset.
+    pub recovered: bool,
+}
+
+/// Advance one slime, given the height its column has stacked up to so far in `jiggle_offset`.
+///
+/// `falling_below` is the velocity of the slime just beneath if that one is still falling; it's updated
+/// for the next slime up.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn step_slime<S: Scalar>(
+    SlimePropsIn { state, y_bottom }: SlimePropsIn<S>,
+    jiggle_offset: &mut S,
+    falling_below: &mut Option<S>,
+    mass: S,
+    physprop: &PhysicsProperties<S>,
+    ctx: &StepContext<S>,
+    outcome: &mut StepOutcome<S>,
+) -> SlimePropsOut<S> {
+    use SlimeState::*;
+    let riding = falling_below.take();
+    let finite = y_bottom.is_finite()
+        && match state {
+            Settled | Frozen => true,
+            AboutToFall { remaining } => remaining.is_finite(),
+            Falling { velocity, fallen }
+            | Bouncing {
+                velocity, fallen, ..
+            } => velocity.is_finite() && fallen.is_finite(),
+            Jiggling {
+                momentum,
+                offset,
+                life,
+                momentum_x,
+                offset_x,
+            } => [momentum, offset, life, momentum_x, offset_x]
+                .iter()
+                .all(|v| v.is_finite()),
+            Launched { velocity, height } => velocity.is_finite() && height.is_finite(),
+            Squished { amount } => amount.is_finite(),
+            Spawning { progress } | Popping { progress } => progress.is_finite(),
+            Held { buffered_impulse } => buffered_impulse.is_finite(),
+            Sliding {
+                x_offset,
+                x_velocity,
+                ..
+            } => x_offset.is_finite() && x_velocity.is_finite(),
+            Swapping {
+                progress,
+                from_x,
+                from_y,
+                buffered_impulse,
+            } => [progress, from_x, from_y, buffered_impulse]
+                .iter()
+                .all(|v| v.is_finite()),
+            Merging {
+                progress,
+                into_x,
+                into_y,
+            } => [progress, into_x, into_y].iter().all(|v| v.is_finite()),
+        };
+    if !finite {
+        // Left alone it would never get under the epsilons, and the board would never settle
+        outcome.active = true;
+        outcome.recovered = true;
+        return step_settled(jiggle_offset, physprop, ctx);
+    }
+    match state {
+        Settled if y_bottom - *jiggle_offset > S::from_f64(VACATED_GAP) * physprop.cell_height => {
+            // Whatever was under it is gone, so drop rather than snapping down
+            outcome.active = true;
+            *falling_below = riding;
+            step_slime(
+                SlimePropsIn {
+                    state: SlimeState::falling(physprop),
+                    y_bottom,
+                },
+                jiggle_offset,
+                falling_below,
+                mass,
+                physprop,
+                ctx,
+                outcome,
+            )
+        }
+        Settled => step_settled(jiggle_offset, physprop, ctx),
+        AboutToFall { remaining } => {
+            outcome.active = true;
+            step_about_to_fall(remaining, y_bottom, jiggle_offset, physprop, ctx)
+        }
+        Falling { velocity, fallen } => {
+            outcome.active = true;
+            step_falling(
+                velocity,
+                fallen,
+                None,
+                y_bottom,
+                (jiggle_offset, riding, falling_below),
+                mass,
+                physprop,
+                ctx,
+                outcome,
+            )
+        }
+        Bouncing {
+            velocity,
+            fallen,
+            bounces_left,
+        } => {
+            outcome.active = true;
+            step_falling(
+                velocity,
+                fallen,
+                Some(bounces_left),
+                y_bottom,
+                (jiggle_offset, riding, falling_below),
+                mass,
+                physprop,
+                ctx,
+                outcome,
+            )
+        }
+        Jiggling {
+            momentum,
+            offset,
+            life,
+            momentum_x,
+            offset_x,
+        } => {
+            outcome.active = true;
+            step_jiggling(
+                Spring { momentum, offset },
+                Spring {
+                    momentum: momentum_x,
+                    offset: offset_x,
+                },
+                life,
+                jiggle_offset,
+                physprop,
+                ctx,
+            )
+        }
+        Launched { velocity, height } => {
+            outcome.active = true;
+            step_launched(
+                velocity,
+                height,
+                jiggle_offset,
+                mass,
+                physprop,
+                ctx,
+                outcome,
+            )
+        }
+        Squished { amount } => step_squished(amount, jiggle_offset, physprop),
+        Spawning { progress } => {
+            outcome.active = true;
+            step_spawning(progress, y_bottom, jiggle_offset, physprop, ctx)
+        }
+        Popping { progress } => step_popping(progress, jiggle_offset, physprop, ctx, outcome),
+        Held { buffered_impulse } => {
+            // Paused where it is, still holding up anything above
+            let y_bottom = y_bottom.max(*jiggle_offset);
+            *jiggle_offset = y_bottom + physprop.cell_height;
+            SlimePropsOut {
+                state: Held { buffered_impulse },
+                y_bottom,
+                y_scale: S::ONE,
+                x_scale: S::ONE,
+                x_offset: S::ZERO,
+            }
+        }
+        Sliding {
+            x_offset,
+            x_velocity,
+            returning,
+        } => {
+            outcome.active = true;
+            step_sliding(
+                x_offset,
+                x_velocity,
+                returning,
+                jiggle_offset,
+                physprop,
+                ctx,
+                outcome,
+            )
+        }
+        Swapping {
+            progress,
+            from_x,
+            from_y,
+            buffered_impulse,
+        } => {
+            outcome.active = true;
+            step_swapping(
+                progress,
+                (from_x, from_y),
+                buffered_impulse,
+                jiggle_offset,
+                physprop,
+                ctx,
+            )
+        }
+        Merging {
+            progress,
+            into_x,
+            into_y,
+        } => step_merging(
+            progress,
+            (into_x, into_y),
+            jiggle_offset,
+            physprop,
+            ctx,
+            outcome,
+        ),
+        Frozen => {
+            let out = SlimePropsOut {
+                state: Frozen,
+                y_bottom: *jiggle_offset,
+                y_scale: S::ONE,
+                x_scale: S::ONE,
+                x_offset: S::ZERO,
+            };
+            *jiggle_offset += physprop.cell_height;
+            out
+        }
+    }
+}
+
+fn step_settled<S: Scalar>(
+    jiggle_offset: &mut S,
+    physprop: &PhysicsProperties<S>,
+    ctx: &StepContext<S>,
+) -> SlimePropsOut<S> {
+    let (y_scale, x_scale) = if physprop.idle_wobble_amplitude > S::ZERO {
+        // Spread the phase around so neighbours don't breathe in lockstep
+        let phase = ctx.idle_phase
+            + S::from_f64(ctx.col_index as f64 * 1.3)
+            + *jiggle_offset / physprop.cell_height * S::from_f64(0.7);
+        let y_scale = S::ONE + physprop.idle_wobble_amplitude * phase.sin();
+        (y_scale, y_scale.recip())
+    } else {
+        (S::ONE, S::ONE)
+    };
+    let out = SlimePropsOut {
+        state: SlimeState::Settled,
+        y_bottom: *jiggle_offset,
+        y_scale,
+        x_scale,
+        x_offset: S::ZERO,
+    };
+    *jiggle_offset += physprop.cell_height;
+    out
+}
+
+fn step_about_to_fall<S: Scalar>(
+    remaining: S,
+    y_bottom: S,
+    jiggle_offset: &mut S,
+    physprop: &PhysicsProperties<S>,
+    ctx: &StepContext<S>,
+) -> SlimePropsOut<S> {
+    // Hang in place, and keep holding up everything above
+    let y_bottom = y_bottom.max(*jiggle_offset);
+    *jiggle_offset = y_bottom + physprop.cell_height;
+    let remaining = remaining - ctx.dt;
+    SlimePropsOut {
+        state: if remaining > S::ZERO {
+            SlimeState::AboutToFall { remaining }
+        } else {
+            SlimeState::Falling {
+                velocity: S::ZERO,
+                fallen: S::ZERO,
+            }
+        },
+        y_bottom,
+        y_scale: S::ONE,
+        x_scale: S::ONE,
+        x_offset: S::ZERO,
+    }
+}
+
+/// `fallen` is the distance in cells since it started falling or topped out of a bounce.
+/// `bounces_left` is `None` for a plain fall, before any `BounceMode::Count` hops have started.
+/// `riding` is the velocity of a still-falling slime it's caught up with, if any.
+#[allow(clippy::too_many_arguments)]
+fn step_falling<S: Scalar>(
+    velocity: S,
+    fallen: S,
+    bounces_left: Option<u32>,
+    y_bottom: S,
+    (jiggle_offset, riding, falling_below): (&mut S, Option<S>, &mut Option<S>),
+    mass: S,
+    physprop: &PhysicsProperties<S>,
+    ctx: &StepContext<S>,
+    outcome: &mut StepOutcome<S>,
+) -> SlimePropsOut<S> {
+    let dt = ctx.dt;
+    let start_velocity = velocity;
+    let velocity = (velocity + dt * physprop.gravity).min(physprop.terminal_velocity);
+
+    // Only moving downwards counts, otherwise a bounce would land again straight away
+    let on_floor = y_bottom <= *jiggle_offset && velocity >= S::ZERO;
+    // Averaging the speeds is exact under constant gravity, so big steps follow the same arc as small ones
+    let moved_to =
+        y_bottom - S::from_f64(0.5) * (start_velocity + velocity) * dt * physprop.cell_height;
+    // Reaching the floor partway through this step lands now, at the speed it would have had by then,
+    // so a big step can't carry it past the floor or add the overshoot to the impact
+    let gap = (y_bottom - *jiggle_offset) / physprop.cell_height;
+    let swept = !on_floor && riding.is_none() && velocity >= S::ZERO && moved_to <= *jiggle_offset;
+    let (on_floor, velocity, fallen) = if swept {
+        let impact_velocity = (start_velocity * start_velocity
+            + S::from_f64(2.0) * physprop.gravity * gap)
+            .sqrt()
+            .min(physprop.terminal_velocity);
+        // Already in the air for the part of the drop above `fallen`, if it was rising
+        let fallen = if start_velocity < S::ZERO {
+            S::ZERO
+        } else {
+            fallen
+        };
+        (true, impact_velocity, fallen + gap)
+    } else {
+        // The last step carried it a little past its floor
+        (on_floor, velocity, fallen - (-gap).max(S::ZERO))
+    };
+    if let Some(below) = riding.filter(|_| on_floor) {
+        // Caught up with a slime that's still falling, so sit on it until it lands
+        let velocity = velocity.min(below.max(S::ZERO));
+        let y_bottom = *jiggle_offset;
+        *jiggle_offset += physprop.cell_height;
+        *falling_below = Some(velocity);
+        let state = match bounces_left {
+            Some(bounces_left) => SlimeState::Bouncing {
+                velocity,
+                fallen,
+                bounces_left,
+            },
+            None => SlimeState::Falling { velocity, fallen },
+        };
+        return SlimePropsOut {
+            state,
+            y_bottom,
+            y_scale: S::ONE,
+            x_scale: S::ONE,
+            x_offset: S::ZERO,
+        };
+    }
+    if on_floor {
+        let (kept, state) = match physprop.bounce_mode {
+            BounceMode::Restitution
+                if physprop.restitution > S::ZERO && velocity > physprop.bounce_min_velocity =>
+            {
+                (
+                    physprop.restitution,
+                    SlimeState::Falling {
+                        velocity: -velocity * physprop.restitution,
+                        fallen: S::ZERO,
+                    },
+                )
+            }
+            BounceMode::Count { bounces, factor } => match bounces_left.unwrap_or(bounces) {
+                0 => (S::ZERO, SlimeState::landed()),
+                left => (
+                    factor,
+                    SlimeState::Bouncing {
+                        velocity: -velocity * factor,
+                        fallen: S::ZERO,
+                        bounces_left: left - 1,
+                    },
+                ),
+            },
+            BounceMode::Restitution => (S::ZERO, SlimeState::landed()),
+        };
+        let impact = physprop
+            .impact_mode
+            .impact(physprop.velocity_to_impact * velocity, fallen.max(S::ZERO));
+        // Landings always squash, whatever the impact mode makes of an odd velocity
+        outcome.landing = Some((impact * mass * (S::ONE - kept)).max(S::ZERO));
+        let y_bottom = *jiggle_offset;
+        // jiggle_offset += physprop.cell_height;
+        SlimePropsOut {
+            state,
+            x_scale: S::ONE,
+            y_scale: S::ONE,
+            y_bottom,
+            x_offset: S::ZERO,
+        }
+    } else {
+        // Measured from the top of the arc, so a bounce doesn't count the way up
+        let fallen = if velocity < S::ZERO {
+            S::ZERO
+        } else {
+            fallen + (y_bottom - moved_to) / physprop.cell_height
+        };
+        let y_bottom = moved_to;
+        let clamped_vel = physprop.fall_stretch.y_scale(velocity);
+        let y_scale = S::ONE * clamped_vel;
+        let x_scale = physprop
+            .squash_mode
+            .preserve_area(y_scale)
+            .unwrap_or(S::ONE / clamped_vel);
+        // Anything falling above it can't pass through it
+        *jiggle_offset = jiggle_offset.max(y_bottom + physprop.cell_height);
+        *falling_below = Some(velocity);
+        let state = match bounces_left {
+            Some(bounces_left) => SlimeState::Bouncing {
+                velocity,
+                fallen,
+                bounces_left,
+            },
+            None => SlimeState::Falling { velocity, fallen },
+        };
+        SlimePropsOut {
+            state,
+            y_bottom,
+            y_scale,
+            x_scale,
+            x_offset: S::ZERO,
+        }
+    }
+}
+
+/// One axis of a jiggling slime: momentum plus offset from rest.
+struct Spring<S> {
+    momentum: S,
+    offset: S,
+}
+
+impl<S: Scalar> Spring<S> {
+    fn step(self, stiff: S, physprop: &PhysicsProperties<S>, dt: S) -> Spring<S> {
+        let accdt = stiff * -self.offset * dt;
+        let mut momentum = ((self.momentum + accdt)
+            * physprop.damping_mode.factor(physprop.jiggle_damp, dt))
+        .clamp(-physprop.max_momentum, physprop.max_momentum);
+        let mut offset = self.offset + momentum * dt;
+        if offset.abs() > physprop.max_offset {
+            let overshoot = offset;
+            offset = offset.clamp(-physprop.max_offset, physprop.max_offset);
+            // Only the part heading further out gets touched
+            if momentum * offset > S::ZERO {
+                momentum = match physprop.offset_limit_mode {
+                    OffsetLimitMode::Discard => S::ZERO,
+                    // What would have carried it past the limit this step comes back as momentum towards rest
+                    OffsetLimitMode::Bleed => (offset - overshoot) / dt,
+                };
+            }
+        }
+        Spring { momentum, offset }
+    }
+    /// Apply the life envelope, returning what should be shown.
+    fn enveloped(&mut self, envelope: S, mode: EnvelopeMode) -> Spring<S> {
+        match mode {
+            EnvelopeMode::Visual => Spring {
+                momentum: self.momentum * envelope,
+                offset: self.offset * envelope,
+            },
+            EnvelopeMode::Legacy => {
+                self.offset *= envelope;
+                self.momentum *= envelope;
+                Spring { ..*self }
+            }
+        }
+    }
+    fn at_rest(&self, physprop: &PhysicsProperties<S>) -> bool {
+        self.offset.abs() < physprop.jiggle_offset_epsilon
+            && self.momentum.abs() < physprop.jiggle_momentum_epsilon
+    }
+}
+
+fn step_jiggling<S: Scalar>(
+    vertical: Spring<S>,
+    horizontal: Spring<S>,
+    life: S,
+    jiggle_offset: &mut S,
+    physprop: &PhysicsProperties<S>,
+    ctx: &StepContext<S>,
+) -> SlimePropsOut<S> {
+    let dt = ctx.dt;
+    let y_bottom = *jiggle_offset;
+    let stiff = match physprop.jiggle_stiff_stretch {
+        Some(stretch) if vertical.offset < S::ZERO => stretch,
+        _ => physprop.jiggle_stiff,
+    };
+    let mut vertical = vertical.step(stiff, physprop, dt);
+    // Leaning either way is the same shape, so the stretch stiffness doesn't come into it
+    let mut horizontal = horizontal.step(physprop.jiggle_stiff, physprop, dt);
+    let envelope = if life < physprop.jiggle_life_threshold {
+        physprop
+            .life_curve
+            .envelope(life, physprop.jiggle_life_threshold_inverse)
+    } else {
+        S::ONE
+    };
+    let shown = vertical.enveloped(envelope, physprop.envelope_mode);
+    let shown_x = horizontal.enveloped(envelope, physprop.envelope_mode);
+    if (life <= S::ZERO) || (shown.at_rest(physprop) && shown_x.at_rest(physprop)) {
+        *jiggle_offset += physprop.cell_height;
+        SlimePropsOut {
+            state: SlimeState::Settled,
+            y_bottom,
+            y_scale: S::ONE,
+            x_scale: S::ONE,
+            x_offset: S::ZERO,
+        }
+    } else {
+        let life = physprop
+            .life_curve
+            .advance(life, physprop.jiggle_life_decrease_rate, dt);
+        let y_scale = (S::ONE - shown.offset).max(physprop.min_y_scale);
+        let x_scale = physprop
+            .squash_mode
+            .preserve_area(y_scale)
+            .unwrap_or(y_scale.max(S::from_f64(0.5)).recip());
+        *jiggle_offset += y_scale * physprop.cell_height;
+        SlimePropsOut {
+            state: SlimeState::Jiggling {
+                momentum: vertical.momentum,
+                offset: vertical.offset,
+                life,
+                momentum_x: horizontal.momentum,
+                offset_x: horizontal.offset,
+            },
+            // Stretched out along the way it's leaning
+            x_scale: x_scale * (S::ONE + shown_x.offset.abs()),
+            y_scale,
+            y_bottom,
+            x_offset: shown_x.offset,
+        }
+    }
+}
+
+fn step_launched<S: Scalar>(
+    velocity: S,
+    height: S,
+    jiggle_offset: &mut S,
+    mass: S,
+    physprop: &PhysicsProperties<S>,
+    ctx: &StepContext<S>,
+    outcome: &mut StepOutcome<S>,
+) -> SlimePropsOut<S> {
+    let dt = ctx.dt;
+    // The slot stays reserved while the slime is in the air, so nothing above drops into it
+    let slot = *jiggle_offset;
+    *jiggle_offset += physprop.cell_height;
+    let velocity = (velocity - dt * physprop.gravity).max(-physprop.terminal_velocity);
+    let height = height + velocity * dt;
+    if height <= S::ZERO && velocity < S::ZERO {
+        let speed = -velocity;
+        // Nothing keeps track of the top of the arc, but the speed it lands at says how far it fell from it
+        let fallen = if physprop.gravity > S::ZERO {
+            speed * speed / (S::from_f64(2.0) * physprop.gravity)
+        } else {
+            S::ZERO
+        };
+        let impact = physprop
+            .impact_mode
+            .impact(physprop.velocity_to_impact * speed, fallen);
+        outcome.landing = Some((impact * mass).max(S::ZERO));
+        SlimePropsOut {
+            state: SlimeState::landed(),
+            y_bottom: slot,
+            y_scale: S::ONE,
+            x_scale: S::ONE,
+            x_offset: S::ZERO,
+        }
+    } else {
+        let y_scale = physprop.fall_stretch.y_scale(velocity.abs());
+        let x_scale = physprop
+            .squash_mode
+            .preserve_area(y_scale)
+            .unwrap_or(y_scale.recip());
+        SlimePropsOut {
+            state: SlimeState::Launched { velocity, height },
+            y_bottom: slot + height * physprop.cell_height,
+            y_scale,
+            x_scale,
+            x_offset: S::ZERO,
+        }
+    }
+}
+
+fn step_squished<S: Scalar>(
+    amount: S,
+    jiggle_offset: &mut S,
+    physprop: &PhysicsProperties<S>,
+) -> SlimePropsOut<S> {
+    let y_bottom = *jiggle_offset;
+    let y_scale = (S::ONE - amount).max(physprop.min_y_scale);
+    let x_scale = physprop
+        .squash_mode
+        .preserve_area(y_scale)
+        .unwrap_or(y_scale.max(S::from_f64(0.5)).recip());
+    *jiggle_offset += y_scale * physprop.cell_height;
+    SlimePropsOut {
+        state: SlimeState::Squished { amount },
+        y_bottom,
+        y_scale,
+        x_scale,
+        x_offset: S::ZERO,
+    }
+}
+
+fn step_spawning<S: Scalar>(
+    progress: S,
+    y_bottom: S,
+    jiggle_offset: &mut S,
+    physprop: &PhysicsProperties<S>,
+    ctx: &StepContext<S>,
+) -> SlimePropsOut<S> {
+    // Spawned up in the air it stays put while it grows, otherwise it sits on the stack
+    let floor = *jiggle_offset;
+    let y_bottom = y_bottom.max(floor);
+    let progress = (progress + ctx.dt / physprop.spawn_duration).min(S::ONE);
+    *jiggle_offset = y_bottom + progress * physprop.cell_height;
+    let state = if progress < S::ONE {
+        SlimeState::Spawning { progress }
+    } else if y_bottom - floor > physprop.cell_height * S::from_f64(SUPPORT_EPSILON) {
+        SlimeState::Falling {
+            velocity: S::ZERO,
+            fallen: S::ZERO,
+        }
+    } else {
+        SlimeState::Settled
+    };
+    SlimePropsOut {
+        state,
+        y_bottom,
+        y_scale: progress,
+        x_scale: progress,
+        x_offset: S::ZERO,
+    }
+}
+
+fn step_popping<S: Scalar>(
+    progress: S,
+    jiggle_offset: &mut S,
+    physprop: &PhysicsProperties<S>,
+    ctx: &StepContext<S>,
+    outcome: &mut StepOutcome<S>,
+) -> SlimePropsOut<S> {
+    let y_bottom = *jiggle_offset;
+    if progress < S::ONE {
+        let progress = (progress + ctx.dt / physprop.pop_duration).min(S::ONE);
+        outcome.active = true;
+        outcome.popped = progress >= S::ONE;
+        *jiggle_offset += (S::ONE - progress) * physprop.cell_height;
+        SlimePropsOut {
+            state: SlimeState::Popping { progress },
+            y_bottom,
+            y_scale: S::ONE - progress,
+            x_scale: S::ONE - progress,
+            x_offset: S::ZERO,
+        }
+    } else {
+        // Finished and already reported, just waiting for the board to take it away
+        SlimePropsOut {
+            state: SlimeState::Popping { progress },
+            y_bottom,
+            y_scale: S::ZERO,
+            x_scale: S::ZERO,
+            x_offset: S::ZERO,
+        }
+    }
+}
+
+fn step_sliding<S: Scalar>(
+    x_offset: S,
+    x_velocity: S,
+    returning: bool,
+    jiggle_offset: &mut S,
+    physprop: &PhysicsProperties<S>,
+    ctx: &StepContext<S>,
+    outcome: &mut StepOutcome<S>,
+) -> SlimePropsOut<S> {
+    let y_bottom = *jiggle_offset;
+    *jiggle_offset += physprop.cell_height;
+    let moved = x_offset + x_velocity * ctx.dt;
+    let (state, x_offset) = if returning && moved * x_offset <= S::ZERO {
+        // Made it back home after bouncing off a blocked column
+        (
+            SlimeState::Jiggling {
+                momentum: S::ZERO,
+                offset: S::ZERO,
+                life: S::ONE,
+                // Still heading the way it bounced back
+                momentum_x: physprop.velocity_to_impact * x_velocity,
+                offset_x: S::ZERO,
+            },
+            S::ZERO,
+        )
+    } else if !returning && moved.abs() >= S::ONE {
+        // The board gets asked about moving it once the column pass is done
+        outcome.slid_across = Some(x_velocity);
+        (
+            SlimeState::Sliding {
+                x_offset: moved.clamp(-S::ONE, S::ONE),
+                x_velocity,
+                returning,
+            },
+            moved.clamp(-S::ONE, S::ONE),
+        )
+    } else {
+        (
+            SlimeState::Sliding {
+                x_offset: moved,
+                x_velocity,
+                returning,
+            },
+            moved,
+        )
+    };
+    SlimePropsOut {
+        state,
+        y_bottom,
+        y_scale: S::ONE,
+        x_scale: S::ONE,
+        x_offset,
+    }
+}
+
+fn step_swapping<S: Scalar>(
+    progress: S,
+    (from_x, from_y): (S, S),
+    buffered_impulse: S,
+    jiggle_offset: &mut S,
+    physprop: &PhysicsProperties<S>,
+    ctx: &StepContext<S>,
+) -> SlimePropsOut<S> {
+    // Drawn on its way over, but the stack above only ever sees it sitting in its new slot
+    let slot = *jiggle_offset;
+    *jiggle_offset += physprop.cell_height;
+    let progress = (progress + ctx.dt / physprop.swap_duration).min(S::ONE);
+    if progress < S::ONE {
+        let left = S::ONE - smooth_step(progress);
+        SlimePropsOut {
+            state: SlimeState::Swapping {
+                progress,
+                from_x,
+                from_y,
+                buffered_impulse,
+            },
+            y_bottom: slot + from_y * left * physprop.cell_height,
+            y_scale: S::ONE,
+            x_scale: S::ONE,
+            x_offset: from_x * left,
+        }
+    } else {
+        SlimePropsOut {
+            state: SlimeState::Jiggling {
+                momentum: (physprop.swap_momentum + buffered_impulse)
+                    .clamp(-physprop.max_momentum, physprop.max_momentum),
+                offset: S::ZERO,
+                life: S::ONE,
+                momentum_x: S::ZERO,
+                offset_x: S::ZERO,
+            },
+            y_bottom: slot,
+            y_scale: S::ONE,
+            x_scale: S::ONE,
+            x_offset: S::ZERO,
+        }
+    }
+}
+
+fn step_merging<S: Scalar>(
+    progress: S,
+    (into_x, into_y): (S, S),
+    jiggle_offset: &mut S,
+    physprop: &PhysicsProperties<S>,
+    ctx: &StepContext<S>,
+    outcome: &mut StepOutcome<S>,
+) -> SlimePropsOut<S> {
+    let y_bottom = *jiggle_offset;
+    let progress = if progress < S::ONE {
+        let progress = (progress + ctx.dt / physprop.merge_duration).min(S::ONE);
+        outcome.active = true;
+        outcome.merged = progress >= S::ONE;
+        progress
+    } else {
+        // Finished and already reported, just waiting for the board to take it away
+        progress
+    };
+    *jiggle_offset += (S::ONE - progress) * physprop.cell_height;
+    // Drawn drifting halfway over as it shrinks, so it looks pulled in
+    let drift = progress * S::from_f64(0.5);
+    SlimePropsOut {
+        state: SlimeState::Merging {
+            progress,
+            into_x,
+            into_y,
+        },
+        y_bottom: y_bottom + into_y * drift * physprop.cell_height,
+        y_scale: S::ONE - progress,
+        x_scale: S::ONE - progress,
+        x_offset: into_x * drift,
+    }
+}
@@ -0,0 +1,87 @@
+//! Running physics at a fixed rate whatever the frame rate.
+
+use crate::{JigglyBoard, PhysicsProperties, Scalar};
+
+/// How many fixed steps [`FixedStepper::new`] lets one `advance` catch up on.
+const DEFAULT_MAX_STEPS: u32 = 8;
+
+/// Turns real frame times into whole steps of `dt`, carrying what's left over to the next frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedStepper<S = f32> {
+    /// The fixed step, in seconds. Must be positive.
+    pub dt: S,
+    /// The most steps one `advance` will run. Time beyond that is dropped, so a slow frame slows the
+    /// physics down rather than making the next frame even slower.
+    pub max_steps: u32,
+    accumulator: S,
+    settled: bool,
+}
+
+/// What a [`FixedStepper::advance`] did.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StepReport<S = f32> {
+    /// How many steps of `dt` ran.
+    pub steps: u32,
+    pub settled: bool,
+    /// How far towards the next step the leftover time is, from 0 to 1, for interpolating rendering.
+    pub alpha: S,
+}
+
+impl<S: Scalar> FixedStepper<S> {
+    pub fn new(dt: S) -> Self {
+        FixedStepper {
+            dt,
+            max_steps: DEFAULT_MAX_STEPS,
+            accumulator: S::ZERO,
+            settled: false,
+        }
+    }
+    pub fn with_max_steps(mut self, max_steps: u32) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+    /// Add `real_dt` seconds and run as many whole steps as are due.
+    ///
+    /// Once the board settles, the rest of the steps due this frame are skipped, as they'd change
+    /// nothing. The next `advance` still steps it, in case the game has changed something since. A frame
+    /// too short for a whole step runs none and reports whether it was settled last time.
+    pub fn advance<B: JigglyBoard<S> + ?Sized>(
+        &mut self,
+        real_dt: S,
+        board: &mut B,
+        physprop: &PhysicsProperties<S>,
+    ) -> StepReport<S> {
+        if real_dt.is_finite() && real_dt > S::ZERO {
+            self.accumulator += real_dt;
+        }
+        let mut steps = 0;
+        if self.dt > S::ZERO {
+            while self.accumulator >= self.dt {
+                if steps == self.max_steps {
+                    self.accumulator %= self.dt;
+                    break;
+                }
+                self.accumulator -= self.dt;
+                steps += 1;
+                self.settled = board.run_physics(self.dt, physprop);
+                if self.settled {
+                    self.accumulator %= self.dt;
+                    break;
+                }
+            }
+        }
+        StepReport {
+            steps,
+            settled: self.settled,
+            alpha: self.alpha(),
+        }
+    }
+    /// How far towards the next step the leftover time is, from 0 to 1.
+    pub fn alpha(&self) -> S {
+        if self.dt > S::ZERO {
+            (self.accumulator / self.dt).clamp(S::ZERO, S::ONE)
+        } else {
+            S::ZERO
+        }
+    }
+}
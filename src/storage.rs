@@ -0,0 +1,232 @@
+//! Where a [`PhysicsScratch`](crate::PhysicsScratch) keeps the buffers it steps in.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::small::SmallDeque;
+#[cfg(feature = "heapless")]
+use crate::small::{ArrayDeque, ArrayVec};
+
+#[cfg(not(any(feature = "alloc", feature = "heapless")))]
+compile_error!("jiggly_fever needs the `alloc` or the `heapless` feature to step boards");
+
+/// Where a [`PhysicsScratch`](crate::PhysicsScratch) keeps its lists of slimes and columns and its
+/// queues of jiggle propagations: `HeapStorage`, or `ArrayStorage` for stepping without a heap.
+pub trait ScratchStorage: sealed::Sealed {
+    #[doc(hidden)]
+    type List<T>: sealed::List<T>;
+    #[doc(hidden)]
+    type Queue<T>: sealed::Queue<T>;
+}
+
+/// Buffers on the heap, which grow to fit the board and keep their capacity between steps.
+#[cfg(feature = "alloc")]
+pub struct HeapStorage;
+
+#[cfg(feature = "alloc")]
+impl ScratchStorage for HeapStorage {
+    type List<T> = Vec<T>;
+    // In the usual step only a slime or two lands, so those never need the heap
+    type Queue<T> = SmallDeque<T, 16>;
+}
+
+/// Buffers that are arrays of `N` entries, for stepping without a heap.
+///
+/// A step only takes on the columns that fit in `N` slimes between them, leaving the rest unstepped and
+/// the board unsettled, and queues at most `N` jiggle propagations, dropping the rest as
+/// [`PhysicsScratch::with_capacity`](crate::PhysicsScratch::with_capacity)'s overflow policy says.
+#[cfg(feature = "heapless")]
+pub struct ArrayStorage<const N: usize>;
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> ScratchStorage for ArrayStorage<N> {
+    type List<T> = ArrayVec<T, N>;
+    type Queue<T> = ArrayDeque<T, N>;
+}
+
+/// How many slimes [`JigglyBoard::run_physics`](crate::JigglyBoard::run_physics) has room for without
+/// the `alloc` feature.
+#[cfg(all(feature = "heapless", not(feature = "alloc")))]
+pub const DEFAULT_ARRAY_CAPACITY: usize = 128;
+
+/// What a [`PhysicsScratch`](crate::PhysicsScratch) uses unless told otherwise: [`HeapStorage`], or
+/// without the `alloc` feature, an `ArrayStorage` of `DEFAULT_ARRAY_CAPACITY`.
+#[cfg(feature = "alloc")]
+pub type DefaultStorage = HeapStorage;
+/// What a [`PhysicsScratch`](crate::PhysicsScratch) uses unless told otherwise: `HeapStorage`, or
+/// without the `alloc` feature, an [`ArrayStorage`] of [`DEFAULT_ARRAY_CAPACITY`].
+#[cfg(all(feature = "heapless", not(feature = "alloc")))]
+pub type DefaultStorage = ArrayStorage<DEFAULT_ARRAY_CAPACITY>;
+
+pub(crate) mod sealed {
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+
+    #[cfg(feature = "alloc")]
+    use crate::small::SmallDeque;
+    #[cfg(feature = "heapless")]
+    use crate::small::{ArrayDeque, ArrayVec};
+
+    pub trait Sealed {}
+
+    #[cfg(feature = "alloc")]
+    impl Sealed for super::HeapStorage {}
+    #[cfg(feature = "heapless")]
+    impl<const N: usize> Sealed for super::ArrayStorage<N> {}
+
+    /// A list that hands back what it has no room for.
+    pub trait List<T>: Default {
+        fn with_capacity(capacity: usize) -> Self;
+        fn len(&self) -> usize;
+        fn get(&self, index: usize) -> Option<&T>;
+        fn push(&mut self, value: T) -> Result<(), T>;
+        /// Drop everything from the `len`th entry on.
+        fn truncate(&mut self, len: usize);
+        /// Reverse the order of everything from the `from`th entry on.
+        fn reverse_from(&mut self, from: usize);
+        /// Take every entry out, first first, as they're iterated over.
+        fn drain(&mut self) -> impl Iterator<Item = T> + '_;
+        fn clear(&mut self) {
+            self.truncate(0);
+        }
+    }
+
+    /// A double-ended queue that hands back what it has no room for.
+    pub trait Queue<T>: Default {
+        /// The most it can hold.
+        const CAPACITY: usize;
+        fn with_capacity(capacity: usize) -> Self;
+        fn len(&self) -> usize;
+        fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+        fn get(&self, index: usize) -> Option<&T>;
+        fn front(&self) -> Option<&T> {
+            self.get(0)
+        }
+        fn push_back(&mut self, value: T) -> Result<(), T>;
+        fn pop_front(&mut self) -> Option<T>;
+        /// Take out the `index`th entry, moving everything after it along.
+        fn remove(&mut self, index: usize) -> Option<T>;
+        fn clear(&mut self);
+        fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+        where
+            T: 'a,
+        {
+            (0..self.len()).filter_map(|index| self.get(index))
+        }
+        /// Take up to `count` entries off the front, as they're iterated over.
+        fn drain_front(&mut self, count: usize) -> impl Iterator<Item = T> + '_ {
+            (0..count).map_while(|_| self.pop_front())
+        }
+        /// Take every entry off the front, as they're iterated over.
+        fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+            core::iter::from_fn(|| self.pop_front())
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<T> List<T> for Vec<T> {
+        fn with_capacity(capacity: usize) -> Self {
+            Vec::with_capacity(capacity)
+        }
+        fn len(&self) -> usize {
+            Vec::len(self)
+        }
+        fn get(&self, index: usize) -> Option<&T> {
+            <[T]>::get(self, index)
+        }
+        fn push(&mut self, value: T) -> Result<(), T> {
+            Vec::push(self, value);
+            Ok(())
+        }
+        fn truncate(&mut self, len: usize) {
+            Vec::truncate(self, len);
+        }
+        fn reverse_from(&mut self, from: usize) {
+            self[from..].reverse();
+        }
+        fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+            Vec::drain(self, ..)
+        }
+    }
+
+    #[cfg(feature = "heapless")]
+    impl<T, const N: usize> List<T> for ArrayVec<T, N> {
+        fn with_capacity(_: usize) -> Self {
+            ArrayVec::new()
+        }
+        fn len(&self) -> usize {
+            ArrayVec::len(self)
+        }
+        fn get(&self, index: usize) -> Option<&T> {
+            ArrayVec::get(self, index)
+        }
+        fn push(&mut self, value: T) -> Result<(), T> {
+            ArrayVec::push(self, value)
+        }
+        fn truncate(&mut self, len: usize) {
+            ArrayVec::truncate(self, len);
+        }
+        fn reverse_from(&mut self, from: usize) {
+            ArrayVec::reverse_from(self, from);
+        }
+        fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+            ArrayVec::drain(self)
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<T, const N: usize> Queue<T> for SmallDeque<T, N> {
+        const CAPACITY: usize = usize::MAX;
+        fn with_capacity(capacity: usize) -> Self {
+            SmallDeque::with_capacity(capacity)
+        }
+        fn len(&self) -> usize {
+            SmallDeque::len(self)
+        }
+        fn get(&self, index: usize) -> Option<&T> {
+            SmallDeque::get(self, index)
+        }
+        fn push_back(&mut self, value: T) -> Result<(), T> {
+            SmallDeque::push_back(self, value);
+            Ok(())
+        }
+        fn pop_front(&mut self) -> Option<T> {
+            SmallDeque::pop_front(self)
+        }
+        fn remove(&mut self, index: usize) -> Option<T> {
+            SmallDeque::remove(self, index)
+        }
+        fn clear(&mut self) {
+            SmallDeque::clear(self);
+        }
+    }
+
+    #[cfg(feature = "heapless")]
+    impl<T, const N: usize> Queue<T> for ArrayDeque<T, N> {
+        const CAPACITY: usize = N;
+        fn with_capacity(_: usize) -> Self {
+            ArrayDeque::new()
+        }
+        fn len(&self) -> usize {
+            ArrayDeque::len(self)
+        }
+        fn get(&self, index: usize) -> Option<&T> {
+            ArrayDeque::get(self, index)
+        }
+        fn push_back(&mut self, value: T) -> Result<(), T> {
+            ArrayDeque::push_back(self, value)
+        }
+        fn pop_front(&mut self) -> Option<T> {
+            ArrayDeque::pop_front(self)
+        }
+        fn remove(&mut self, index: usize) -> Option<T> {
+            ArrayDeque::remove(self, index)
+        }
+        fn clear(&mut self) {
+            ArrayDeque::clear(self);
+        }
+    }
+}
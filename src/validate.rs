@@ -0,0 +1,234 @@
+use crate::{
+    BounceMode, FallStretch, ImpactMode, LifeRefresh, PhysicsProperties, PhysicsPropertiesError,
+    Scalar, SquashMode,
+};
+
+/// How far `jiggle_life_threshold_inverse` may drift from `1.0 / jiggle_life_threshold`, relatively.
+const INVERSE_TOLERANCE: f64 = 1e-4;
+
+impl<S: Scalar> PhysicsProperties<S> {
+    /// Check that every field is finite and within the range the simulation expects.
+    ///
+    /// `run_physics` asserts it every step in debug builds, and doesn't check in release builds.
+    /// [`JigglyBoard::try_run_physics`](crate::JigglyBoard::try_run_physics) returns why instead, in any
+    /// build, and checks each slime's [`props_for`](crate::JigglyBoard::props_for) too.
+    pub fn validate(&self) -> Result<(), PhysicsPropertiesError> {
+        finite_non_negative("gravity", self.gravity)?;
+        finite_non_negative("velocity_to_impact", self.velocity_to_impact)?;
+        finite_non_negative("min_impactable", self.min_impactable)?;
+        finite("jiggle_stiff", self.jiggle_stiff)?;
+        positive("jiggle_stiff", self.jiggle_stiff)?;
+        if let Some(stretch) = self.jiggle_stiff_stretch {
+            finite("jiggle_stiff_stretch", stretch)?;
+            positive("jiggle_stiff_stretch", stretch)?;
+        }
+        unit_interval("jiggle_damp", self.jiggle_damp)?;
+        finite_non_negative("jiggle_life_decrease_rate", self.jiggle_life_decrease_rate)?;
+        finite("jiggle_life_threshold", self.jiggle_life_threshold)?;
+        positive("jiggle_life_threshold", self.jiggle_life_threshold)?;
+        finite(
+            "jiggle_life_threshold_inverse",
+            self.jiggle_life_threshold_inverse,
+        )?;
+        let expected = self.jiggle_life_threshold.recip();
+        if (self.jiggle_life_threshold_inverse - expected).abs()
+            > expected * S::from_f64(INVERSE_TOLERANCE)
+        {
+            return Err(PhysicsPropertiesError::InverseMismatch {
+                expected: report(expected),
+                actual: report(self.jiggle_life_threshold_inverse),
+            });
+        }
+        finite_non_negative("jiggle_offset_epsilon", self.jiggle_offset_epsilon)?;
+        finite_non_negative("jiggle_momentum_epsilon", self.jiggle_momentum_epsilon)?;
+        // Infinity is the "no limit" value here
+        positive("terminal_velocity", self.terminal_velocity)?;
+        finite_non_negative("transfer_horizontal", self.transfer_horizontal)?;
+        finite_non_negative("transfer_up", self.transfer_up)?;
+        finite_non_negative("transfer_down", self.transfer_down)?;
+        positive("max_offset", self.max_offset)?;
+        finite_non_negative("min_y_scale", self.min_y_scale)?;
+        if let SquashMode::PreserveArea {
+            min_x_scale,
+            max_x_scale,
+        } = self.squash_mode
+        {
+            finite("min_x_scale", min_x_scale)?;
+            positive("min_x_scale", min_x_scale)?;
+            finite("max_x_scale", max_x_scale)?;
+            if max_x_scale < min_x_scale {
+                return Err(PhysicsPropertiesError::OutOfRange {
+                    field: "max_x_scale",
+                    value: report(max_x_scale),
+                    min: report(min_x_scale),
+                    max: f32::INFINITY,
+                });
+            }
+        }
+        positive("max_dt", self.max_dt)?;
+        positive("max_momentum", self.max_momentum)?;
+        finite("cell_height", self.cell_height)?;
+        positive("cell_height", self.cell_height)?;
+        finite_non_negative("idle_wobble_amplitude", self.idle_wobble_amplitude)?;
+        finite_non_negative("idle_wobble_frequency", self.idle_wobble_frequency)?;
+        finite_non_negative("restitution", self.restitution)?;
+        if self.restitution >= S::ONE {
+            // A perfectly elastic bounce would never come to rest
+            return Err(PhysicsPropertiesError::OutOfRange {
+                field: "restitution",
+                value: report(self.restitution),
+                min: 0.0,
+                max: 1.0,
+            });
+        }
+        finite_non_negative("bounce_min_velocity", self.bounce_min_velocity)?;
+        finite_non_negative("fall_delay", self.fall_delay)?;
+        if let ImpactMode::Distance { per_cell } | ImpactMode::Combined { per_cell } =
+            self.impact_mode
+        {
+            finite_non_negative("impact_mode.per_cell", per_cell)?;
+        }
+        finite_non_negative("detach_stretch", self.detach_stretch)?;
+        if let BounceMode::Count { factor, .. } = self.bounce_mode {
+            // Even a perfectly elastic hop runs out, since the count does
+            unit_interval("bounce_mode.factor", factor)?;
+        }
+        finite_non_negative("propagate_up_factor", self.propagate_up_factor)?;
+        finite_non_negative("edge_reflectance", self.edge_reflectance)?;
+        if self.edge_reflectance >= S::ONE {
+            // A wave in a strip without falloff would bounce between its ends forever
+            return Err(PhysicsPropertiesError::OutOfRange {
+                field: "edge_reflectance",
+                value: report(self.edge_reflectance),
+                min: 0.0,
+                max: 1.0,
+            });
+        }
+        finite_non_negative("cutoff_growth", self.cutoff_growth)?;
+        finite_non_negative("squish_release_momentum", self.squish_release_momentum)?;
+        finite("spawn_duration", self.spawn_duration)?;
+        positive("spawn_duration", self.spawn_duration)?;
+        finite("pop_duration", self.pop_duration)?;
+        positive("pop_duration", self.pop_duration)?;
+        finite_non_negative("slide_bounce", self.slide_bounce)?;
+        positive("swap_duration", self.swap_duration)?;
+        finite("swap_momentum", self.swap_momentum)?;
+        positive("merge_duration", self.merge_duration)?;
+        finite_non_negative("merge_impulse", self.merge_impulse)?;
+        match self.fall_stretch {
+            FallStretch::Linear { per_velocity, max } => {
+                finite_non_negative("fall_stretch.per_velocity", per_velocity)?;
+                at_least_one("fall_stretch.max", max)?;
+            }
+            FallStretch::SmoothStep { full_velocity, max } => {
+                finite("fall_stretch.full_velocity", full_velocity)?;
+                positive("fall_stretch.full_velocity", full_velocity)?;
+                at_least_one("fall_stretch.max", max)?;
+            }
+        }
+        match self.life_refresh {
+            LifeRefresh::Reset => {}
+            LifeRefresh::Max { per_impulse } => {
+                finite_non_negative("life_refresh.per_impulse", per_impulse)?;
+            }
+            LifeRefresh::Add { per_impulse, cap } => {
+                finite_non_negative("life_refresh.per_impulse", per_impulse)?;
+                finite("life_refresh.cap", cap)?;
+                positive("life_refresh.cap", cap)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// The checks are written so that NaN always falls into the error branch
+
+pub(crate) fn finite<S: Scalar>(
+    field: &'static str,
+    value: S,
+) -> Result<(), PhysicsPropertiesError> {
+    if value.is_finite() {
+        Ok(())
+    } else {
+        Err(PhysicsPropertiesError::NotFinite {
+            field,
+            value: report(value),
+        })
+    }
+}
+
+pub(crate) fn positive<S: Scalar>(
+    field: &'static str,
+    value: S,
+) -> Result<(), PhysicsPropertiesError> {
+    if value > S::ZERO {
+        Ok(())
+    } else {
+        Err(PhysicsPropertiesError::NotPositive {
+            field,
+            value: report(value),
+        })
+    }
+}
+
+pub(crate) fn non_negative<S: Scalar>(
+    field: &'static str,
+    value: S,
+) -> Result<(), PhysicsPropertiesError> {
+    if value >= S::ZERO {
+        Ok(())
+    } else {
+        Err(PhysicsPropertiesError::Negative {
+            field,
+            value: report(value),
+        })
+    }
+}
+
+pub(crate) fn finite_non_negative<S: Scalar>(
+    field: &'static str,
+    value: S,
+) -> Result<(), PhysicsPropertiesError> {
+    finite(field, value)?;
+    non_negative(field, value)
+}
+
+/// Finite and `>= 1.0`
+pub(crate) fn at_least_one<S: Scalar>(
+    field: &'static str,
+    value: S,
+) -> Result<(), PhysicsPropertiesError> {
+    finite(field, value)?;
+    if value >= S::ONE {
+        Ok(())
+    } else {
+        Err(PhysicsPropertiesError::OutOfRange {
+            field,
+            value: report(value),
+            min: 1.0,
+            max: f32::INFINITY,
+        })
+    }
+}
+
+/// `(0, 1]`
+pub(crate) fn unit_interval<S: Scalar>(
+    field: &'static str,
+    value: S,
+) -> Result<(), PhysicsPropertiesError> {
+    if value > S::ZERO && value <= S::ONE {
+        Ok(())
+    } else {
+        Err(PhysicsPropertiesError::OutOfRange {
+            field,
+            value: report(value),
+            min: 0.0,
+            max: 1.0,
+        })
+    }
+}
+
+/// Errors report values as `f32` whatever the simulation runs in.
+fn report<S: Scalar>(value: S) -> f32 {
+    value.to_f64() as f32
+}
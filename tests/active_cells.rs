@@ -0,0 +1,43 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+fn sorted(mut cells: Vec<((usize, usize), ActiveKind)>) -> Vec<((usize, usize), ActiveKind)> {
+    cells.sort_by_key(|(location, _)| *location);
+    cells
+}
+
+#[test]
+fn tracked_cells_match_a_full_scan() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(4, 6);
+    for x in 0..4 {
+        for y in 0..3 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board.set(1, 3, SlimeState::falling(&physprop));
+    board.cell_mut(1, 3).unwrap().y_bottom = 5.0;
+    let mut scratch = PhysicsScratch::new().with_active_tracking();
+    let (mut fell, mut jiggled, mut settled) = (false, false, false);
+    for _ in 0..3600 {
+        settled = board.run_physics_with_scratch(1.0 / 60.0, &physprop, &mut scratch, &mut ());
+        let tracked = sorted(scratch.active_cells().collect());
+        assert_eq!(sorted(board.active_cells().collect()), tracked);
+        fell |= tracked.iter().any(|(_, kind)| *kind == ActiveKind::Falling);
+        jiggled |= tracked
+            .iter()
+            .any(|(_, kind)| *kind == ActiveKind::Jiggling);
+        if settled {
+            break;
+        }
+    }
+    assert!(settled && fell && jiggled);
+    assert_eq!(scratch.active_cells().count(), 0);
+}
+
+#[test]
+fn untracked_scratch_has_nothing_active() {
+    let scratch = PhysicsScratch::<(usize, usize), Cardinal4>::new();
+    assert_eq!(scratch.active_cells().count(), 0);
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// Three columns, three slimes deep, all settled.
+fn board() -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(3, 6);
+    for x in 0..3 {
+        for y in 0..3 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board
+}
+
+#[test]
+fn settled_boards_are_calm() {
+    let agitation = board().agitation(&AgitationWeights::default());
+    assert_eq!(agitation.total, 0.0);
+    assert!(agitation.peak.is_none());
+}
+
+#[test]
+fn agitation_rises_and_falls_with_a_drop() {
+    let physprop = PhysicsProperties::default();
+    let weights = AgitationWeights::default();
+    let mut board = board();
+    board.set(1, 3, SlimeState::falling(&physprop));
+    board.cell_mut(1, 3).unwrap().y_bottom = 5.0;
+    let mut totals = Vec::new();
+    for _ in 0..3600 {
+        let settled = board.run_physics(1.0 / 60.0, &physprop);
+        let agitation = board.agitation(&weights);
+        assert_eq!(agitation.peak.is_some(), agitation.total > 0.0);
+        if let Some((_, peak)) = agitation.peak {
+            assert!(peak <= agitation.total);
+        }
+        totals.push(agitation.total);
+        if settled {
+            break;
+        }
+    }
+    assert_eq!(totals.last(), Some(&0.0));
+    let most = totals.iter().copied().fold(0.0, f32::max);
+    assert!(most > totals[0]);
+}
+
+#[test]
+fn weights_pick_what_counts() {
+    let mut board = board();
+    board.set(
+        1,
+        2,
+        SlimeState::Jiggling {
+            momentum: 2.0,
+            offset: 0.5,
+            life: 1.0,
+            momentum_x: 0.0,
+            offset_x: 0.0,
+        },
+    );
+    let only_momentum = AgitationWeights {
+        momentum: 1.0,
+        offset: 0.0,
+        falling: 0.0,
+    };
+    let agitation = board.agitation(&only_momentum);
+    assert_eq!(agitation.total, 2.0);
+    assert_eq!(agitation.peak, Some(((1, 2), 2.0)));
+}
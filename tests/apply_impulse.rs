@@ -0,0 +1,54 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+fn filled(width: usize, height: usize, depth: usize) -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(width, height);
+    for x in 0..width {
+        for y in 0..depth {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board
+}
+
+#[test]
+fn poking_matches_a_landing() {
+    for wave_mode in [WaveMode::Separate, WaveMode::Coalesced] {
+        let physprop = PhysicsProperties {
+            propagate_up_factor: 0.4,
+            wave_mode,
+            ..PhysicsProperties::default()
+        };
+        let mut board = filled(5, 6, 4);
+        board.launch((2, 1), 4.0);
+        let mut landings = Vec::new();
+        for _ in 0..200 {
+            let column: Vec<_> = board.cols().nth(2).unwrap().collect();
+            board.step_column(2, column.into_iter(), 1.0 / 60.0, &physprop, &mut landings);
+            if !landings.is_empty() {
+                break;
+            }
+        }
+        assert_eq!(landings.len(), 2, "{wave_mode:?}");
+        assert_eq!(landings[0].at, (2, 1));
+        let impulse = landings[0].impulse;
+        assert!(impulse > 0.0);
+        let before = board.snapshot();
+        board.apply_propagations(landings, &physprop);
+        let landed = board.snapshot();
+        board.restore(&before);
+        board.apply_impulse_at((2, 1), impulse, Cardinal4::Up, &physprop);
+        assert_eq!(board.snapshot(), landed, "{wave_mode:?}");
+        assert!(matches!(board.get(2, 3), Some(SlimeState::Jiggling { .. })));
+        assert!(matches!(board.get(0, 0), Some(SlimeState::Jiggling { .. })));
+    }
+}
+
+#[test]
+fn poking_from_the_side_ripples_across() {
+    let physprop = PhysicsProperties::default();
+    let mut board = filled(5, 3, 3);
+    board.apply_impulse_at((0, 1), 3.0, Cardinal4::Left, &physprop);
+    assert!(matches!(board.get(3, 1), Some(SlimeState::Jiggling { .. })));
+}
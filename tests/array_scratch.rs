@@ -0,0 +1,159 @@
+#![cfg(feature = "heapless")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use jiggly_fever::*;
+
+/// Counts what the test that's watching allocates.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static WATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if WATCHING.with(Cell::get) {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        unsafe { System.alloc(layout) }
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static COUNTING: Counting = Counting;
+
+/// Columns of slimes kept in arrays, so it works without `alloc` too.
+#[derive(Clone)]
+struct Stacks<const W: usize, const H: usize> {
+    cells: [[Option<(SlimeState, f32)>; H]; W],
+}
+
+impl<const W: usize, const H: usize> Stacks<W, H> {
+    /// `depth` settled slimes in every column, and one more dropped from high up on the middle one.
+    fn dropped(depth: usize) -> Self {
+        let mut cells = [[None; H]; W];
+        for column in &mut cells {
+            for (y, cell) in column.iter_mut().enumerate().take(depth) {
+                *cell = Some((SlimeState::Settled, y as f32));
+            }
+        }
+        let physprop = PhysicsProperties::default();
+        cells[W / 2][depth] = Some((SlimeState::falling(&physprop), H as f32));
+        Stacks { cells }
+    }
+}
+
+impl<const W: usize, const H: usize> JigglyBoard for Stacks<W, H> {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        (x, y): (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        let (x, y) = match dir {
+            Cardinal4::Up => (x, y + 1),
+            Cardinal4::Down => (x, y.checked_sub(1)?),
+            Cardinal4::Left => (x.checked_sub(1)?, y),
+            Cardinal4::Right => (x + 1, y),
+        };
+        self.cells.get(x)?.get(y)?.as_ref()?;
+        Some(((x, y), impulse * 0.5))
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.cells.iter().enumerate().map(|(x, column)| {
+            column
+                .iter()
+                .enumerate()
+                .filter(|(_, cell)| cell.is_some())
+                .map(move |(y, _)| (x, y))
+        })
+    }
+    fn mut_slime_with(
+        &mut self,
+        (x, y): (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        if let Some((state, y_bottom)) = &mut self.cells[x][y] {
+            let out = f(SlimePropsIn {
+                state: *state,
+                y_bottom: *y_bottom,
+            });
+            (*state, *y_bottom) = (out.state, out.y_bottom);
+        }
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        (x, y): (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        if let Some((state, _)) = &mut self.cells[x][y] {
+            *state = f(*state);
+        }
+    }
+    fn peek_slime<R>(
+        &self,
+        (x, y): (usize, usize),
+        f: impl FnOnce(&SlimeState, f32) -> R,
+    ) -> Option<R> {
+        let (state, y_bottom) = self.cells.get(x)?.get(y)?.as_ref()?;
+        Some(f(state, *y_bottom))
+    }
+}
+
+#[test]
+fn arrays_step_like_the_heap() {
+    let physprop = PhysicsProperties::default();
+    let mut on_heap = Stacks::<3, 8>::dropped(3);
+    let mut in_arrays = on_heap.clone();
+    let mut heap_scratch = PhysicsScratch::new();
+    let mut array_scratch = PhysicsScratch::<_, _, f32, ArrayStorage<16>>::new_in();
+    let mut settled = false;
+    for _ in 0..3600 {
+        settled =
+            on_heap.run_physics_with_scratch(1.0 / 60.0, &physprop, &mut heap_scratch, &mut ());
+        let also =
+            in_arrays.run_physics_with_scratch(1.0 / 60.0, &physprop, &mut array_scratch, &mut ());
+        assert_eq!(settled, also);
+        assert_eq!(on_heap.cells, in_arrays.cells);
+        assert_eq!(array_scratch.skipped_columns(), 0);
+        if settled {
+            break;
+        }
+    }
+    assert!(settled);
+}
+
+#[test]
+fn columns_that_dont_fit_wait() {
+    let physprop = PhysicsProperties::default();
+    let mut board = Stacks::<3, 8>::dropped(3);
+    let before = board.cells;
+    // Room for the two outer columns, not the middle one's four slimes as well
+    let mut scratch = PhysicsScratch::<_, _, f32, ArrayStorage<6>>::new_in();
+    assert!(!board.run_physics_with_scratch(1.0 / 60.0, &physprop, &mut scratch, &mut ()));
+    assert_eq!(scratch.skipped_columns(), 1);
+    assert_eq!(board.cells, before);
+}
+
+#[test]
+fn arrays_never_touch_the_heap() {
+    let physprop = PhysicsProperties::default();
+    let mut board = Stacks::<3, 8>::dropped(3);
+    let mut scratch = PhysicsScratch::<_, _, f32, ArrayStorage<16>>::new_in();
+    WATCHING.with(|watching| watching.set(true));
+    let settled = (0..3600)
+        .any(|_| board.run_physics_with_scratch(1.0 / 60.0, &physprop, &mut scratch, &mut ()));
+    WATCHING.with(|watching| watching.set(false));
+    assert!(settled);
+    assert_eq!(ALLOCATIONS.load(Ordering::Relaxed), 0);
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+const BAD_DTS: [f32; 4] = [0.0, -0.01, f32::NAN, f32::INFINITY];
+
+/// A stack with a slime landing on it, so that any step at all would change something.
+fn busy() -> DenseGridBoard {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(3, 4);
+    for x in 0..3 {
+        board.set(x, 0, SlimeState::Settled);
+    }
+    board.set(1, 2, SlimeState::falling(&physprop));
+    board.cell_mut(1, 2).unwrap().y_bottom = 1.5;
+    board
+}
+
+/// Check that `step` leaves a busy board alone and says it isn't at rest, and that it says a settled one is.
+fn leaves_alone(step: impl Fn(&mut DenseGridBoard, f32) -> bool) {
+    for dt in BAD_DTS {
+        let mut board = busy();
+        let before = board.snapshot();
+        assert!(!step(&mut board, dt), "dt {dt}");
+        assert_eq!(board.snapshot(), before, "dt {dt}");
+        let mut board = DenseGridBoard::new(2, 2);
+        board.set(0, 0, SlimeState::Settled);
+        assert!(step(&mut board, dt), "dt {dt}");
+    }
+}
+
+#[test]
+fn run_physics_ignores_a_bad_dt() {
+    let physprop = PhysicsProperties::default();
+    leaves_alone(|board, dt| board.run_physics(dt, &physprop));
+}
+
+#[test]
+fn run_physics_par_ignores_a_bad_dt() {
+    let physprop = PhysicsProperties::default();
+    leaves_alone(|board, dt| board.run_physics_par(dt, &physprop));
+}
+
+#[test]
+fn physics_step_ignores_a_bad_dt() {
+    let physprop = PhysicsProperties::default();
+    leaves_alone(|board, dt| board.physics_step(dt, &physprop));
+    leaves_alone(|board, dt| board.physics_step_observed(dt, &physprop, &mut ()));
+}
+
+#[test]
+fn run_physics_partial_starts_nothing_with_a_bad_dt() {
+    let physprop = PhysicsProperties::default();
+    leaves_alone(|board, dt| {
+        let mut budget = StepBudget::new(1, 1);
+        let settled = board.run_physics_partial(dt, &physprop, &mut budget);
+        assert!(!budget.in_progress());
+        settled.unwrap()
+    });
+}
+
+#[test]
+fn step_column_ignores_a_bad_dt() {
+    let physprop = PhysicsProperties::default();
+    leaves_alone(|board, dt| {
+        let mut propagations = Vec::new();
+        let settled = (0..board.width()).all(|x| {
+            board.step_column(x, (0..4).map(|y| (x, y)), dt, &physprop, &mut propagations)
+        });
+        assert!(propagations.is_empty());
+        settled
+    });
+}
+
+#[test]
+fn step_column_slice_ignores_a_bad_dt() {
+    let physprop = PhysicsProperties::default();
+    for dt in BAD_DTS {
+        let mut states = [SlimeState::Settled, SlimeState::falling(&physprop)];
+        let mut y_bottoms = [0.0, 1.5];
+        let render = RenderProps {
+            y_bottom: 0.0,
+            y_scale: 1.0,
+            x_scale: 1.0,
+            x_offset: 0.0,
+        };
+        let mut outs = [render; 2];
+        let result = step_column_slice(
+            0,
+            &mut states,
+            &mut y_bottoms,
+            &mut outs,
+            dt,
+            0.0,
+            &physprop,
+        );
+        assert!(!result.settled && !result.at_rest, "dt {dt}");
+        assert_eq!(y_bottoms, [0.0, 1.5]);
+        assert!(matches!(states[1], SlimeState::Falling { .. }));
+        assert_eq!(outs, [render; 2]);
+    }
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A slime dropped from seven cells up onto another, and a third riding down on top of it.
+fn dropped_pair(physprop: &PhysicsProperties) -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(1, 3);
+    board.set(0, 0, SlimeState::Settled);
+    board.set(0, 1, SlimeState::falling(physprop));
+    board.set(0, 2, SlimeState::falling(physprop));
+    board.cell_mut(0, 1).unwrap().y_bottom = 8.0;
+    board.cell_mut(0, 2).unwrap().y_bottom = 9.0;
+    board
+}
+
+#[test]
+fn bounces_come_down_where_they_belong() {
+    let physprop = PhysicsProperties {
+        restitution: 0.5,
+        bounce_min_velocity: 2.0,
+        ..PhysicsProperties::default()
+    };
+    let mut board = dropped_pair(&physprop);
+    let mut bounces = 0;
+    let mut rising = false;
+    let mut steps = 0;
+    while !board.run_physics(1.0 / 60.0, &physprop) {
+        let up = matches!(board.get(0, 1), Some(SlimeState::Falling { velocity, .. }) if *velocity < 0.0);
+        if up && !rising {
+            bounces += 1;
+        }
+        rising = up;
+        // Never sinks into the slime it's bouncing on, however squashed that is
+        let lower = board.cell(0, 1).unwrap().y_bottom;
+        let floor = board.cell(0, 0).unwrap().render;
+        assert!(lower >= floor.y_bottom + floor.y_scale - 1e-4, "{lower}");
+        steps += 1;
+        assert!(steps < 1200, "never settled");
+    }
+    assert!(bounces >= 2, "{bounces}");
+    assert!(board.is_settled());
+    for y in 0..3 {
+        assert_eq!(board.get(0, y), Some(&SlimeState::Settled));
+        assert_eq!(board.cell(0, y).unwrap().y_bottom, y as f32);
+    }
+}
+
+#[test]
+fn no_restitution_no_bounce() {
+    let physprop = PhysicsProperties::default();
+    let mut board = dropped_pair(&physprop);
+    while !board.run_physics(1.0 / 60.0, &physprop) {
+        assert!(!matches!(
+            board.get(0, 1),
+            Some(SlimeState::Falling { velocity, .. }) if *velocity < 0.0
+        ));
+    }
+    assert_eq!(board.cell(0, 2).unwrap().y_bottom, 2.0);
+}
+
+#[test]
+fn a_set_number_of_hops_lands_one_more_time() {
+    for bounces in [0, 1, 2, 4] {
+        let physprop = PhysicsProperties {
+            bounce_mode: BounceMode::Count {
+                bounces,
+                factor: 0.5,
+            },
+            ..PhysicsProperties::default()
+        };
+        let mut board = DenseGridBoard::new(1, 2);
+        board.set(0, 0, SlimeState::Settled);
+        board.set(0, 1, SlimeState::falling(&physprop));
+        board.cell_mut(0, 1).unwrap().y_bottom = 6.0;
+        let mut impacts = Vec::new();
+        for _ in 0..1200 {
+            let stats = board.run_physics_stats(1.0 / 60.0, &physprop);
+            for _ in 0..stats.landings {
+                impacts.push(board.get(0, 1).copied());
+            }
+        }
+        assert_eq!(impacts.len() as u32, bounces + 1, "{bounces}: {impacts:?}");
+        // Every hop but the last goes back up
+        let (last, hops) = impacts.split_last().unwrap();
+        assert!(
+            hops.iter()
+                .all(|state| matches!(state, Some(SlimeState::Bouncing { .. }))),
+            "{impacts:?}"
+        );
+        assert!(matches!(last, Some(SlimeState::Jiggling { .. })));
+        assert!(board.is_settled());
+        assert_eq!(board.cell(0, 1).unwrap().y_bottom, 1.0);
+    }
+}
@@ -0,0 +1,133 @@
+use jiggly_fever::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction for Dir {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        [Dir::Up, Dir::Down, Dir::Left, Dir::Right]
+            .into_iter()
+            .filter(move |&dir| dir != self)
+    }
+    fn opposite(self) -> Self {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+            Dir::Right => Dir::Left,
+        }
+    }
+    const UP: Self = Dir::Up;
+    const RIGHT: Option<Self> = Some(Dir::Right);
+}
+
+/// A `width` by `height` board, row by row, bottom first.
+struct Grid<S> {
+    width: usize,
+    height: usize,
+    states: Vec<SlimeState<S>>,
+    y_bottoms: Vec<S>,
+}
+
+impl<S: Scalar> Grid<S> {
+    /// Everything dropped from a couple of cells up, staggered so they land at different times.
+    fn dropped(width: usize, height: usize) -> Self {
+        Grid {
+            width,
+            height,
+            states: (0..width * height)
+                .map(|_| SlimeState::Falling {
+                    velocity: S::ZERO,
+                    fallen: S::ZERO,
+                })
+                .collect(),
+            y_bottoms: (0..width * height)
+                .map(|i| S::from_f64((i / width) as f64 + 2.0 + (i % width) as f64 * 0.7))
+                .collect(),
+        }
+    }
+}
+
+impl<S: Scalar> JigglyBoard<S> for Grid<S> {
+    type Dir = Dir;
+    type Loc = usize;
+    fn apply_dir_to_loc(&self, dir: Dir, loc: usize, impulse: S) -> Option<(usize, S)> {
+        let (x, y) = (loc % self.width, loc / self.width);
+        let to = match dir {
+            Dir::Left if x > 0 => loc - 1,
+            Dir::Right if x + 1 < self.width => loc + 1,
+            Dir::Up if y + 1 < self.height => loc + self.width,
+            Dir::Down if y > 0 => loc - self.width,
+            _ => return None,
+        };
+        if matches!(self.states[to], SlimeState::Falling { .. }) {
+            return None;
+        }
+        Some((to, impulse * S::from_f64(0.5)))
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = usize>> {
+        let (width, height) = (self.width, self.height);
+        (0..width).map(move |x| (0..height).map(move |y| x + y * width))
+    }
+    fn mut_slime_with(&mut self, loc: usize, f: impl FnOnce(SlimePropsIn<S>) -> SlimePropsOut<S>) {
+        let state = std::mem::replace(&mut self.states[loc], SlimeState::Settled);
+        let out = f(SlimePropsIn {
+            state,
+            y_bottom: self.y_bottoms[loc],
+        });
+        self.states[loc] = out.state;
+        self.y_bottoms[loc] = out.y_bottom;
+    }
+    fn impulse_jiggle_with(&mut self, loc: usize, f: impl FnOnce(SlimeState<S>) -> SlimeState<S>) {
+        let state = std::mem::replace(&mut self.states[loc], SlimeState::Settled);
+        self.states[loc] = f(state);
+    }
+}
+
+/// Drop a 4x3 board and step it with `scratch` until it settles, returning where everything ended
+/// up and how many propagations didn't fit.
+fn settle(mut scratch: PhysicsScratch<usize, Dir>) -> (Vec<f32>, usize) {
+    let mut grid = Grid::<f32>::dropped(4, 3);
+    let physprop = PhysicsProperties::default();
+    let mut truncated = 0;
+    for _ in 0..500 {
+        let settled = grid.run_physics_with_scratch(1.0 / 60.0, &physprop, &mut scratch, &mut ());
+        truncated += scratch.truncated_propagations();
+        if settled {
+            return (grid.y_bottoms, truncated);
+        }
+    }
+    panic!("never settled");
+}
+
+#[test]
+fn roomy_scratch_matches_unbounded() {
+    let unbounded = settle(PhysicsScratch::new());
+    let bounded = settle(PhysicsScratch::with_capacity(
+        12,
+        4,
+        64,
+        PropagationOverflow::DropWeakest,
+    ));
+    assert_eq!(unbounded, bounded);
+    assert_eq!(bounded.1, 0);
+}
+
+#[test]
+fn full_scratch_drops_propagations_and_still_settles() {
+    for overflow in [
+        PropagationOverflow::DropWeakest,
+        PropagationOverflow::DropNewest,
+    ] {
+        let (y_bottoms, truncated) = settle(PhysicsScratch::with_capacity(12, 4, 1, overflow));
+        assert!(truncated > 0);
+        for (i, y) in y_bottoms.iter().enumerate() {
+            assert!((y - (i / 4) as f32).abs() < 1e-4, "{overflow:?} {i} {y}");
+        }
+    }
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A stack of three in a one column board.
+fn stack() -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(1, 6);
+    for y in 0..3 {
+        board.set(0, y, SlimeState::Settled);
+    }
+    board
+}
+
+/// Passes jiggles that went off the right edge of one board on to the left edge of the next, halved.
+fn bridge(
+    escaped: EscapedImpulse<(usize, usize), Cardinal4>,
+) -> Option<JigglePropagation<(usize, usize), Cardinal4>> {
+    (escaped.dir == Cardinal4::Right).then_some(JigglePropagation {
+        at: escaped.from,
+        impulse: escaped.impulse * 0.5,
+        came_from: Cardinal4::Left,
+        hops: escaped.hops,
+    })
+}
+
+#[test]
+fn landing_on_one_board_jiggles_the_next() {
+    for wave_mode in [WaveMode::Separate, WaveMode::Coalesced] {
+        let physprop = PhysicsProperties {
+            wave_mode,
+            ..PhysicsProperties::default()
+        };
+        let (mut left, mut right) = (stack(), stack());
+        left.set(0, 3, SlimeState::falling(&physprop));
+        left.cell_mut(0, 3).unwrap().y_bottom = 5.0;
+        let mut escaped = Vec::new();
+        let mut total = 0;
+        let mut leaned: f32 = 0.0;
+        for _ in 0..120 {
+            left.run_physics_collect_escaped(1.0 / 60.0, &physprop, &mut escaped);
+            assert!(escaped.iter().all(|escaped| escaped.from.0 == 0));
+            total += escaped.len();
+            right.inject_impulses(escaped.drain(..), &bridge, &physprop);
+            right.run_physics(1.0 / 60.0, &physprop);
+            for y in 0..3 {
+                leaned = leaned.max(right.cell(0, y).unwrap().render.x_offset.abs());
+            }
+        }
+        assert!(total > 0, "{wave_mode:?}");
+        assert!(leaned > 0.0, "{wave_mode:?}");
+    }
+}
+
+#[test]
+fn bridge_decides_what_crosses() {
+    let physprop = PhysicsProperties::default();
+    let mut board = stack();
+    board.inject_impulses(
+        [EscapedImpulse {
+            from: (0, 0),
+            dir: Cardinal4::Left,
+            impulse: 5.0,
+            hops: 1,
+        }],
+        &bridge,
+        &physprop,
+    );
+    assert!(matches!(board.get(0, 0), Some(SlimeState::Settled)));
+    board.inject_impulses(
+        [EscapedImpulse {
+            from: (0, 0),
+            dir: Cardinal4::Right,
+            impulse: 5.0,
+            hops: 1,
+        }],
+        &bridge,
+        &physprop,
+    );
+    assert!(matches!(board.get(0, 0), Some(SlimeState::Jiggling { .. })));
+}
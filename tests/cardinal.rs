@@ -0,0 +1,48 @@
+use jiggly_fever::*;
+
+#[test]
+fn opposite_of_every_direction() {
+    assert_eq!(Cardinal4::Up.opposite(), Cardinal4::Down);
+    assert_eq!(Cardinal4::Down.opposite(), Cardinal4::Up);
+    assert_eq!(Cardinal4::Left.opposite(), Cardinal4::Right);
+    assert_eq!(Cardinal4::Right.opposite(), Cardinal4::Left);
+}
+
+#[test]
+fn other_directions_of_every_direction() {
+    let others = |dir: Cardinal4| dir.other_directions().collect::<Vec<_>>();
+    assert_eq!(
+        others(Cardinal4::Up),
+        [Cardinal4::Down, Cardinal4::Left, Cardinal4::Right]
+    );
+    assert_eq!(
+        others(Cardinal4::Down),
+        [Cardinal4::Up, Cardinal4::Left, Cardinal4::Right]
+    );
+    assert_eq!(
+        others(Cardinal4::Left),
+        [Cardinal4::Up, Cardinal4::Down, Cardinal4::Right]
+    );
+    assert_eq!(
+        others(Cardinal4::Right),
+        [Cardinal4::Up, Cardinal4::Down, Cardinal4::Left]
+    );
+}
+
+#[test]
+fn constants_name_the_variants() {
+    assert_eq!(<Cardinal4 as Direction>::UP, Cardinal4::Up);
+    assert_eq!(Cardinal4::DOWN, Cardinal4::Down);
+    assert_eq!(Cardinal4::LEFT, Cardinal4::Left);
+    assert_eq!(Cardinal4::RIGHT, Cardinal4::Right);
+    assert_eq!(<Cardinal4 as Direction>::RIGHT, Some(Cardinal4::Right));
+    assert_eq!(
+        Cardinal4::ALL,
+        [
+            Cardinal4::Up,
+            Cardinal4::Down,
+            Cardinal4::Left,
+            Cardinal4::Right
+        ]
+    );
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+const WIDTH: usize = 12;
+
+/// Every impulse delivered, as `(step, loc, impulse)`.
+#[derive(Default)]
+struct Impulses {
+    step: usize,
+    seen: Vec<(usize, (usize, usize), f32)>,
+}
+
+impl PhysicsObserver<(usize, usize), Cardinal4> for Impulses {
+    fn impulsed(&mut self, loc: (usize, usize), impulse: f32, _: Cardinal4) {
+        self.seen.push((self.step, loc, impulse));
+    }
+}
+
+/// A slime landing on every column of a wide row at once, stepped in one scratch until it settles.
+fn cascade(max_propagations_per_step: Option<u32>) -> Vec<(usize, (usize, usize), f32)> {
+    let physprop = PhysicsProperties {
+        max_propagations_per_step,
+        ..PhysicsProperties::default()
+    };
+    let mut board = DenseGridBoard::new(WIDTH, 2);
+    for x in 0..WIDTH {
+        board.set(x, 0, SlimeState::Settled);
+        board.set(x, 1, SlimeState::falling(&physprop));
+        board.cell_mut(x, 1).unwrap().y_bottom = 1.5;
+    }
+    let mut scratch = PhysicsScratch::new();
+    let mut impulses = Impulses::default();
+    while !board.run_physics_with_scratch(1.0 / 60.0, &physprop, &mut scratch, &mut impulses) {
+        impulses.step += 1;
+        assert!(impulses.step < 600, "never settled");
+    }
+    impulses.seen
+}
+
+fn steps(impulses: &[(usize, (usize, usize), f32)]) -> Vec<usize> {
+    let mut steps: Vec<usize> = impulses.iter().map(|&(step, ..)| step).collect();
+    steps.dedup();
+    steps
+}
+
+#[test]
+fn a_budget_delays_impulses_but_drops_none() {
+    let all_at_once = cascade(None);
+    let budgeted = cascade(Some(2));
+    // Twelve landings at two a step
+    assert_eq!(steps(&all_at_once).len(), 1);
+    assert_eq!(steps(&budgeted).len(), WIDTH / 2);
+    let landed = steps(&all_at_once)[0];
+    assert_eq!(
+        steps(&budgeted),
+        (landed..landed + WIDTH / 2).collect::<Vec<_>>()
+    );
+    let reached = |impulses: Vec<(usize, (usize, usize), f32)>| {
+        let mut reached: Vec<_> = impulses
+            .into_iter()
+            .map(|(_, loc, impulse)| (loc, impulse.to_bits()))
+            .collect();
+        reached.sort();
+        reached
+    };
+    let (all_at_once, budgeted) = (reached(all_at_once), reached(budgeted));
+    assert_eq!(all_at_once, budgeted);
+    let mut cells: Vec<_> = budgeted.iter().map(|&(loc, _)| loc).collect();
+    cells.dedup();
+    assert_eq!(cells.len(), WIDTH * 2);
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// One column of slimes, bottom first, with `y_bottom`s in whatever units `cell_height` says.
+struct Column {
+    cells: Vec<(SlimeState, f32, f32)>,
+}
+
+impl JigglyBoard for Column {
+    type Dir = Cardinal4;
+    type Loc = usize;
+    fn apply_dir_to_loc(&self, dir: Cardinal4, y: usize, impulse: f32) -> Option<(usize, f32)> {
+        let y = match dir {
+            Cardinal4::Up => y + 1,
+            Cardinal4::Down => y.checked_sub(1)?,
+            Cardinal4::Left | Cardinal4::Right => return None,
+        };
+        (y < self.cells.len()).then_some((y, impulse * 0.5))
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = usize>> {
+        core::iter::once(0..self.cells.len())
+    }
+    fn mut_slime_with(&mut self, y: usize, f: impl FnOnce(SlimePropsIn) -> SlimePropsOut) {
+        let (state, y_bottom, y_scale) = &mut self.cells[y];
+        let out = f(SlimePropsIn {
+            state: *state,
+            y_bottom: *y_bottom,
+        });
+        (*state, *y_bottom, *y_scale) = (out.state, out.y_bottom, out.y_scale);
+    }
+    fn impulse_jiggle_with(&mut self, y: usize, f: impl FnOnce(SlimeState) -> SlimeState) {
+        let (state, _, _) = &mut self.cells[y];
+        *state = f(*state);
+    }
+    fn peek_slime<R>(&self, y: usize, f: impl FnOnce(&SlimeState, f32) -> R) -> Option<R> {
+        let (state, y_bottom, _) = &self.cells[y];
+        Some(f(state, *y_bottom))
+    }
+}
+
+/// Every slime's `y_bottom` and `y_scale` after each step of one dropping from three cells up onto two
+/// others, until they've all stopped.
+fn drop_trajectory(cell_height: f32) -> Vec<Vec<(f32, f32)>> {
+    let physprop = PhysicsProperties {
+        cell_height,
+        ..PhysicsProperties::default()
+    };
+    let mut board = Column {
+        cells: vec![
+            (SlimeState::Settled, 0.0, 1.0),
+            (SlimeState::Settled, cell_height, 1.0),
+            (SlimeState::falling(&physprop), 5.0 * cell_height, 1.0),
+        ],
+    };
+    let mut trajectory = Vec::new();
+    while !board.run_physics(1.0 / 60.0, &physprop) {
+        trajectory.push(
+            board
+                .cells
+                .iter()
+                .map(|&(_, y_bottom, y_scale)| (y_bottom, y_scale))
+                .collect(),
+        );
+        assert!(trajectory.len() < 1000, "never settled");
+    }
+    assert!(board.is_settled());
+    assert_eq!(board.cells[2].1, 2.0 * cell_height);
+    trajectory
+}
+
+#[test]
+fn tall_cells_move_the_same_way_scaled_up() {
+    let unit = drop_trajectory(1.0);
+    let tall = drop_trajectory(32.0);
+    assert_eq!(unit.len(), tall.len());
+    for (step, (unit, tall)) in unit.iter().zip(&tall).enumerate() {
+        for (&(unit_bottom, unit_scale), &(tall_bottom, tall_scale)) in unit.iter().zip(tall) {
+            assert!(
+                (unit_bottom * 32.0 - tall_bottom).abs() < 1e-3,
+                "step {step}: {unit_bottom} against {tall_bottom}"
+            );
+            assert!(
+                (unit_scale - tall_scale).abs() < 1e-5,
+                "step {step}: {unit_scale} against {tall_scale}"
+            );
+        }
+    }
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// Step `board` until it settles, panicking if it takes more than a minute.
+fn settle(board: &mut DenseGridBoard, physprop: &PhysicsProperties) {
+    if !(0..3600).any(|_| board.run_physics(1.0 / 60.0, physprop)) {
+        panic!("never settled");
+    }
+}
+
+/// A tower of six in the middle of a 3 wide board, with a couple of slimes beside it.
+fn tower() -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(3, 8);
+    for y in 0..6 {
+        board.set(1, y, SlimeState::Settled);
+        board.cell_mut(1, y).unwrap().y_bottom = y as f32;
+    }
+    board.set(0, 0, SlimeState::Settled);
+    board.set(0, 1, SlimeState::Settled);
+    board.set(2, 0, SlimeState::Settled);
+    board
+}
+
+#[test]
+fn clearing_drops_the_rest_of_the_column() {
+    let physprop = PhysicsProperties::default();
+    let mut board = tower();
+    assert_eq!(
+        board.clear_cells([(1, 1), (1, 2), (1, 2)], &physprop, 0.5),
+        2
+    );
+    assert!(matches!(board.get(1, 1), Some(SlimeState::Falling { .. })));
+    assert_eq!(board.cell(1, 1).unwrap().y_bottom, 3.0);
+    assert!(board.get(1, 4).is_none());
+    assert!(matches!(board.get(0, 1), Some(SlimeState::Jiggling { .. })));
+    assert!(matches!(board.get(1, 0), Some(SlimeState::Jiggling { .. })));
+    settle(&mut board, &physprop);
+    for y in 0..4 {
+        let y_bottom = board.cell(1, y).unwrap().y_bottom;
+        assert!((y_bottom - y as f32).abs() < 1e-4, "{y} at {y_bottom}");
+    }
+}
+
+#[test]
+fn clearing_the_top_or_nothing_is_fine() {
+    let physprop = PhysicsProperties::default();
+    let mut board = tower();
+    assert_eq!(board.clear_cells([(1, 5)], &physprop, 0.5), 1);
+    assert_eq!(board.clear_cells([(1, 7)], &physprop, 0.5), 0);
+    settle(&mut board, &physprop);
+    assert!(board.get(1, 4).is_some() && board.get(1, 5).is_none());
+}
+
+#[test]
+fn no_reaction_leaves_neighbours_alone() {
+    let physprop = PhysicsProperties::default();
+    let mut board = tower();
+    assert_eq!(board.clear_cells([(1, 1)], &physprop, 0.0), 1);
+    assert!(matches!(board.get(0, 1), Some(SlimeState::Settled)));
+    assert!(matches!(board.get(1, 0), Some(SlimeState::Settled)));
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+#[test]
+fn three_landings_on_one_slime_add_up() {
+    let physprop = PhysicsProperties {
+        wave_mode: WaveMode::Coalesced,
+        max_propagation_hops: 1,
+        ..PhysicsProperties::default()
+    };
+    // A slime in the middle with three more coming down on it, from the left, the right and above,
+    // each fast enough to land in the first step, but all at different speeds
+    let mut board = DenseGridBoard::new(3, 3);
+    for x in 0..3 {
+        board.set(x, 0, SlimeState::Settled);
+    }
+    board.set(1, 1, SlimeState::Settled);
+    for (x, y, velocity) in [(0, 1, 3.0), (2, 1, 5.0), (1, 2, 8.0)] {
+        board.set(
+            x,
+            y,
+            SlimeState::Falling {
+                velocity,
+                fallen: 0.0,
+            },
+        );
+        board.cell_mut(x, y).unwrap().y_bottom = y as f32 + 0.01;
+    }
+    let mut events = Vec::new();
+    board.run_physics_with_events(1.0 / 60.0, &physprop, &mut events);
+    let landed = |at| {
+        events
+            .iter()
+            .find_map(|event| match *event {
+                SlimeEvent::Landed { loc, impulse } if loc == at => Some(impulse),
+                _ => None,
+            })
+            .unwrap()
+    };
+    let (left, right, above) = (landed((0, 1)), landed((2, 1)), landed((1, 2)));
+    // Half of each gets across, the sideways ones leaning it opposite ways
+    let Some(&SlimeState::Jiggling {
+        momentum,
+        momentum_x,
+        ..
+    }) = board.get(1, 1)
+    else {
+        panic!("{:?}", board.get(1, 1));
+    };
+    assert!((momentum - above / 2.0).abs() < 1e-5, "{momentum}");
+    assert!(
+        (momentum_x - (left - right) / 2.0).abs() < 1e-5,
+        "{momentum_x}"
+    );
+    // All in one go, with the total
+    let impulsed: Vec<_> = events
+        .iter()
+        .filter_map(|event| match *event {
+            SlimeEvent::Impulsed { loc, impulse, .. } => Some((loc, impulse)),
+            _ => None,
+        })
+        .collect();
+    let middle: Vec<f32> = impulsed
+        .iter()
+        .filter(|(loc, _)| *loc == (1, 1))
+        .map(|&(_, impulse)| impulse)
+        .collect();
+    assert_eq!(middle.len(), 1, "{impulsed:?}");
+    assert!((middle[0] - (left + right + above) / 2.0).abs() < 1e-5);
+    let mut locs: Vec<_> = impulsed.iter().map(|&(loc, _)| loc).collect();
+    locs.sort();
+    let count = locs.len();
+    locs.dedup();
+    assert_eq!(locs.len(), count, "{impulsed:?}");
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A [`DenseGridBoard`] whose floor rises half a cell more than a slime's height with each column.
+struct Stairs(DenseGridBoard);
+
+impl JigglyBoard for Stairs {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.0.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.0.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.0.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.0.impulse_jiggle_with(loc, f)
+    }
+    fn peek_slime<R>(
+        &self,
+        loc: (usize, usize),
+        f: impl FnOnce(&SlimeState, f32) -> R,
+    ) -> Option<R> {
+        self.0.peek_slime(loc, f)
+    }
+    fn column_base(&self, column: usize) -> f32 {
+        column as f32 * 1.5
+    }
+}
+
+fn dropped(physprop: &PhysicsProperties) -> Stairs {
+    let mut grid = DenseGridBoard::new(4, 6);
+    for x in 0..4 {
+        for y in 0..3 {
+            grid.set(x, y, SlimeState::falling(physprop));
+            grid.cell_mut(x, y).unwrap().y_bottom = 9.0 + y as f32;
+        }
+    }
+    Stairs(grid)
+}
+
+#[test]
+fn columns_stack_from_their_own_floor() {
+    let physprop = PhysicsProperties::default();
+    let mut board = dropped(&physprop);
+    let mut settled = false;
+    for _ in 0..3600 {
+        settled = board.run_physics(1.0 / 60.0, &physprop);
+        assert_eq!(board.check_invariants(&physprop), Ok(()));
+        if settled {
+            break;
+        }
+    }
+    assert!(settled);
+    for x in 0..4 {
+        for y in 0..3 {
+            let got = board.0.cell(x, y).unwrap().y_bottom;
+            let want = x as f32 * 1.5 + y as f32;
+            assert!((got - want).abs() < 1e-3, "{x} {y} {got}");
+        }
+    }
+    board.0.cell_mut(3, 0).unwrap().y_bottom = 0.0;
+    board.0.set(3, 0, SlimeState::Settled);
+    assert!(board.check_invariants(&physprop).is_err());
+}
+
+#[test]
+fn erased_boards_keep_the_floor() {
+    let physprop = PhysicsProperties::default();
+    let mut board = dropped(&physprop);
+    let erased: &mut dyn JigglyBoardDyn<f32, Loc = (usize, usize), Dir = Cardinal4> = &mut board;
+    assert_eq!(erased.column_base(2), 3.0);
+}
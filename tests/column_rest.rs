@@ -0,0 +1,66 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A [`DenseGridBoard`] that remembers which columns the column pass found at rest.
+struct Watched {
+    grid: DenseGridBoard,
+    at_rest: Vec<usize>,
+}
+
+impl JigglyBoard for Watched {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.grid.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.grid.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.grid.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.grid.impulse_jiggle_with(loc, f)
+    }
+    fn on_column_at_rest(&mut self, col_index: usize) {
+        self.at_rest.push(col_index);
+    }
+}
+
+#[test]
+fn frozen_and_squished_slimes_count_as_at_rest() {
+    let physprop = PhysicsProperties::default();
+    let mut grid = DenseGridBoard::new(3, 4);
+    for x in 0..3 {
+        for y in 0..3 {
+            grid.set(x, y, SlimeState::Settled);
+        }
+    }
+    grid.set(0, 1, SlimeState::Frozen);
+    grid.squish((1, 2), 0.5);
+    grid.nudge((2, 2), 3.0, Cardinal4::Up, &physprop);
+    let mut board = Watched {
+        grid,
+        at_rest: Vec::new(),
+    };
+    board.physics_step(1.0 / 60.0, &physprop);
+    assert_eq!(board.at_rest, [0, 1]);
+    assert!((0..3600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+    board.at_rest.clear();
+    board.physics_step(1.0 / 60.0, &physprop);
+    assert_eq!(board.at_rest, [0, 1, 2]);
+}
@@ -0,0 +1,146 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+#[derive(Clone, Copy, PartialEq)]
+struct Up;
+
+impl Direction for Up {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+    fn opposite(self) -> Self {
+        self
+    }
+    const UP: Self = Up;
+}
+
+/// One column of slimes, bottom first, keeping what each was last drawn with.
+struct Column {
+    states: Vec<Option<SlimeState>>,
+    y_bottoms: Vec<f32>,
+    render: Vec<RenderProps>,
+}
+
+impl JigglyBoard for Column {
+    type Dir = Up;
+    type Loc = usize;
+    fn apply_dir_to_loc(&self, _: Up, _: usize, _: f32) -> Option<(usize, f32)> {
+        None
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = usize>> {
+        core::iter::once(0..self.y_bottoms.len())
+    }
+    fn mut_slime_with(&mut self, loc: usize, f: impl FnOnce(SlimePropsIn) -> SlimePropsOut) {
+        let out = f(SlimePropsIn {
+            state: self.states[loc].take().unwrap(),
+            y_bottom: self.y_bottoms[loc],
+        });
+        self.render[loc] = RenderProps::from(&out);
+        self.states[loc] = Some(out.state);
+        self.y_bottoms[loc] = out.y_bottom;
+    }
+    fn impulse_jiggle_with(&mut self, loc: usize, f: impl FnOnce(SlimeState) -> SlimeState) {
+        self.states[loc] = Some(f(self.states[loc].take().unwrap()));
+    }
+}
+
+/// A slime's state, bit for bit.
+fn bits(state: &SlimeState) -> Vec<u32> {
+    match state {
+        SlimeState::Settled => vec![0],
+        SlimeState::Falling { velocity, fallen } => vec![1, velocity.to_bits(), fallen.to_bits()],
+        SlimeState::Jiggling {
+            momentum,
+            offset,
+            life,
+            momentum_x,
+            offset_x,
+        } => [momentum, offset, life, momentum_x, offset_x]
+            .iter()
+            .map(|value| value.to_bits())
+            .collect(),
+        _ => vec![2],
+    }
+}
+
+/// Every third slime falling, the rest jiggling at different strengths.
+fn mixed(n: usize) -> Vec<SlimeState> {
+    (0..n)
+        .map(|i| {
+            if i % 3 == 0 {
+                SlimeState::Falling {
+                    velocity: 0.0,
+                    fallen: 0.0,
+                }
+            } else {
+                SlimeState::Jiggling {
+                    momentum: i as f32,
+                    offset: 0.1,
+                    life: 1.0,
+                    momentum_x: 0.0,
+                    offset_x: 0.0,
+                }
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn slice_steps_like_step_column() {
+    let n = 7;
+    let y_bottoms: Vec<f32> = (0..n).map(|i| i as f32 * 1.5).collect();
+    let render = RenderProps {
+        y_bottom: 0.0,
+        y_scale: 1.0,
+        x_scale: 1.0,
+        x_offset: 0.0,
+    };
+    let mut board = Column {
+        states: mixed(n).into_iter().map(Some).collect(),
+        y_bottoms: y_bottoms.clone(),
+        render: vec![render; n],
+    };
+    let (mut states, mut ys, mut out) = (mixed(n), y_bottoms, vec![render; n]);
+    let physprop = PhysicsProperties::bouncy();
+    let mut landings = 0;
+    for step in 0..400 {
+        let mut propagations = Vec::new();
+        let settled = board.step_column(0, 0..n, 1.0 / 120.0, &physprop, &mut propagations);
+        let result = step_column_slice(
+            0,
+            &mut states,
+            &mut ys,
+            &mut out,
+            1.0 / 120.0,
+            0.0,
+            &physprop,
+        );
+        assert_eq!(settled, result.settled, "step {step}");
+        assert_eq!(
+            propagations
+                .iter()
+                .map(|propagation| (propagation.at, propagation.impulse.to_bits()))
+                .collect::<Vec<_>>(),
+            result
+                .landings
+                .iter()
+                .map(|&(at, impulse)| (at, impulse.to_bits()))
+                .collect::<Vec<_>>(),
+            "step {step}"
+        );
+        assert_eq!(board.y_bottoms, ys, "step {step}");
+        assert_eq!(board.render, out, "step {step}");
+        assert_eq!(
+            board
+                .states
+                .iter()
+                .map(|state| bits(state.as_ref().unwrap()))
+                .collect::<Vec<_>>(),
+            states.iter().map(bits).collect::<Vec<_>>(),
+            "step {step}"
+        );
+        landings += result.landings.len();
+    }
+    assert!(landings > 0);
+}
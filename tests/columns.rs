@@ -0,0 +1,78 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A `width` wide board, three slimes deep everywhere.
+fn filled(width: usize) -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(width, 4);
+    for x in 0..width {
+        for y in 0..3 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board
+}
+
+/// How far the bottom slime in column `x` is leaning, or `None` if any of the column isn't jiggling.
+fn leaning(board: &DenseGridBoard, x: usize) -> Option<f32> {
+    let lean = match board.get(x, 0)? {
+        SlimeState::Jiggling { momentum_x, .. } => *momentum_x,
+        _ => return None,
+    };
+    (0..3)
+        .all(|y| matches!(board.get(x, y), Some(SlimeState::Jiggling { .. })))
+        .then_some(lean)
+}
+
+fn settled(board: &DenseGridBoard, x: usize) -> bool {
+    (0..3).all(|y| matches!(board.get(x, y), Some(SlimeState::Settled)))
+}
+
+#[test]
+fn removing_a_column_closes_the_gap_and_pushes_its_neighbours_apart() {
+    let physprop = PhysicsProperties::default();
+    let mut board = filled(5);
+    board.set(2, 3, SlimeState::landed());
+    let removed = board.remove_column(2, 1.0, &physprop).unwrap();
+    assert_eq!(removed.len(), 4);
+    assert_eq!(board.width(), 4);
+    let left = leaning(&board, 1).unwrap();
+    let right = leaning(&board, 2).unwrap();
+    assert!(left < 0.0 && right > 0.0, "{left} {right}");
+    assert!(settled(&board, 0) && settled(&board, 3));
+    assert!(board.remove_column(4, 1.0, &physprop).is_none());
+    assert!(board.remove_column(0, 1.0, &physprop).is_some());
+    assert_eq!(board.width(), 3);
+    assert!(leaning(&board, 0).is_some());
+    assert!((0..3600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+}
+
+#[test]
+fn inserting_a_column_pushes_its_neighbours_apart() {
+    let physprop = PhysicsProperties::default();
+    let mut board = filled(3);
+    board.insert_column(1, (0..2).map(|_| SlimeState::Settled), 1.0, &physprop);
+    assert_eq!(board.width(), 4);
+    let left = leaning(&board, 0).unwrap();
+    let right = leaning(&board, 2).unwrap();
+    assert!(left < 0.0 && right > 0.0, "{left} {right}");
+    assert!(settled(&board, 3));
+    assert_eq!(board.cell(1, 1).unwrap().y_bottom, 1.0);
+    assert!(board.get(1, 2).is_none());
+    board.insert_column(4, [], 1.0, &physprop);
+    assert_eq!(board.width(), 5);
+    assert!(leaning(&board, 3).is_some());
+    assert!((0..3600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+    assert!(settled(&board, 0));
+}
+
+#[test]
+fn nudge_jiggles_one_slime_only() {
+    let physprop = PhysicsProperties::default();
+    let mut board = filled(3);
+    board.nudge((1, 1), 2.0, Cardinal4::Left, &physprop);
+    assert!(leaning(&board, 1).is_none());
+    assert!(matches!(board.get(1, 1), Some(SlimeState::Jiggling { .. })));
+    assert!(matches!(board.get(2, 1), Some(SlimeState::Settled)));
+    assert!(matches!(board.get(1, 0), Some(SlimeState::Settled)));
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// All the momentum, either way, that a tap of `impulse` on top of a settled 5 by 4 block leaves in it.
+fn momentum_added(conservation_mode: ConservationMode, impulse: f32) -> f32 {
+    let physprop = PhysicsProperties {
+        conservation_mode,
+        ..PhysicsProperties::default()
+    };
+    let mut board = DenseGridBoard::new(5, 4);
+    for x in 0..5 {
+        for y in 0..4 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board.impulse_signed((2, 3), impulse, Cardinal4::Up, &physprop);
+    (0..5)
+        .flat_map(|x| (0..4).map(move |y| (x, y)))
+        .map(|(x, y)| match *board.get(x, y).unwrap() {
+            SlimeState::Jiggling {
+                momentum,
+                momentum_x,
+                ..
+            } => momentum.abs() + momentum_x.abs(),
+            _ => 0.0,
+        })
+        .sum()
+}
+
+#[test]
+fn a_conserving_wave_gives_out_no_more_than_it_had() {
+    for impulse in [0.5, 2.0, 4.0, 10.0] {
+        let conserved = momentum_added(ConservationMode::Conserving, impulse);
+        assert!(conserved <= impulse * 1.0001, "{impulse}: {conserved}");
+        assert!(conserved > 0.0);
+    }
+}
+
+#[test]
+fn an_additive_wave_gives_out_more() {
+    let added = momentum_added(ConservationMode::Additive, 4.0);
+    assert!(added > 4.0, "{added}");
+}
@@ -0,0 +1,112 @@
+use jiggly_fever::*;
+
+#[derive(Clone, Copy, PartialEq)]
+struct Up;
+
+impl Direction for Up {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+    fn opposite(self) -> Self {
+        self
+    }
+    const UP: Self = Up;
+}
+
+/// A board of one slime, on the floor.
+struct One {
+    state: SlimeState,
+    y_bottom: f32,
+}
+
+impl JigglyBoard for One {
+    type Dir = Up;
+    type Loc = ();
+    fn apply_dir_to_loc(&self, _: Up, _: (), _: f32) -> Option<((), f32)> {
+        None
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = ()>> {
+        core::iter::once(core::iter::once(()))
+    }
+    fn mut_slime_with(&mut self, _: (), f: impl FnOnce(SlimePropsIn) -> SlimePropsOut) {
+        let state = std::mem::replace(&mut self.state, SlimeState::Settled);
+        let out = f(SlimePropsIn {
+            state,
+            y_bottom: self.y_bottom,
+        });
+        self.state = out.state;
+        self.y_bottom = out.y_bottom;
+    }
+    fn impulse_jiggle_with(&mut self, _: (), f: impl FnOnce(SlimeState) -> SlimeState) {
+        let state = std::mem::replace(&mut self.state, SlimeState::Settled);
+        self.state = f(state);
+    }
+}
+
+/// How big the wobble is every tenth of a second for the first `samples` tenths after a poke, as the
+/// amplitude the offset would swing to with no damping.
+fn envelope(dt: f32, physprop: &PhysicsProperties, samples: usize) -> Vec<f32> {
+    let mut one = One {
+        state: SlimeState::Jiggling {
+            momentum: 5.0,
+            offset: 0.0,
+            life: 1.0,
+            momentum_x: 0.0,
+            offset_x: 0.0,
+        },
+        y_bottom: 0.0,
+    };
+    let steps_per_sample = (0.1 / dt).round() as usize;
+    (0..samples)
+        .map(|_| {
+            for _ in 0..steps_per_sample {
+                one.run_physics(dt, physprop);
+            }
+            match one.state {
+                SlimeState::Jiggling {
+                    momentum, offset, ..
+                } => (offset * offset + momentum * momentum / physprop.jiggle_stiff).sqrt(),
+                _ => 0.0,
+            }
+        })
+        .collect()
+}
+
+/// How far apart two envelopes get, relative to the first one's peak.
+fn mismatch(a: &[f32], b: &[f32]) -> f32 {
+    let peak = a.iter().copied().fold(0.0, f32::max);
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| (a - b).abs() / peak)
+        .fold(0.0, f32::max)
+}
+
+fn physprop(damping_mode: DampingMode) -> PhysicsProperties {
+    PhysicsProperties {
+        jiggle_stiff: 100.0,
+        jiggle_damp: 0.05,
+        // Keep the life scaling out of it, since that's still applied per step.
+        jiggle_life_decrease_rate: 0.0,
+        damping_mode,
+        ..PhysicsProperties::default()
+    }
+}
+
+#[test]
+fn per_second_damping_matches_across_framerates() {
+    let physprop = physprop(DampingMode::PerSecond);
+    let slow = envelope(1.0 / 30.0, &physprop, 6);
+    let fast = envelope(1.0 / 120.0, &physprop, 6);
+    assert!(mismatch(&fast, &slow) < 0.1, "{slow:?} vs {fast:?}");
+}
+
+#[test]
+fn per_step_damping_depends_on_framerate() {
+    let physprop = PhysicsProperties {
+        jiggle_damp: 0.95,
+        ..physprop(DampingMode::PerStep)
+    };
+    let slow = envelope(1.0 / 30.0, &physprop, 6);
+    let fast = envelope(1.0 / 120.0, &physprop, 6);
+    assert!(mismatch(&slow, &fast) > 0.3, "{slow:?} vs {fast:?}");
+}
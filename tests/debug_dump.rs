@@ -0,0 +1,50 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+fn jiggling(offset: f32, offset_x: f32) -> SlimeState {
+    SlimeState::Jiggling {
+        momentum: 1.0,
+        offset,
+        life: 1.0,
+        momentum_x: 0.0,
+        offset_x,
+    }
+}
+
+/// A settled column, a jiggling one, an empty one, and one with a frozen slime under a falling one.
+fn board() -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(4, 5);
+    for y in 0..3 {
+        board.set(0, y, SlimeState::Settled);
+    }
+    board.set(1, 0, SlimeState::Settled);
+    board.set(1, 1, jiggling(0.32, 0.0));
+    board.set(1, 2, jiggling(0.01, -2.0));
+    board.set(1, 3, jiggling(0.01, 0.0));
+    board.set(3, 0, SlimeState::Frozen);
+    board.set(
+        3,
+        4,
+        SlimeState::Falling {
+            velocity: 2.0,
+            fallen: 0.0,
+        },
+    );
+    board
+}
+
+#[test]
+fn dump_has_a_line_per_column() {
+    assert_eq!(board().debug_dump(), "...\n.39~\n\n#v\n");
+}
+
+#[test]
+fn verbose_dump_lists_every_slime() {
+    let dump = board().debug_dump_verbose();
+    assert!(dump.starts_with("column 0:\n  0 . y_bottom 0.0 Settled\n"));
+    assert!(dump.contains("column 2:\ncolumn 3:\n"));
+    assert!(dump.ends_with(
+        "column 3:\n  0 # y_bottom 0.0 Frozen\n  1 v y_bottom 4.0 Falling { velocity: 2.0, fallen: 0.0 }\n"
+    ));
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// Step `board` until it settles, panicking if it takes more than half a minute.
+fn settle(board: &mut DenseGridBoard, physprop: &PhysicsProperties) {
+    if !(0..1800).any(|_| board.run_physics(1.0 / 60.0, physprop)) {
+        panic!("never settled");
+    }
+}
+
+fn assert_stacked(board: &DenseGridBoard, x: usize, height: usize) {
+    for y in 0..height {
+        let y_bottom = board.cell(x, y).unwrap().y_bottom;
+        assert!(
+            (y_bottom - y as f32).abs() < 1e-4,
+            "({x}, {y}) at {y_bottom}"
+        );
+    }
+    assert!(board.get(x, height).is_none());
+}
+
+/// A tower of three in the middle of a 3 wide board, one slime either side of it.
+fn tower() -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(3, 6);
+    for y in 0..3 {
+        board.set(1, y, SlimeState::Settled);
+    }
+    board.set(0, 0, SlimeState::Settled);
+    board.set(2, 0, SlimeState::Settled);
+    board
+}
+
+#[test]
+fn drop_lands_on_the_stack_and_ripples_sideways() {
+    let physprop = PhysicsProperties::default();
+    let mut board = tower();
+    board.set(1, 3, SlimeState::falling(&physprop));
+    board.cell_mut(1, 3).unwrap().y_bottom = 5.0;
+    let mut rippled = false;
+    for _ in 0..600 {
+        board.run_physics(1.0 / 60.0, &physprop);
+        rippled |= matches!(board.get(0, 0), Some(SlimeState::Jiggling { .. }));
+    }
+    assert!(rippled);
+    settle(&mut board, &physprop);
+    assert_stacked(&board, 1, 4);
+}
+
+#[test]
+fn clearing_a_cell_drops_everything_above_it() {
+    let physprop = PhysicsProperties::default();
+    let mut board = tower();
+    board.set(1, 3, SlimeState::Settled);
+    board.cell_mut(1, 3).unwrap().y_bottom = 3.0;
+    assert!(matches!(board.remove(1, 1), Some(SlimeState::Settled)));
+    assert!(board.get(1, 3).is_none());
+    assert_eq!(board.cell(1, 2).unwrap().y_bottom, 3.0);
+    settle(&mut board, &physprop);
+    assert_stacked(&board, 1, 3);
+}
+
+#[test]
+fn popped_slimes_leave_the_board() {
+    let physprop = PhysicsProperties::default();
+    let mut board = tower();
+    board.set(1, 0, SlimeState::Popping { progress: 0.0 });
+    board.set(1, 1, SlimeState::Popping { progress: 0.0 });
+    settle(&mut board, &physprop);
+    assert!(matches!(board.get(1, 0), Some(SlimeState::Settled)));
+    assert_stacked(&board, 1, 1);
+}
+
+#[test]
+fn transfer_factors_scale_the_ripple() {
+    let mut board = DenseGridBoard::<f32>::new(3, 3);
+    board.set(0, 0, SlimeState::Settled);
+    board.set(1, 0, SlimeState::Settled);
+    board.set(0, 1, SlimeState::Settled);
+    board.transfer_horizontal = 0.5;
+    board.transfer_vertical = 0.25;
+    assert_eq!(
+        board.apply_dir_to_loc(Cardinal4::Right, (0, 0), 1.0),
+        Some(((1, 0), 0.5))
+    );
+    assert_eq!(
+        board.apply_dir_to_loc(Cardinal4::Up, (0, 0), 1.0),
+        Some(((0, 1), 0.25))
+    );
+    assert_eq!(board.apply_dir_to_loc(Cardinal4::Left, (0, 0), 1.0), None);
+    assert_eq!(board.apply_dir_to_loc(Cardinal4::Right, (1, 0), 1.0), None);
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// The bottom slime's offset each step from the one above it letting go until that one lands back on it,
+/// having been left a cell up by the one between them being taken out.
+fn offsets_before_landing(detach_stretch: f32) -> Vec<f32> {
+    let physprop = PhysicsProperties {
+        detach_stretch,
+        ..PhysicsProperties::default()
+    };
+    let mut board = DenseGridBoard::new(1, 3);
+    for y in 0..3 {
+        board.set(0, y, SlimeState::Settled);
+    }
+    board.remove(0, 1);
+    board.begin_fall((0, 1), &physprop);
+    let mut offsets = Vec::new();
+    while !matches!(board.get(0, 1), Some(SlimeState::Jiggling { .. })) {
+        offsets.push(match board.get(0, 0) {
+            Some(&SlimeState::Jiggling { offset, .. }) => offset,
+            _ => 0.0,
+        });
+        board.run_physics(1.0 / 60.0, &physprop);
+        assert!(offsets.len() < 600, "never landed");
+    }
+    assert!((0..600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+    offsets
+}
+
+#[test]
+fn the_slime_below_stretches_after_the_one_above_and_springs_back() {
+    let offsets = offsets_before_landing(1.5);
+    let stretched = offsets.iter().position(|&offset| offset < 0.0);
+    let Some(stretched) = stretched else {
+        panic!("{offsets:?}");
+    };
+    // Pulled up, then back down past where it rests, all before the faller comes back down on it
+    assert!(
+        offsets[stretched..].iter().any(|&offset| offset >= 0.0),
+        "{offsets:?}"
+    );
+}
+
+#[test]
+fn no_detach_stretch_leaves_it_alone() {
+    assert!(
+        offsets_before_landing(0.0)
+            .iter()
+            .all(|&offset| offset == 0.0)
+    );
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "alloc")]
+
+use std::collections::BTreeSet;
+use std::marker::PhantomData;
+
+use jiggly_fever::*;
+
+/// Remembers which cells impulses arrived at.
+struct Reached<'a, D>(&'a mut BTreeSet<(usize, usize)>, PhantomData<D>);
+
+impl<D> PhysicsObserver<(usize, usize), D> for Reached<'_, D> {
+    fn impulsed(&mut self, at: (usize, usize), _impulse: f32, _came_from: D) {
+        self.0.insert(at);
+    }
+}
+
+/// Poke the middle of a full 9x9 board, returning every cell the wave reached.
+fn reached<D: GridDirection>(mut board: DenseGridBoard<f32, D>) -> BTreeSet<(usize, usize)> {
+    for x in 0..9 {
+        for y in 0..9 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board.transfer_horizontal = 0.6;
+    board.transfer_vertical = 0.6;
+    board.transfer_diagonal = 0.6f32.powf(std::f32::consts::SQRT_2);
+    let physprop = PhysicsProperties {
+        propagation_order: PropagationOrder::BreadthFirst,
+        ..PhysicsProperties::default()
+    };
+    let mut reached = BTreeSet::new();
+    board.propagate_jiggle_observed(
+        JigglePropagation {
+            at: (4, 4),
+            impulse: 1.0,
+            came_from: D::UP,
+            hops: 0,
+        },
+        &physprop,
+        &mut Reached(&mut reached, PhantomData),
+    );
+    reached
+}
+
+#[test]
+fn diagonals_reach_more_of_the_board() {
+    let four = reached(DenseGridBoard::<f32>::new(9, 9));
+    let eight = reached(DenseGridBoard::<f32, Diag8>::with_directions(9, 9));
+    assert!(four.is_subset(&eight));
+    assert!(eight.len() > four.len());
+    assert!(eight.contains(&(3, 3)));
+}
+
+#[test]
+fn diagonal_opposites_point_back() {
+    for dir in Diag8::ALL {
+        assert_eq!(dir.opposite().opposite(), dir);
+        let (dx, dy) = dir.grid_offset();
+        assert_eq!(dir.opposite().grid_offset(), (-dx, -dy));
+        assert_eq!(dir.other_directions().count(), 7);
+        assert!(dir.other_directions().all(|other| other != dir));
+    }
+    assert_eq!(Diag8::UpLeft.opposite(), Diag8::DownRight);
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A [`DenseGridBoard`] that marks columns clean once they're at rest, and counts how often each
+/// column's slimes are stepped.
+struct Tracked {
+    grid: DenseGridBoard,
+    clean: Vec<bool>,
+    visits: Vec<usize>,
+}
+
+impl Tracked {
+    fn new(grid: DenseGridBoard) -> Self {
+        let width = grid.width();
+        Tracked {
+            grid,
+            clean: vec![false; width],
+            visits: vec![0; width],
+        }
+    }
+}
+
+impl JigglyBoard for Tracked {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.grid.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.grid.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.visits[loc.0] += 1;
+        self.grid.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.clean[loc.0] = false;
+        self.grid.impulse_jiggle_with(loc, f)
+    }
+    fn column_dirty_hint(&self, col_index: usize) -> bool {
+        !self.clean[col_index]
+    }
+    fn on_column_at_rest(&mut self, col_index: usize) {
+        self.clean[col_index] = true;
+    }
+}
+
+#[test]
+fn clean_columns_are_left_alone() {
+    let physprop = PhysicsProperties::default();
+    let dt = 1.0 / 60.0;
+    let mut grid = DenseGridBoard::new(3, 2);
+    for x in 0..3 {
+        grid.set(x, 0, SlimeState::Settled);
+    }
+    grid.set(1, 1, SlimeState::Settled);
+    let mut board = Tracked::new(grid);
+    assert!(board.run_physics(dt, &physprop));
+    assert_eq!(board.clean, [true; 3]);
+    assert_eq!(board.visits, [1, 2, 1]);
+    // Somewhere the column pass would put straight back if it looked
+    board.grid.cell_mut(1, 1).unwrap().y_bottom = 1.25;
+    for _ in 0..1000 {
+        assert!(board.run_physics(dt, &physprop));
+    }
+    assert_eq!(board.visits, [1, 2, 1]);
+    assert_eq!(board.grid.cell(1, 1).unwrap().y_bottom, 1.25);
+    // Until something makes it dirty again
+    board.clean[1] = false;
+    assert!(board.run_physics(dt, &physprop));
+    assert_eq!(board.visits, [1, 4, 1]);
+    assert_eq!(board.grid.cell(1, 1).unwrap().y_bottom, 1.0);
+}
+
+#[test]
+fn a_wave_into_a_clean_column_wakes_it() {
+    let physprop = PhysicsProperties::default();
+    let dt = 1.0 / 60.0;
+    let mut grid = DenseGridBoard::new(3, 2);
+    for x in 0..3 {
+        grid.set(x, 0, SlimeState::Settled);
+    }
+    grid.set(1, 1, SlimeState::falling(&physprop));
+    grid.cell_mut(1, 1).unwrap().y_bottom = 1.5;
+    let mut board = Tracked::new(grid);
+    board.run_physics(dt, &physprop);
+    assert_eq!(board.clean, [true, false, true]);
+    // Nothing either side is stepped until the landing reaches it
+    let mut steps = 0;
+    while board.clean[0] {
+        assert_eq!((board.visits[0], board.visits[2]), (1, 1));
+        board.run_physics(dt, &physprop);
+        steps += 1;
+        assert!(steps < 60, "never landed");
+    }
+    assert!(!board.clean[2]);
+    while !board.clean[0] || !board.clean[2] {
+        board.run_physics(dt, &physprop);
+        steps += 1;
+        assert!(steps < 600, "never settled again");
+    }
+    // It was stepped for as long as it jiggled, not just the once
+    assert!(board.visits[0] > 5, "{:?}", board.visits);
+    assert_eq!(board.visits[0], board.visits[2]);
+    while !board.run_physics(dt, &physprop) {
+        steps += 1;
+        assert!(steps < 600, "never settled again");
+    }
+    assert_eq!(board.clean, [true; 3]);
+    let visits = board.visits.clone();
+    for _ in 0..100 {
+        board.run_physics(dt, &physprop);
+    }
+    assert_eq!(board.visits, visits);
+}
@@ -0,0 +1,152 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction for Dir {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        [Dir::Up, Dir::Down, Dir::Left, Dir::Right]
+            .into_iter()
+            .filter(move |&dir| dir != self)
+    }
+    fn opposite(self) -> Self {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+            Dir::Right => Dir::Left,
+        }
+    }
+    const UP: Self = Dir::Up;
+    const RIGHT: Option<Self> = Some(Dir::Right);
+}
+
+/// A `width` by `height` board, column by column, bottom first, with heavier slimes on the right.
+struct Board {
+    width: usize,
+    height: usize,
+    states: Vec<SlimeState>,
+    y_bottoms: Vec<f32>,
+}
+
+impl Board {
+    fn dropped(width: usize, height: usize) -> Self {
+        Board {
+            width,
+            height,
+            states: (0..width * height)
+                .map(|_| SlimeState::Falling {
+                    velocity: 0.0,
+                    fallen: 0.0,
+                })
+                .collect(),
+            y_bottoms: (0..width * height)
+                .map(|i| (i % height) as f32 + 2.0 + (i / height) as f32 * 0.7)
+                .collect(),
+        }
+    }
+    /// Every slime's state and `y_bottom`, bit for bit.
+    fn bits(&self) -> Vec<(String, u32)> {
+        self.states
+            .iter()
+            .map(|state| match state {
+                SlimeState::Settled => "settled".into(),
+                SlimeState::Falling { velocity, .. } => format!("falling {}", velocity.to_bits()),
+                SlimeState::Jiggling {
+                    momentum, offset, ..
+                } => format!("jiggling {} {}", momentum.to_bits(), offset.to_bits()),
+                _ => "other".into(),
+            })
+            .zip(self.y_bottoms.iter().map(|y| y.to_bits()))
+            .collect()
+    }
+}
+
+impl JigglyBoard for Board {
+    type Dir = Dir;
+    type Loc = usize;
+    fn apply_dir_to_loc(&self, dir: Dir, loc: usize, impulse: f32) -> Option<(usize, f32)> {
+        let (x, y) = (loc / self.height, loc % self.height);
+        let to = match dir {
+            Dir::Up if y + 1 < self.height => loc + 1,
+            Dir::Down if y > 0 => loc - 1,
+            Dir::Left if x > 0 => loc - self.height,
+            Dir::Right if x + 1 < self.width => loc + self.height,
+            _ => return None,
+        };
+        if matches!(self.states[to], SlimeState::Falling { .. }) {
+            return None;
+        }
+        Some((to, impulse * 0.5))
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = usize>> {
+        let height = self.height;
+        (0..self.width).map(move |x| (0..height).map(move |y| x * height + y))
+    }
+    fn mut_slime_with(&mut self, loc: usize, f: impl FnOnce(SlimePropsIn) -> SlimePropsOut) {
+        let state = std::mem::replace(&mut self.states[loc], SlimeState::Settled);
+        let out = f(SlimePropsIn {
+            state,
+            y_bottom: self.y_bottoms[loc],
+        });
+        self.states[loc] = out.state;
+        self.y_bottoms[loc] = out.y_bottom;
+    }
+    fn impulse_jiggle_with(&mut self, loc: usize, f: impl FnOnce(SlimeState) -> SlimeState) {
+        let state = std::mem::replace(&mut self.states[loc], SlimeState::Settled);
+        self.states[loc] = f(state);
+    }
+    fn mass(&self, loc: usize) -> f32 {
+        1.0 + (loc / self.height) as f32 * 0.5
+    }
+    fn loc_index(&self, loc: usize) -> Option<usize> {
+        Some(loc)
+    }
+}
+
+#[test]
+fn erased_board_steps_like_the_board_itself() {
+    let physprop = PhysicsProperties::default();
+    let mut plain = Board::dropped(4, 3);
+    let mut other = Board::dropped(4, 3);
+    let mut plain_stats = StepStats::default();
+    let mut erased_stats = StepStats::default();
+    let mut settled = false;
+    for _ in 0..300 {
+        let erased: &mut dyn JigglyBoardDyn<Loc = usize, Dir = Dir> = &mut other;
+        let erased_settled = erased.run_physics_observed(1.0 / 60.0, &physprop, &mut erased_stats);
+        let plain_settled = plain.run_physics_observed(1.0 / 60.0, &physprop, &mut plain_stats);
+        assert_eq!(plain_settled, erased_settled);
+        assert_eq!(plain.bits(), other.bits());
+        settled = plain_settled;
+    }
+    assert!(settled);
+    assert_eq!(plain_stats.landings, 12);
+    assert_eq!(plain_stats.propagations, erased_stats.propagations);
+}
+
+#[test]
+fn boxed_board_settles() {
+    let physprop = PhysicsProperties::default();
+    let mut boxed: Box<dyn JigglyBoardDyn<Loc = usize, Dir = Dir>> = Box::new(Board::dropped(4, 3));
+    assert!((0..300).any(|_| boxed.run_physics(1.0 / 60.0, &physprop)));
+}
+
+#[test]
+fn erased_board_forwards_the_hooks() {
+    let board = Board::dropped(2, 2);
+    let erased: &dyn JigglyBoardDyn<Loc = usize, Dir = Dir> = &board;
+    assert_eq!(erased.mass(3), board.mass(3));
+    assert_eq!(erased.loc_index(2), Some(2));
+    assert_eq!(
+        erased.apply_dir_to_loc(Dir::Right, 0, 1.0),
+        board.apply_dir_to_loc(Dir::Right, 0, 1.0)
+    );
+}
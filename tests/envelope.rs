@@ -0,0 +1,43 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// Seconds a slime poked hard takes to settle at `hz` steps a second.
+fn settle_time(envelope_mode: EnvelopeMode, hz: f32) -> f32 {
+    let physprop = PhysicsProperties {
+        envelope_mode,
+        // So damping itself doesn't depend on the frame rate
+        damping_mode: DampingMode::PerSecond,
+        jiggle_damp: 0.2,
+        ..PhysicsProperties::default()
+    };
+    let dt = 1.0 / hz;
+    let mut board = DenseGridBoard::new(1, 1);
+    board.set(0, 0, SlimeState::Settled);
+    board.impulse_signed((0, 0), 3.0, Cardinal4::Up, &physprop);
+    let mut elapsed = 0.0;
+    while !board.run_physics(dt, &physprop) {
+        elapsed += dt;
+        assert!(elapsed < 5.0, "never settled");
+    }
+    elapsed
+}
+
+const RATES: [f32; 4] = [30.0, 60.0, 120.0, 240.0];
+
+#[test]
+fn settling_takes_as_long_at_any_frame_rate() {
+    // Around when life runs out, a second in, to within the slowest rate's step
+    let times = RATES.map(|hz| settle_time(EnvelopeMode::Visual, hz));
+    for time in times {
+        assert!((time - 1.0).abs() <= 1.0 / 30.0 + 1e-4, "{times:?}");
+    }
+    assert!((times[0] - times[3]).abs() < 0.05, "{times:?}");
+}
+
+#[test]
+fn the_legacy_envelope_settles_sooner_the_faster_it_steps() {
+    let times = RATES.map(|hz| settle_time(EnvelopeMode::Legacy, hz));
+    assert!(times.windows(2).all(|w| w[1] < w[0]), "{times:?}");
+    assert!(times[0] - times[3] > 0.1, "{times:?}");
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+#[test]
+fn one_drop_lands_once_and_ripples_through_the_row() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(3, 2);
+    for x in 0..3 {
+        board.set(x, 0, SlimeState::Settled);
+    }
+    board.set(1, 1, SlimeState::falling(&physprop));
+    board.cell_mut(1, 1).unwrap().y_bottom = 3.0;
+    let mut events = Vec::new();
+    while !board.run_physics_with_events(1.0 / 60.0, &physprop, &mut events) {}
+    let landed: Vec<_> = events
+        .iter()
+        .filter_map(|event| match *event {
+            SlimeEvent::Landed { loc, impulse } => Some((loc, impulse)),
+            _ => None,
+        })
+        .collect();
+    // Two cells' fall
+    let impact = physprop.velocity_to_impact * (2.0 * physprop.gravity * 2.0).sqrt();
+    assert_eq!(landed.len(), 1);
+    assert_eq!(landed[0].0, (1, 1));
+    assert!((landed[0].1 - impact).abs() < 1e-4, "{landed:?}");
+    let impulsed: Vec<_> = events
+        .iter()
+        .filter_map(|event| match *event {
+            SlimeEvent::Impulsed { loc, impulse, dir } => Some((loc, impulse / impact, dir)),
+            _ => None,
+        })
+        .collect();
+    // Where it landed, the slime under it, then either side of that, losing half each hop
+    assert_eq!(
+        impulsed,
+        [
+            ((1, 1), 1.0, Cardinal4::Up),
+            ((1, 0), 0.5, Cardinal4::Up),
+            ((0, 0), 0.25, Cardinal4::Right),
+            ((2, 0), 0.25, Cardinal4::Left),
+        ]
+    );
+    // And each of them comes to rest once, after all that
+    let mut settled: Vec<_> = events
+        .iter()
+        .filter_map(|event| match *event {
+            SlimeEvent::SettledAt { loc } => Some(loc),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(events.len(), 9);
+    assert!(
+        events[5..]
+            .iter()
+            .all(|event| matches!(event, SlimeEvent::SettledAt { .. }))
+    );
+    settled.sort();
+    assert_eq!(settled, [(0, 0), (1, 0), (1, 1), (2, 0)]);
+}
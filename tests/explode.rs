@@ -0,0 +1,138 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// How hard the slime in `state` was hit, either way.
+fn hit(state: Option<&SlimeState>) -> f32 {
+    match state {
+        Some(SlimeState::Jiggling {
+            momentum,
+            momentum_x,
+            ..
+        }) => momentum.abs() + momentum_x.abs(),
+        _ => 0.0,
+    }
+}
+
+#[test]
+fn blast_halves_with_every_step_away() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(11, 11);
+    for x in 0..11 {
+        for y in 0..11 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board.explode_at((5, 5), 8.0, &physprop);
+    let center = hit(board.get(5, 5));
+    assert!(center > 0.0);
+    for x in 0..11usize {
+        for y in 0..11usize {
+            let hops = (x.abs_diff(5) + y.abs_diff(5)) as u32;
+            let want = center * 0.5f32.powi(hops as i32);
+            if want >= physprop.min_impactable_at(hops) * 1.0001 {
+                let got = hit(board.get(x, y));
+                assert!((got - want).abs() < 1e-4 * center, "{x} {y} {got} {want}");
+            }
+        }
+    }
+    assert_eq!(hit(board.get(0, 0)), 0.0);
+}
+
+#[test]
+fn frozen_slimes_stop_the_blast() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(7, 1);
+    for x in 0..7 {
+        board.set(x, 0, SlimeState::Settled);
+    }
+    board.set(4, 0, SlimeState::Frozen);
+    board.explode_at((2, 0), 8.0, &physprop);
+    assert!(hit(board.get(3, 0)) > 0.0);
+    assert_eq!(hit(board.get(5, 0)), 0.0);
+}
+
+/// A [`DenseGridBoard`] without `loc_index`, where column `goo`, if any, soaks up every jiggle.
+struct Unindexed {
+    grid: DenseGridBoard,
+    goo: Option<usize>,
+}
+
+impl Unindexed {
+    fn filled(width: usize, height: usize, goo: Option<usize>) -> Self {
+        let mut grid = DenseGridBoard::new(width, height);
+        for x in 0..width {
+            for y in 0..height {
+                grid.set(x, y, SlimeState::Settled);
+            }
+        }
+        Unindexed { grid, goo }
+    }
+}
+
+impl JigglyBoard for Unindexed {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.grid.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.grid.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.grid.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.grid.impulse_jiggle_with(loc, f)
+    }
+    fn cell_material(&self, (x, _): (usize, usize)) -> Material {
+        Material {
+            absorb: if Some(x) == self.goo { 1.0 } else { 0.0 },
+            amplify: 0.0,
+        }
+    }
+}
+
+#[test]
+fn boards_without_loc_index_are_hit_once() {
+    let physprop = PhysicsProperties::default();
+    let mut indexed = Unindexed::filled(9, 9, None).grid;
+    let mut unindexed = Unindexed::filled(9, 9, None);
+    indexed.explode_at((4, 4), 8.0, &physprop);
+    unindexed.explode_at((4, 4), 8.0, &physprop);
+    assert_eq!(indexed.snapshot(), unindexed.grid.snapshot());
+}
+
+#[test]
+fn materials_and_transfer_shape_the_blast() {
+    let physprop = PhysicsProperties::default();
+    let mut board = Unindexed::filled(7, 1, Some(3));
+    board.explode_at((1, 0), 8.0, &physprop);
+    assert!(hit(board.grid.get(2, 0)) > 0.0);
+    assert_eq!(hit(board.grid.get(3, 0)), 0.0);
+    assert_eq!(hit(board.grid.get(4, 0)), 0.0);
+
+    let upright = PhysicsProperties {
+        transfer_horizontal: 0.0,
+        ..PhysicsProperties::default()
+    };
+    let mut board = Unindexed::filled(5, 5, None);
+    board.explode_at((2, 2), 8.0, &upright);
+    assert!(hit(board.grid.get(2, 0)) > 0.0);
+    assert!(hit(board.grid.get(2, 4)) > 0.0);
+    assert_eq!(hit(board.grid.get(1, 2)), 0.0);
+    assert_eq!(hit(board.grid.get(3, 3)), 0.0);
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+const DT: f32 = 1.0 / 240.0;
+
+/// Seconds from letting go of a slime four cells above another to it landing on it.
+fn time_to_land(physprop: &PhysicsProperties) -> f32 {
+    let mut board = DenseGridBoard::new(1, 2);
+    board.set(0, 0, SlimeState::Settled);
+    board.set(0, 1, SlimeState::falling(physprop));
+    board.cell_mut(0, 1).unwrap().y_bottom = 5.0;
+    let mut elapsed = 0.0;
+    while !matches!(board.get(0, 1), Some(SlimeState::Jiggling { .. })) {
+        if let Some(SlimeState::AboutToFall { .. }) = board.get(0, 1) {
+            // Hangs exactly where it was
+            assert_eq!(board.cell(0, 1).unwrap().y_bottom, 5.0);
+        }
+        board.run_physics(DT, physprop);
+        elapsed += DT;
+        assert!(elapsed < 5.0, "never landed");
+    }
+    elapsed
+}
+
+#[test]
+fn a_delayed_fall_takes_the_delay_longer() {
+    let physprop = PhysicsProperties::default();
+    let falling = time_to_land(&physprop);
+    // Four cells from a standstill
+    let expected = (2.0 * 4.0 / physprop.gravity).sqrt();
+    assert!(
+        (falling - expected).abs() <= DT,
+        "{falling} against {expected}"
+    );
+    for fall_delay in [0.1, 0.25, 0.5] {
+        let delayed = time_to_land(&PhysicsProperties {
+            fall_delay,
+            ..physprop
+        });
+        assert!(
+            (delayed - (fall_delay + falling)).abs() <= DT * 1.5,
+            "{delayed} against {fall_delay} + {falling}"
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use jiggly_fever::*;
+
+fn sweep() -> impl Iterator<Item = f32> {
+    (0..=100).map(|i| i as f32 * 0.5)
+}
+
+#[test]
+fn linear_default_matches_the_old_constants() {
+    let stretch = FallStretch::DEFAULT;
+    assert_eq!(FallStretch::<f32>::default(), stretch);
+    for velocity in sweep() {
+        let old = (velocity * (1.0 / 9.0) + 1.0).clamp(1.0, 2.0);
+        assert_eq!(stretch.y_scale(velocity), old, "at {velocity}");
+    }
+    assert_eq!(stretch.y_scale(4.5), 1.5);
+    assert_eq!(stretch.y_scale(9.0), 2.0);
+    assert_eq!(stretch.y_scale(50.0), 2.0);
+}
+
+#[test]
+fn linear_follows_its_slope_up_to_max() {
+    let stretch = FallStretch::Linear {
+        per_velocity: 0.05,
+        max: 1.6,
+    };
+    for velocity in sweep() {
+        let expected = (1.0 + velocity * 0.05).min(1.6);
+        assert!(
+            (stretch.y_scale(velocity) - expected).abs() < 1e-6,
+            "at {velocity}"
+        );
+    }
+}
+
+#[test]
+fn smooth_step_eases_in_and_out() {
+    let stretch = FallStretch::SmoothStep {
+        full_velocity: 20.0,
+        max: 1.8,
+    };
+    let mut last = 0.0;
+    for velocity in sweep() {
+        let t = (velocity / 20.0).clamp(0.0, 1.0);
+        let expected = 1.0 + t * t * (3.0 - 2.0 * t) * 0.8;
+        let y_scale = stretch.y_scale(velocity);
+        assert!((y_scale - expected).abs() < 1e-6, "at {velocity}");
+        assert!(y_scale >= last, "at {velocity}");
+        last = y_scale;
+    }
+    assert_eq!(stretch.y_scale(0.0), 1.0);
+    assert_eq!(stretch.y_scale(10.0), 1.4);
+    assert_eq!(stretch.y_scale(20.0), 1.8);
+    assert_eq!(stretch.y_scale(50.0), 1.8);
+    // Flat at both ends, steepest in the middle
+    let slope = |v: f32| stretch.y_scale(v + 0.5) - stretch.y_scale(v);
+    assert!(slope(0.0) < slope(9.75) / 10.0);
+    assert!(slope(19.5) < slope(9.75) / 10.0);
+}
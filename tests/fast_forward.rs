@@ -0,0 +1,72 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A slime dropped from four cells up onto a row of three.
+fn dropping() -> DenseGridBoard {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(3, 2);
+    for x in 0..3 {
+        board.set(x, 0, SlimeState::Settled);
+    }
+    board.set(1, 1, SlimeState::falling(&physprop));
+    board.cell_mut(1, 1).unwrap().y_bottom = 5.0;
+    board
+}
+
+#[test]
+fn fast_forwarding_matches_stepping() {
+    let physprop = PhysicsProperties::default();
+    let dt = 1.0 / 60.0;
+    let mut fast = dropping();
+    let outcome = fast.run_physics_steps(600, dt, &physprop);
+    assert!(outcome.settled);
+    assert!(outcome.steps < 600, "{outcome:?}");
+    let mut slow = dropping();
+    let steps = (1..=600).find(|_| slow.run_physics(dt, &physprop)).unwrap();
+    assert_eq!(outcome.steps, steps);
+    assert_eq!(fast.snapshot(), slow.snapshot());
+}
+
+#[test]
+fn running_out_of_steps_stops_unsettled() {
+    let physprop = PhysicsProperties::default();
+    let dt = 1.0 / 60.0;
+    let mut fast = dropping();
+    let outcome = fast.run_physics_steps(5, dt, &physprop);
+    assert_eq!(
+        outcome,
+        SettleOutcome {
+            steps: 5,
+            settled: false
+        }
+    );
+    let mut slow = dropping();
+    for _ in 0..5 {
+        slow.run_physics(dt, &physprop);
+    }
+    assert_eq!(fast.snapshot(), slow.snapshot());
+}
+
+#[test]
+fn no_steps_just_says_whether_it_is_at_rest() {
+    let physprop = PhysicsProperties::default();
+    let mut board = dropping();
+    let before = board.snapshot();
+    assert_eq!(
+        board.run_physics_steps(0, 1.0 / 60.0, &physprop),
+        SettleOutcome {
+            steps: 0,
+            settled: false
+        }
+    );
+    assert_eq!(board.snapshot(), before);
+    board.remove(1, 1);
+    assert_eq!(
+        board.run_physics_steps(0, 1.0 / 60.0, &physprop),
+        SettleOutcome {
+            steps: 0,
+            settled: true
+        }
+    );
+}
@@ -0,0 +1,152 @@
+use jiggly_fever::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction for Dir {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        [Dir::Up, Dir::Down, Dir::Left, Dir::Right]
+            .into_iter()
+            .filter(move |&dir| dir != self)
+    }
+    fn opposite(self) -> Self {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+            Dir::Right => Dir::Left,
+        }
+    }
+    const UP: Self = Dir::Up;
+    const RIGHT: Option<Self> = Some(Dir::Right);
+}
+
+/// A `width` by `height` board, row by row, bottom first.
+struct Grid<S> {
+    width: usize,
+    height: usize,
+    states: Vec<SlimeState<S>>,
+    y_bottoms: Vec<S>,
+}
+
+impl<S: Scalar> Grid<S> {
+    /// Everything dropped from a couple of cells up, staggered so they land at different times.
+    fn dropped(width: usize, height: usize) -> Self {
+        Grid {
+            width,
+            height,
+            states: (0..width * height)
+                .map(|_| SlimeState::Falling {
+                    velocity: S::ZERO,
+                    fallen: S::ZERO,
+                })
+                .collect(),
+            y_bottoms: (0..width * height)
+                .map(|i| S::from_f64((i / width) as f64 + 2.0 + (i % width) as f64 * 0.7))
+                .collect(),
+        }
+    }
+}
+
+impl<S: Scalar> JigglyBoard<S> for Grid<S> {
+    type Dir = Dir;
+    type Loc = usize;
+    fn apply_dir_to_loc(&self, dir: Dir, loc: usize, impulse: S) -> Option<(usize, S)> {
+        let (x, y) = (loc % self.width, loc / self.width);
+        let to = match dir {
+            Dir::Left if x > 0 => loc - 1,
+            Dir::Right if x + 1 < self.width => loc + 1,
+            Dir::Up if y + 1 < self.height => loc + self.width,
+            Dir::Down if y > 0 => loc - self.width,
+            _ => return None,
+        };
+        if matches!(self.states[to], SlimeState::Falling { .. }) {
+            return None;
+        }
+        Some((to, impulse * S::from_f64(0.5)))
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = usize>> {
+        let (width, height) = (self.width, self.height);
+        (0..width).map(move |x| (0..height).map(move |y| x + y * width))
+    }
+    fn mut_slime_with(&mut self, loc: usize, f: impl FnOnce(SlimePropsIn<S>) -> SlimePropsOut<S>) {
+        let state = std::mem::replace(&mut self.states[loc], SlimeState::Settled);
+        let out = f(SlimePropsIn {
+            state,
+            y_bottom: self.y_bottoms[loc],
+        });
+        self.states[loc] = out.state;
+        self.y_bottoms[loc] = out.y_bottom;
+    }
+    fn impulse_jiggle_with(&mut self, loc: usize, f: impl FnOnce(SlimeState<S>) -> SlimeState<S>) {
+        let state = std::mem::replace(&mut self.states[loc], SlimeState::Settled);
+        self.states[loc] = f(state);
+    }
+}
+
+/// Drop a 4x3 board and run it for `steps`, poking it again part way through, returning where
+/// everything ended up and which step it first settled on.
+fn run<S: Scalar>(steps: u32) -> (Vec<S>, Option<u32>) {
+    let mut grid = Grid::<S>::dropped(4, 3);
+    let physprop = PhysicsProperties::gelatin().cast::<S>();
+    let mut settled = None;
+    for step in 0..steps {
+        if grid.run_physics(S::from_f64(1.0 / 60.0), &physprop) && settled.is_none() {
+            settled = Some(step);
+        }
+        if step == 300 {
+            grid.propagate_jiggle(
+                JigglePropagation {
+                    at: 5,
+                    impulse: S::from_f64(3.0),
+                    came_from: Dir::Up,
+                    hops: 0,
+                },
+                &physprop,
+            );
+        }
+    }
+    (grid.y_bottoms, settled)
+}
+
+#[test]
+fn fixed_is_deterministic() {
+    let a = run::<Fixed>(1000);
+    let b = run::<Fixed>(1000);
+    assert_eq!(a, b);
+    assert!(a.1.is_some());
+}
+
+#[test]
+fn fixed_lands_close_to_f32() {
+    let (fixed, _) = run::<Fixed>(1000);
+    let (float, _) = run::<f32>(1000);
+    for (a, b) in fixed.iter().zip(&float) {
+        assert!((a.to_f64() - *b as f64).abs() < 1e-2, "{a} vs {b}");
+    }
+}
+
+#[test]
+fn fixed_math_is_close_to_f64() {
+    for x in [-3.0f64, -0.5, 0.0, 0.1, 0.5, 1.0, 2.0, 7.0] {
+        let fixed = Fixed::from_f64(x);
+        let close =
+            |got: Fixed, want: f64| (got.to_f64() - want).abs() <= 1e-3 * want.abs().max(1.0);
+        assert!(close(Scalar::exp(fixed), x.exp()), "exp {x}");
+        assert!(close(Scalar::sin(fixed), x.sin()), "sin {x}");
+        let magnitude = Fixed::from_f64(x.abs());
+        assert!(close(Scalar::sqrt(magnitude), x.abs().sqrt()), "sqrt {x}");
+        assert!(
+            close(
+                Scalar::powf(magnitude, Fixed::from_f64(0.37)),
+                x.abs().powf(0.37)
+            ),
+            "powf {x}"
+        );
+    }
+}
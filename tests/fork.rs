@@ -0,0 +1,101 @@
+use jiggly_fever::*;
+
+/// Three slimes in a row, then a fork: right of the third are two branches of three, `3..6` and
+/// `6..9`, sharing out what reaches the fork between them. Each slime sits on the ground on its own.
+struct Fork {
+    cells: [SlimeState; 9],
+    /// What each branch gets of an impulse going right from the fork.
+    shares: (f32, f32),
+}
+
+impl Fork {
+    fn new(shares: (f32, f32)) -> Self {
+        Fork {
+            cells: [SlimeState::Settled; 9],
+            shares,
+        }
+    }
+}
+
+impl JigglyBoard for Fork {
+    type Dir = Cardinal4;
+    type Loc = usize;
+    fn apply_dir_to_loc(&self, dir: Cardinal4, at: usize, impulse: f32) -> Option<(usize, f32)> {
+        match (dir, at) {
+            (Cardinal4::Right, 2) => Some((3, impulse * self.shares.0)),
+            (Cardinal4::Right, 5 | 8) | (Cardinal4::Left, 0) => None,
+            (Cardinal4::Right, _) => Some((at + 1, impulse)),
+            (Cardinal4::Left, 3 | 6) => Some((2, impulse)),
+            (Cardinal4::Left, _) => Some((at - 1, impulse)),
+            (Cardinal4::Up | Cardinal4::Down, _) => None,
+        }
+    }
+    fn apply_dir_to_loc_multi(
+        &self,
+        dir: Cardinal4,
+        at: usize,
+        impulse: f32,
+        out: &mut impl Extend<(usize, f32)>,
+    ) {
+        out.extend(self.apply_dir_to_loc(dir, at, impulse));
+        if (dir, at) == (Cardinal4::Right, 2) {
+            out.extend([(6, impulse * self.shares.1)]);
+        }
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = usize>> {
+        (0..9).map(core::iter::once)
+    }
+    fn mut_slime_with(&mut self, at: usize, f: impl FnOnce(SlimePropsIn) -> SlimePropsOut) {
+        let out = f(SlimePropsIn {
+            state: self.cells[at],
+            y_bottom: 0.0,
+        });
+        self.cells[at] = out.state;
+    }
+    fn impulse_jiggle_with(&mut self, at: usize, f: impl FnOnce(SlimeState) -> SlimeState) {
+        self.cells[at] = f(self.cells[at]);
+    }
+}
+
+/// Tap the slime at the start of the row, sending a wave off to the right, and say what reached where.
+fn tap(board: &mut Fork, impulse: f32, physprop: &PhysicsProperties) -> [Option<f32>; 9] {
+    let mut reached = [None; 9];
+    board.propagate_jiggle_with_listener(
+        JigglePropagation {
+            at: 0,
+            impulse,
+            came_from: Cardinal4::Left,
+            hops: 0,
+        },
+        physprop,
+        &mut |event| reached[event.loc] = Some(event.impulse),
+    );
+    reached
+}
+
+#[test]
+fn one_wave_goes_down_both_branches() {
+    let physprop = PhysicsProperties::default();
+    let mut board = Fork::new((0.5, 0.25));
+    let reached = tap(&mut board, 4.0, &physprop);
+    assert_eq!(
+        reached,
+        [4.0, 4.0, 4.0, 2.0, 2.0, 2.0, 1.0, 1.0, 1.0].map(Some)
+    );
+    assert!(
+        board
+            .cells
+            .iter()
+            .all(|state| matches!(state, SlimeState::Jiggling { .. }))
+    );
+}
+
+#[test]
+fn a_branch_too_weak_to_shake_is_dropped() {
+    let physprop = PhysicsProperties::default();
+    let mut board = Fork::new((1.0, physprop.min_impactable / 8.0));
+    let reached = tap(&mut board, 4.0, &physprop);
+    assert!(reached[..6].iter().all(Option::is_some), "{reached:?}");
+    assert!(reached[6..].iter().all(Option::is_none), "{reached:?}");
+    assert_eq!(board.cells[6..], [SlimeState::Settled; 3]);
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+#[test]
+fn frozen_slimes_bank_what_reaches_them_until_unfrozen() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(4, 6);
+    for x in 0..4 {
+        for y in 0..3 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    let region: Vec<_> = (2..4).flat_map(|x| (0..3).map(move |y| (x, y))).collect();
+    board.freeze_region(region.iter().copied());
+    board.set(1, 3, SlimeState::falling(&physprop));
+    board.cell_mut(1, 3).unwrap().y_bottom = 5.0;
+    for _ in 0..200 {
+        board.run_physics(1.0 / 60.0, &physprop);
+        for &(x, y) in &region {
+            let cell = board.cell(x, y).unwrap();
+            assert!(matches!(cell.state, SlimeState::Held { .. }));
+            assert_eq!(cell.render.y_scale, 1.0);
+            assert_eq!(cell.render.x_offset, 0.0);
+        }
+    }
+    let banked: f32 = region
+        .iter()
+        .map(|&(x, y)| match board.get(x, y) {
+            Some(SlimeState::Held { buffered_impulse }) => buffered_impulse.abs(),
+            _ => 0.0,
+        })
+        .sum();
+    assert!(banked > 0.0);
+    board.unfreeze_region(region.iter().copied(), &physprop);
+    assert!(matches!(
+        board.get(2, 0),
+        Some(SlimeState::Jiggling { momentum, .. }) if *momentum != 0.0
+    ));
+    assert!((0..3600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+}
+
+#[test]
+fn a_frozen_region_counts_as_at_rest() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(3, 4);
+    for x in 0..3 {
+        for y in 0..2 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    let region = [(2, 0), (2, 1)];
+    board.freeze_region(region);
+    assert!(board.is_at_rest() && board.is_settled());
+    assert!(board.run_physics(1.0 / 60.0, &physprop));
+    // A landing next door still has to die down, and settles with the region still held
+    board.set(0, 2, SlimeState::falling(&physprop));
+    board.cell_mut(0, 2).unwrap().y_bottom = 3.0;
+    assert!(!board.run_physics(1.0 / 60.0, &physprop));
+    assert!((0..3600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+    assert!(board.is_at_rest() && board.is_settled());
+    for (x, y) in region {
+        assert!(matches!(board.get(x, y), Some(SlimeState::Held { .. })));
+    }
+    // Whatever it banked only gets going once it's unfrozen
+    board.unfreeze_region(region, &physprop);
+    assert!(!board.is_at_rest());
+    assert!(!board.run_physics(1.0 / 60.0, &physprop));
+    assert!((0..3600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+}
+
+/// Whether the far end of a row of three ever jiggles when the near end is poked, with `middle` between.
+fn far_end_jiggles(middle: SlimeState) -> bool {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(3, 1);
+    board.transfer_horizontal = 1.0;
+    board.set(0, 0, SlimeState::Settled);
+    board.set(1, 0, middle);
+    board.set(2, 0, SlimeState::Settled);
+    board.impulse_signed((0, 0), 3.0, Cardinal4::Up, &physprop);
+    let mut jiggled = false;
+    for _ in 0..600 {
+        jiggled |= matches!(board.get(2, 0), Some(SlimeState::Jiggling { .. }));
+        if middle == SlimeState::Frozen {
+            assert_eq!(board.get(1, 0), Some(&SlimeState::Frozen));
+            assert_eq!(board.cell(1, 0).unwrap().render.y_scale, 1.0);
+        }
+        board.run_physics(1.0 / 60.0, &physprop);
+    }
+    assert!(board.is_settled());
+    jiggled
+}
+
+#[test]
+fn a_frozen_slime_stops_the_wave() {
+    assert!(far_end_jiggles(SlimeState::Settled));
+    assert!(!far_end_jiggles(SlimeState::Frozen));
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A [`DenseGridBoard`] with a cell of empty space under the third slime up each column.
+struct Holey(DenseGridBoard);
+
+impl JigglyBoard for Holey {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.0.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.0.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.0.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.0.impulse_jiggle_with(loc, f)
+    }
+    fn gap_before(&self, (_, y): (usize, usize)) -> f32 {
+        if y == 2 { 1.0 } else { 0.0 }
+    }
+}
+
+fn dropped() -> Holey {
+    let physprop = PhysicsProperties::default();
+    let mut grid = DenseGridBoard::new(1, 6);
+    for y in 0..3 {
+        grid.set(0, y, SlimeState::Settled);
+    }
+    grid.set(0, 3, SlimeState::falling(&physprop));
+    grid.cell_mut(0, 3).unwrap().y_bottom = 8.0;
+    Holey(grid)
+}
+
+#[test]
+fn slimes_stack_over_the_gap() {
+    let physprop = PhysicsProperties::default();
+    let mut board = dropped();
+    assert!((0..3600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+    for (y, want) in [0.0, 1.0, 3.0, 4.0].into_iter().enumerate() {
+        let got = board.0.cell(0, y).unwrap().y_bottom;
+        assert!((got - want).abs() < 1e-3, "{y} at {got}");
+    }
+}
+
+#[test]
+fn erased_boards_keep_the_gap() {
+    let physprop = PhysicsProperties::default();
+    let mut board: Box<dyn JigglyBoardDyn<f32, Loc = (usize, usize), Dir = Cardinal4>> =
+        Box::new(dropped());
+    assert_eq!(board.gap_before((0, 2)), 1.0);
+    assert!((0..3600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A grid with some of its cells glued into rigid blocks.
+struct Blocky {
+    grid: DenseGridBoard,
+    groups: Vec<((usize, usize), GroupId)>,
+}
+
+impl JigglyBoard for Blocky {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.grid.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.grid.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.grid.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.grid.impulse_jiggle_with(loc, f)
+    }
+    fn peek_slime<R>(
+        &self,
+        loc: (usize, usize),
+        f: impl FnOnce(&SlimeState, f32) -> R,
+    ) -> Option<R> {
+        self.grid.peek_slime(loc, f)
+    }
+    fn group_of(&self, loc: (usize, usize)) -> Option<GroupId> {
+        self.groups
+            .iter()
+            .find(|(at, _)| *at == loc)
+            .map(|(_, id)| *id)
+    }
+}
+
+#[test]
+fn a_block_over_an_uneven_floor_rests_on_the_taller_column() {
+    let physprop = PhysicsProperties::default();
+    let mut grid = DenseGridBoard::new(2, 4);
+    // Two high on the left, one on the right
+    grid.set(0, 0, SlimeState::Settled);
+    grid.set(0, 1, SlimeState::Settled);
+    grid.set(1, 0, SlimeState::Settled);
+    // The block's bottom left and right are the third and second slimes up their columns
+    let block = [(0, 2), (0, 3), (1, 1), (1, 2)];
+    for (x, y) in block {
+        grid.set(x, y, SlimeState::falling(&physprop));
+    }
+    for x in 0..2 {
+        let bottom = if x == 0 { 2 } else { 1 };
+        grid.cell_mut(x, bottom).unwrap().y_bottom = 6.0;
+        grid.cell_mut(x, bottom + 1).unwrap().y_bottom = 7.0;
+    }
+    let mut board = Blocky {
+        grid,
+        groups: block.map(|loc| (loc, GroupId(1))).to_vec(),
+    };
+    let mut steps = 0;
+    while !board.run_physics(1.0 / 60.0, &physprop) {
+        // Never tears apart: each row of the block stays level
+        let y = |loc: (usize, usize)| board.grid.cell(loc.0, loc.1).unwrap().y_bottom;
+        assert!((y((0, 2)) - y((1, 1))).abs() < 1e-5, "step {steps}");
+        assert!((y((0, 3)) - y((1, 2))).abs() < 1e-5, "step {steps}");
+        steps += 1;
+        assert!(steps < 1200, "never settled");
+    }
+    for (x, y) in block {
+        assert_eq!(board.grid.get(x, y), Some(&SlimeState::Settled));
+    }
+    // On top of the left column, leaving a cell of air over the right one
+    for (loc, y_bottom) in [((0, 2), 2.0), ((0, 3), 3.0), ((1, 1), 2.0), ((1, 2), 3.0)] {
+        let cell = board.grid.cell(loc.0, loc.1).unwrap();
+        assert!(
+            (cell.y_bottom - y_bottom).abs() < 1e-4,
+            "{loc:?}: {}",
+            cell.y_bottom
+        );
+    }
+    assert_eq!(board.grid.cell(1, 0).unwrap().y_bottom, 0.0);
+}
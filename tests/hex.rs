@@ -0,0 +1,87 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// Remembers where impulses arrived, how hard and from which way.
+struct Impulses<'a>(&'a mut Vec<((i32, i32), f32, HexDirection)>);
+
+impl PhysicsObserver<(i32, i32), HexDirection> for Impulses<'_> {
+    fn impulsed(&mut self, at: (i32, i32), impulse: f32, came_from: HexDirection) {
+        self.0.push((at, impulse, came_from));
+    }
+}
+
+/// Three columns, three slimes deep.
+fn board() -> HexBoard {
+    let mut board = HexBoard::new(3, 4);
+    for x in 0..3 {
+        for y in 0..3 {
+            board.set(HexBoard::<f32>::from_offset(x, y), SlimeState::Settled);
+        }
+    }
+    board
+}
+
+#[test]
+fn offset_coordinates_round_trip() {
+    let board = board();
+    for x in 0..3 {
+        for y in 0..4 {
+            let loc = HexBoard::<f32>::from_offset(x, y);
+            assert_eq!(board.to_offset(loc), Some((x, y)));
+        }
+    }
+    assert_eq!(board.to_offset((3, 0)), None);
+}
+
+#[test]
+fn landing_reaches_the_five_other_neighbours_once() {
+    let physprop = PhysicsProperties {
+        cycle_guard: CycleGuard::VisitOnce,
+        propagation_order: PropagationOrder::BreadthFirst,
+        ..PhysicsProperties::default()
+    };
+    let mut board = board();
+    let centre = HexBoard::<f32>::from_offset(1, 1);
+    let mut impulses = Vec::new();
+    board.propagate_jiggle_observed(
+        JigglePropagation {
+            at: centre,
+            impulse: 1.0,
+            came_from: HexDirection::Up,
+            hops: 0,
+        },
+        &physprop,
+        &mut Impulses(&mut impulses),
+    );
+    let mut reached: Vec<_> = impulses.iter().map(|&(at, ..)| at).collect();
+    reached.sort();
+    reached.dedup();
+    assert_eq!(reached.len(), impulses.len(), "a slime was hit twice");
+    let (q, r) = centre;
+    let neighbours: Vec<_> = HexDirection::Up
+        .other_directions()
+        .map(|dir| {
+            let (dq, dr) = dir.axial_offset();
+            ((q + dq, r + dr), dir.opposite())
+        })
+        .collect();
+    assert!(neighbours.iter().all(|&(at, _)| board.get(at).is_some()));
+    // Breadth first, the neighbours are the first reached after the slime that landed.
+    let first_hop: Vec<_> = impulses[1..6]
+        .iter()
+        .map(|&(at, _, came_from)| (at, came_from))
+        .collect();
+    assert_eq!(first_hop, neighbours);
+}
+
+#[test]
+fn every_direction_has_a_distinct_opposite() {
+    for dir in HexDirection::ALL {
+        assert_eq!(dir.opposite().opposite(), dir);
+        let (dq, dr) = dir.axial_offset();
+        assert_eq!(dir.opposite().axial_offset(), (-dq, -dr));
+        assert_eq!(dir.other_directions().count(), 5);
+        assert!(dir.other_directions().all(|other| other != dir));
+    }
+}
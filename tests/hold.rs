@@ -0,0 +1,75 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+#[test]
+fn a_held_slime_banks_what_reaches_it_and_jiggles_with_it_on_release() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(2, 1);
+    board.set(0, 0, SlimeState::Settled);
+    board.set(1, 0, SlimeState::Settled);
+    board.hold((1, 0));
+    board.impulse_signed((0, 0), 2.0, Cardinal4::Up, &physprop);
+    let Some(&SlimeState::Held { buffered_impulse }) = board.get(1, 0) else {
+        panic!("{:?}", board.get(1, 0));
+    };
+    assert!(buffered_impulse > 0.0);
+    for _ in 0..120 {
+        board.run_physics(1.0 / 60.0, &physprop);
+        assert_eq!(
+            board.get(1, 0),
+            Some(&SlimeState::Held { buffered_impulse })
+        );
+        assert_eq!(board.cell(1, 0).unwrap().render.y_scale, 1.0);
+    }
+    board.release((1, 0), true, &physprop);
+    assert_eq!(
+        board.get(1, 0),
+        Some(&SlimeState::Jiggling {
+            momentum: buffered_impulse,
+            offset: 0.0,
+            life: 1.0,
+            momentum_x: 0.0,
+            offset_x: 0.0,
+        })
+    );
+    assert!(!board.run_physics(1.0 / 60.0, &physprop));
+    assert!((0..600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+}
+
+#[test]
+fn a_held_slime_with_nothing_under_it_falls_on_release() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(2, 2);
+    for x in 0..2 {
+        board.set(x, 0, SlimeState::Settled);
+        board.set(x, 1, SlimeState::Settled);
+    }
+    board.hold((0, 1));
+    board.impulse_signed((1, 1), 2.0, Cardinal4::Up, &physprop);
+    // Take the slime out from under it; it hangs where it was held
+    board.remove(0, 0);
+    for _ in 0..60 {
+        board.run_physics(1.0 / 60.0, &physprop);
+        assert!(matches!(board.get(0, 0), Some(SlimeState::Held { .. })));
+        assert_eq!(board.cell(0, 0).unwrap().y_bottom, 1.0);
+    }
+    board.release((0, 0), false, &physprop);
+    // What it banked is dropped; it falls from a standstill
+    assert_eq!(
+        board.get(0, 0),
+        Some(&SlimeState::Falling {
+            velocity: 0.0,
+            fallen: 0.0,
+        })
+    );
+    let mut landed = false;
+    for _ in 0..600 {
+        board.run_physics(1.0 / 60.0, &physprop);
+        landed |= matches!(board.get(0, 0), Some(SlimeState::Jiggling { .. }));
+    }
+    assert!(landed);
+    assert!(board.is_settled());
+    assert_eq!(board.get(0, 0), Some(&SlimeState::Settled));
+    assert_eq!(board.cell(0, 0).unwrap().y_bottom, 0.0);
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// How many slimes of a 100 long row a poke at one end sets jiggling, with a wave that never fades.
+fn reached(max_propagation_hops: u32) -> usize {
+    let physprop = PhysicsProperties {
+        max_propagation_hops,
+        ..PhysicsProperties::default()
+    };
+    let mut board = DenseGridBoard::new(100, 1);
+    board.transfer_horizontal = 1.0;
+    for x in 0..100 {
+        board.set(x, 0, SlimeState::Settled);
+    }
+    board.impulse_signed((0, 0), 1.0, Cardinal4::Up, &physprop);
+    (0..100)
+        .filter(|&x| matches!(board.get(x, 0), Some(SlimeState::Jiggling { .. })))
+        .count()
+}
+
+#[test]
+fn a_wave_goes_as_many_hops_as_it_is_allowed() {
+    for hops in [0, 1, 2, 5, 40, 98] {
+        assert_eq!(reached(hops), hops as usize + 1, "{hops} hops");
+    }
+    assert_eq!(reached(u32::MAX), 100);
+}
+
+/// How many slimes of a 100 long row a poke at one end sets jiggling, losing a tenth at each hop.
+fn reached_fading(cutoff_growth: f32) -> usize {
+    let physprop = PhysicsProperties {
+        cutoff_growth,
+        ..PhysicsProperties::default()
+    };
+    let mut board = DenseGridBoard::new(100, 1);
+    board.transfer_horizontal = 0.9;
+    for x in 0..100 {
+        board.set(x, 0, SlimeState::Settled);
+    }
+    board.impulse_signed((0, 0), 5.0, Cardinal4::Up, &physprop);
+    let reached: Vec<usize> = (0..100)
+        .filter(|&x| matches!(board.get(x, 0), Some(SlimeState::Jiggling { .. })))
+        .collect();
+    // All in one run from the poke, whatever the cutoff
+    assert_eq!(reached, (0..reached.len()).collect::<Vec<_>>());
+    reached.len()
+}
+
+#[test]
+fn a_growing_cutoff_reaches_fewer_cells() {
+    let reach: Vec<usize> = [0.0, 0.05, 0.2, 1.0, 5.0]
+        .into_iter()
+        .map(reached_fading)
+        .collect();
+    assert!(reach.windows(2).all(|w| w[0] > w[1]), "{reach:?}");
+    // 5 * 0.9^hops stays over the flat 0.1 for 37 hops
+    assert_eq!(reach[0], 38);
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A grid that keeps the time for idle wobble.
+struct Breathing {
+    grid: DenseGridBoard,
+    clock: f32,
+}
+
+impl JigglyBoard for Breathing {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.grid.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.grid.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.grid.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.grid.impulse_jiggle_with(loc, f)
+    }
+    fn peek_slime<R>(
+        &self,
+        loc: (usize, usize),
+        f: impl FnOnce(&SlimeState, f32) -> R,
+    ) -> Option<R> {
+        self.grid.peek_slime(loc, f)
+    }
+    fn idle_clock(&self) -> f32 {
+        self.clock
+    }
+}
+
+#[test]
+fn settled_slimes_breathe_and_stay_settled() {
+    let amplitude = 0.02;
+    let physprop = PhysicsProperties {
+        idle_wobble_amplitude: amplitude,
+        idle_wobble_frequency: 0.5,
+        ..PhysicsProperties::default()
+    };
+    let mut board = Breathing {
+        grid: DenseGridBoard::new(3, 2),
+        clock: 0.0,
+    };
+    for x in 0..3 {
+        for y in 0..2 {
+            board.grid.set(x, y, SlimeState::Settled);
+        }
+    }
+    let dt = 1.0 / 60.0;
+    let (mut lowest, mut highest) = (f32::INFINITY, f32::NEG_INFINITY);
+    // Two whole breaths
+    for _ in 0..240 {
+        assert!(board.run_physics(dt, &physprop));
+        board.clock += dt;
+        for x in 0..3 {
+            for y in 0..2 {
+                let cell = board.grid.cell(x, y).unwrap();
+                let RenderProps {
+                    y_bottom,
+                    y_scale,
+                    x_scale,
+                    ..
+                } = cell.render;
+                assert_eq!(cell.state, SlimeState::Settled);
+                // Neighbours stack on a whole cell, however it's breathing
+                assert_eq!(y_bottom, y as f32);
+                assert!((y_scale - 1.0).abs() <= amplitude, "{y_scale}");
+                assert!((x_scale * y_scale - 1.0).abs() < 1e-6, "{x_scale}");
+                lowest = lowest.min(y_scale);
+                highest = highest.max(y_scale);
+            }
+        }
+    }
+    assert!(board.is_settled());
+    assert!(highest - lowest > amplitude, "{lowest}..{highest}");
+}
+
+#[test]
+fn no_amplitude_no_breathing() {
+    let mut board = DenseGridBoard::new(2, 1);
+    board.set(0, 0, SlimeState::Settled);
+    board.set(1, 0, SlimeState::Settled);
+    for _ in 0..60 {
+        assert!(board.run_physics(1.0 / 60.0, &PhysicsProperties::default()));
+        for x in 0..2 {
+            let render = board.cell(x, 0).unwrap().render;
+            assert_eq!((render.y_scale, render.x_scale), (1.0, 1.0));
+        }
+    }
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// Step `board` until it settles, panicking if it takes more than a minute.
+fn settle(board: &mut DenseGridBoard, physprop: &PhysicsProperties) {
+    if !(0..3600).any(|_| board.run_physics(1.0 / 60.0, physprop)) {
+        panic!("never settled");
+    }
+}
+
+#[test]
+fn rising_row_lifts_falling_slimes_too() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(3, 8);
+    for x in 0..3 {
+        for y in 0..2 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board.set(1, 2, SlimeState::falling(&physprop));
+    board.cell_mut(1, 2).unwrap().y_bottom = 6.0;
+    for _ in 0..5 {
+        board.run_physics(1.0 / 60.0, &physprop);
+    }
+    assert!(board.insert_bottom_row((0..3).map(|_| SlimeState::Settled), &physprop, 0.5));
+    assert!(matches!(board.get(1, 3), Some(SlimeState::Falling { .. })));
+    assert!(matches!(board.get(0, 0), Some(SlimeState::Jiggling { .. })));
+    settle(&mut board, &physprop);
+    for y in 0..4 {
+        let y_bottom = board.cell(1, y).unwrap().y_bottom;
+        assert!((y_bottom - y as f32).abs() < 1e-4, "{y} at {y_bottom}");
+    }
+}
+
+#[test]
+fn full_column_refuses_the_row() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::<f32>::new(2, 2);
+    board.set(0, 0, SlimeState::Settled);
+    board.set(0, 1, SlimeState::Settled);
+    assert!(!board.insert_bottom_row([SlimeState::Settled], &physprop, 0.5));
+    assert!(board.insert_bottom_row([], &physprop, 0.5));
+    assert!(board.get(1, 0).is_none());
+}
+
+#[test]
+fn layered_boards_take_the_row_layer_by_layer() {
+    let physprop = PhysicsProperties::default();
+    let mut board = LayeredGridBoard::<f32>::new(2, 3, 2);
+    assert!(board.insert_bottom_row((0..3).map(|_| SlimeState::Settled), &physprop, 0.0));
+    assert!(board.get((1, 0, 0)).is_some());
+    assert!(board.get((0, 0, 1)).is_some());
+    assert!(board.get((1, 0, 1)).is_none());
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "alloc")]
+
+use std::collections::HashMap;
+
+use jiggly_fever::*;
+
+/// A [`DenseGridBoard`] that keeps what `on_previous_output` tells it.
+struct Blended {
+    grid: DenseGridBoard,
+    prev: HashMap<(usize, usize), RenderProps>,
+}
+
+impl JigglyBoard for Blended {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.grid.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.grid.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.grid.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.grid.impulse_jiggle_with(loc, f)
+    }
+    fn loc_index(&self, loc: (usize, usize)) -> Option<usize> {
+        self.grid.loc_index(loc)
+    }
+    fn on_previous_output(&mut self, loc: (usize, usize), prev: RenderProps) {
+        self.prev.insert(loc, prev);
+    }
+}
+
+impl Blended {
+    /// A slime dropped onto another, with the one below already jiggling.
+    fn dropping() -> Self {
+        let physprop = PhysicsProperties::default();
+        let mut grid = DenseGridBoard::new(1, 2);
+        grid.set(0, 0, SlimeState::Settled);
+        grid.set(0, 1, SlimeState::falling(&physprop));
+        grid.cell_mut(0, 1).unwrap().y_bottom = 3.0;
+        grid.nudge((0, 0), 2.0, Cardinal4::Up, &physprop);
+        Blended {
+            grid,
+            prev: HashMap::new(),
+        }
+    }
+    fn renders(&self) -> [RenderProps; 2] {
+        [0, 1].map(|y| self.grid.cell(0, y).unwrap().render)
+    }
+}
+
+#[test]
+fn prev_is_the_last_step_and_current_is_this_one() {
+    let physprop = PhysicsProperties::default();
+    let dt = 1.0 / 30.0;
+    let mut board = Blended::dropping();
+    let mut scratch = PhysicsScratch::new().with_interpolation();
+    board.run_physics_with_scratch(dt, &physprop, &mut scratch, &mut ());
+    // Seen for the first time, so there's nothing to blend from yet
+    for (y, render) in board.renders().into_iter().enumerate() {
+        assert_eq!(board.prev[&(0, y)], render);
+    }
+    for step in 0..20 {
+        let before = board.renders();
+        board.run_physics_with_scratch(dt, &physprop, &mut scratch, &mut ());
+        let after = board.renders();
+        for (y, render) in before.iter().enumerate() {
+            assert_eq!(board.prev[&(0, y)], *render, "step {step}, slime {y}");
+        }
+        assert_ne!(before, after, "step {step}");
+        // Halfway between steps, the faller is drawn halfway between where the two steps put it
+        let halfway = before[1].lerp(after[1], 0.5);
+        assert!((halfway.y_bottom - (before[1].y_bottom + after[1].y_bottom) / 2.0).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn nothing_is_remembered_unless_asked() {
+    let physprop = PhysicsProperties::default();
+    let mut board = Blended::dropping();
+    let mut scratch = PhysicsScratch::new();
+    for _ in 0..20 {
+        board.run_physics_with_scratch(1.0 / 30.0, &physprop, &mut scratch, &mut ());
+    }
+    assert!(board.prev.is_empty());
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+fn jiggling(momentum: f32, offset_x: f32) -> SlimeState {
+    SlimeState::Jiggling {
+        momentum,
+        offset: 0.0,
+        life: 1.0,
+        momentum_x: 0.0,
+        offset_x,
+    }
+}
+
+#[test]
+fn physics_keeps_a_busy_board_clean() {
+    for physprop in [
+        PhysicsProperties::default(),
+        PhysicsProperties {
+            max_offset: 0.3,
+            ..PhysicsProperties::default()
+        },
+    ] {
+        let mut board = DenseGridBoard::new(5, 10);
+        for x in 0..5 {
+            for y in 0..3 {
+                board.set(x, y, SlimeState::Settled);
+            }
+        }
+        for (x, height) in [(1, 7.0), (2, 5.5), (4, 9.0)] {
+            for (y, above) in [(3, 0.0), (4, 1.0)] {
+                board.set(x, y, SlimeState::falling(&physprop));
+                board.cell_mut(x, y).unwrap().y_bottom = height + above;
+            }
+        }
+        board.set(
+            0,
+            3,
+            SlimeState::Launched {
+                velocity: 5.0,
+                height: 0.0,
+            },
+        );
+        board.set(0, 4, SlimeState::Settled);
+        board.squish((3, 2), 0.4);
+        board.impulse_signed((2, 1), -6.0, Cardinal4::Up, &physprop);
+        board.set(3, 3, SlimeState::Spawning { progress: 0.0 });
+        for step in 0..600 {
+            board.run_physics(1.0 / 60.0, &physprop);
+            if let Err(violations) = board.check_invariants(&physprop) {
+                panic!(
+                    "step {step}: {violations:?}\n{}",
+                    board.debug_dump_verbose()
+                );
+            }
+            if step == 100 {
+                board.unsquish((3, 2), &physprop);
+                board.remove_slime((2, 0));
+            }
+        }
+    }
+}
+
+#[test]
+fn corrupted_boards_are_caught() {
+    let physprop = PhysicsProperties {
+        max_offset: 0.5,
+        ..PhysicsProperties::default()
+    };
+    let mut board = DenseGridBoard::new(3, 5);
+    for x in 0..3 {
+        for y in 0..3 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    assert_eq!(board.check_invariants(&physprop), Ok(()));
+    board.cell_mut(1, 2).unwrap().y_bottom = 1.5;
+    assert_eq!(
+        board.check_invariants(&physprop),
+        Err(vec![InvariantViolation::Overlapping {
+            loc: (1, 2),
+            y_bottom: 1.5,
+            floor: 2.0
+        }])
+    );
+    board.cell_mut(1, 2).unwrap().y_bottom = 0.5;
+    assert_eq!(
+        board.check_invariants(&physprop).unwrap_err()[0],
+        InvariantViolation::OutOfOrder {
+            loc: (1, 2),
+            y_bottom: 0.5,
+            below: 1.0
+        }
+    );
+    board.cell_mut(1, 2).unwrap().y_bottom = 2.0;
+    board.set(2, 1, jiggling(f32::NAN, 0.0));
+    board.set(0, 0, jiggling(0.0, 0.8));
+    board.set(
+        0,
+        2,
+        SlimeState::Falling {
+            velocity: 1.0,
+            fallen: 0.0,
+        },
+    );
+    board.cell_mut(0, 2).unwrap().y_bottom = 1.2;
+    let violations = board.check_invariants(&physprop).unwrap_err();
+    assert_eq!(
+        violations,
+        [
+            InvariantViolation::OffsetOutOfRange {
+                loc: (0, 0),
+                offset: 0.8,
+                max: 0.5
+            },
+            InvariantViolation::BelowFloor {
+                loc: (0, 2),
+                y_bottom: 1.2,
+                floor: 2.0
+            },
+            InvariantViolation::NotFinite { loc: (2, 1) },
+        ]
+    );
+    assert_eq!(violations[2].loc(), (2, 1));
+}
+
+#[test]
+fn bouncing_on_a_hex_board_stays_clean() {
+    let physprop = PhysicsProperties {
+        restitution: 0.5,
+        bounce_min_velocity: 1.0,
+        ..PhysicsProperties::default()
+    };
+    let mut board = HexBoard::new(3, 8);
+    for x in 0..3 {
+        for y in 0..2 {
+            board.set(HexBoard::<f32>::from_offset(x, y), SlimeState::Settled);
+        }
+        for y in 2..4 {
+            board.set(
+                HexBoard::<f32>::from_offset(x, y),
+                SlimeState::falling(&physprop),
+            );
+        }
+    }
+    let mut settled = false;
+    for step in 0..2000 {
+        settled = board.run_physics(1.0 / 60.0, &physprop);
+        if let Err(violations) = board.check_invariants(&physprop) {
+            panic!(
+                "step {step}: {violations:?}\n{}",
+                board.debug_dump_verbose()
+            );
+        }
+        if settled {
+            break;
+        }
+    }
+    assert!(settled);
+}
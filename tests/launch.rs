@@ -0,0 +1,108 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// The impulse the slime at the bottom of a one-wide board sends out when it lands.
+fn landing(board: &mut DenseGridBoard, physprop: &PhysicsProperties) -> f32 {
+    let mut events = Vec::new();
+    for _ in 0..600 {
+        board.run_physics_with_events(1.0 / 240.0, physprop, &mut events);
+        if let Some(impulse) = events.iter().find_map(|event| match event {
+            SlimeEvent::Landed { impulse, .. } => Some(*impulse),
+            _ => None,
+        }) {
+            return impulse;
+        }
+    }
+    panic!("never landed");
+}
+
+fn launched(velocity: f32, physprop: &PhysicsProperties) -> f32 {
+    let mut board = DenseGridBoard::new(1, 1);
+    board.set(0, 0, SlimeState::Settled);
+    board.launch((0, 0), velocity);
+    landing(&mut board, physprop)
+}
+
+fn dropped(cells: f32, physprop: &PhysicsProperties) -> f32 {
+    let mut board = DenseGridBoard::new(1, 1);
+    board.set(0, 0, SlimeState::falling(physprop));
+    board.cell_mut(0, 0).unwrap().y_bottom = cells;
+    landing(&mut board, physprop)
+}
+
+#[test]
+fn launched_slimes_land_through_the_impact_mode() {
+    let velocity = 20.0;
+    for impact_mode in [
+        ImpactMode::Velocity,
+        ImpactMode::Distance { per_cell: 3.0 },
+        ImpactMode::Combined { per_cell: 3.0 },
+    ] {
+        let physprop = PhysicsProperties {
+            impact_mode,
+            ..PhysicsProperties::default()
+        };
+        // Up and back down again from v^2 / 2g
+        let apex = velocity * velocity / (2.0 * physprop.gravity);
+        let expected = impact_mode.impact(physprop.velocity_to_impact * velocity, apex);
+        let up_and_down = launched(velocity, &physprop);
+        let from_the_top = dropped(apex, &physprop);
+        for impulse in [up_and_down, from_the_top] {
+            assert!(
+                (impulse - expected).abs() < expected * 0.05,
+                "{impact_mode:?}: {impulse} rather than {expected}"
+            );
+        }
+    }
+}
+
+#[test]
+fn a_distance_impact_ignores_speed_either_way() {
+    let physprop = PhysicsProperties {
+        impact_mode: ImpactMode::Distance { per_cell: 3.0 },
+        velocity_to_impact: 100.0,
+        ..PhysicsProperties::default()
+    };
+    let apex = 100.0 / (2.0 * physprop.gravity);
+    for impulse in [launched(10.0, &physprop), dropped(apex, &physprop)] {
+        assert!(
+            (impulse - 3.0 * apex).abs() < 3.0 * apex * 0.05,
+            "{impulse}"
+        );
+    }
+}
+
+#[test]
+fn a_launch_peaks_at_v_squared_over_2g_and_comes_home() {
+    let physprop = PhysicsProperties::default();
+    for velocity in [5.0, 10.0, 20.0] {
+        let mut board = DenseGridBoard::new(1, 3);
+        for y in 0..3 {
+            board.set(0, y, SlimeState::Settled);
+        }
+        board.launch((0, 1), velocity);
+        let mut apex = 0.0f32;
+        let mut steps = 0;
+        while !board.run_physics(1.0 / 240.0, &physprop) {
+            if let Some(&SlimeState::Launched { height, .. }) = board.get(0, 1) {
+                apex = apex.max(height);
+                assert_eq!(board.cell(0, 1).unwrap().y_bottom, 1.0 + height);
+                // Its slot stays reserved, so the slime above doesn't drop into it
+                assert_eq!(board.cell(0, 2).unwrap().y_bottom, 2.0);
+            }
+            steps += 1;
+            assert!(steps < 2400, "never settled");
+        }
+        // To within how far it goes in a step at the speed it leaves at
+        let expected = velocity * velocity / (2.0 * physprop.gravity);
+        assert!(
+            (apex - expected).abs() <= velocity / 240.0,
+            "{velocity}: {apex} rather than {expected}"
+        );
+        for y in 0..3 {
+            assert_eq!(board.get(0, y), Some(&SlimeState::Settled));
+            assert_eq!(board.cell(0, y).unwrap().y_bottom, y as f32);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// How hard a slime is jiggling, if it is.
+fn wobble(state: Option<&SlimeState>) -> f32 {
+    match state {
+        Some(SlimeState::Jiggling {
+            momentum, offset, ..
+        }) => momentum.abs().max(offset.abs()),
+        _ => 0.0,
+    }
+}
+
+/// Drop a slime onto the front layer of two 3x2 layers, returning the hardest the slime under it and
+/// the one behind that jiggled.
+fn drop_in_front(transfer_layer: f32) -> (f32, f32) {
+    let physprop = PhysicsProperties::default();
+    let mut board = LayeredGridBoard::new(3, 4, 2);
+    board.transfer_layer = transfer_layer;
+    for layer in 0..2 {
+        let grid = board.layer_mut(layer).unwrap();
+        for x in 0..3 {
+            for y in 0..2 {
+                grid.set(x, y, SlimeState::Settled);
+            }
+        }
+    }
+    let front = board.layer_mut(0).unwrap();
+    front.set(1, 2, SlimeState::falling(&physprop));
+    front.cell_mut(1, 2).unwrap().y_bottom = 3.5;
+    let (mut under, mut behind) = (0f32, 0f32);
+    for _ in 0..300 {
+        board.run_physics(1.0 / 60.0, &physprop);
+        under = under.max(wobble(board.get((1, 1, 0))));
+        behind = behind.max(wobble(board.get((1, 1, 1))));
+    }
+    (under, behind)
+}
+
+#[test]
+fn landings_reach_the_next_layer_weaker() {
+    let (under, behind) = drop_in_front(0.5);
+    assert!(behind > 0.0 && behind < under, "{under} {behind}");
+}
+
+#[test]
+fn zero_transfer_keeps_layers_apart() {
+    let (under, behind) = drop_in_front(0.0);
+    assert!(under > 0.0);
+    assert_eq!(behind, 0.0);
+}
+
+#[test]
+fn layered_directions_add_front_and_back() {
+    let others: Vec<_> = Layered::<Cardinal4>::UP.other_directions().collect();
+    assert_eq!(others.len(), 5);
+    assert!(others.contains(&Layered::Front) && others.contains(&Layered::Back));
+    assert_eq!(Layered::<Cardinal4>::Front.opposite(), Layered::Back);
+}
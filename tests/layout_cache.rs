@@ -0,0 +1,118 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+#[derive(Clone, Copy, PartialEq)]
+struct Up;
+
+impl Direction for Up {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+    fn opposite(self) -> Self {
+        self
+    }
+    const UP: Self = Up;
+}
+
+/// One column of slimes, bottom first, keeping what each was last drawn with.
+struct Column {
+    states: Vec<Option<SlimeState>>,
+    y_bottoms: Vec<f32>,
+    render: Vec<RenderProps>,
+}
+
+impl JigglyBoard for Column {
+    type Dir = Up;
+    type Loc = usize;
+    fn apply_dir_to_loc(&self, _: Up, _: usize, _: f32) -> Option<(usize, f32)> {
+        None
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = usize>> {
+        core::iter::once(0..self.y_bottoms.len())
+    }
+    fn mut_slime_with(&mut self, loc: usize, f: impl FnOnce(SlimePropsIn) -> SlimePropsOut) {
+        let out = f(SlimePropsIn {
+            state: self.states[loc].take().unwrap(),
+            y_bottom: self.y_bottoms[loc],
+        });
+        self.render[loc] = RenderProps::from(&out);
+        self.states[loc] = Some(out.state);
+        self.y_bottoms[loc] = out.y_bottom;
+    }
+    fn impulse_jiggle_with(&mut self, loc: usize, f: impl FnOnce(SlimeState) -> SlimeState) {
+        self.states[loc] = Some(f(self.states[loc].take().unwrap()));
+    }
+}
+
+impl Column {
+    /// `n` slimes dropped from a cell or so above where they end up.
+    fn dropped(n: usize) -> Self {
+        let render = RenderProps {
+            y_bottom: 0.0,
+            y_scale: 1.0,
+            x_scale: 1.0,
+            x_offset: 0.0,
+        };
+        Column {
+            states: (0..n)
+                .map(|_| {
+                    Some(SlimeState::Falling {
+                        velocity: 0.0,
+                        fallen: 0.0,
+                    })
+                })
+                .collect(),
+            y_bottoms: (0..n).map(|i| i as f32 * 1.5 + 1.0).collect(),
+            render: vec![render; n],
+        }
+    }
+}
+
+#[test]
+fn cached_layout_steps_like_cols() {
+    let physprop = PhysicsProperties::default();
+    let (mut walked, mut cached) = (Column::dropped(5), Column::dropped(5));
+    let mut layout = BoardLayoutCache::new();
+    let (mut walked_scratch, mut cached_scratch) = (PhysicsScratch::new(), PhysicsScratch::new());
+    for step in 0..100 {
+        let a =
+            walked.run_physics_with_scratch(1.0 / 60.0, &physprop, &mut walked_scratch, &mut ());
+        let b = cached
+            .run_physics_cached(1.0 / 60.0, &physprop, &mut layout, &mut cached_scratch)
+            .unwrap();
+        assert_eq!(a, b, "step {step}");
+        assert_eq!(walked.y_bottoms, cached.y_bottoms, "step {step}");
+    }
+    assert!(layout.is_filled());
+}
+
+#[test]
+fn stale_layout_is_caught_until_invalidated() {
+    let physprop = PhysicsProperties::default();
+    let mut column = Column::dropped(5);
+    let mut layout = BoardLayoutCache::new();
+    let mut scratch = PhysicsScratch::new();
+    column
+        .run_physics_cached(1.0 / 60.0, &physprop, &mut layout, &mut scratch)
+        .unwrap();
+    column.states.push(Some(SlimeState::Settled));
+    column.y_bottoms.push(9.0);
+    column.render.push(column.render[0]);
+    let before = column.y_bottoms.clone();
+    assert_eq!(
+        column.run_physics_cached(1.0 / 60.0, &physprop, &mut layout, &mut scratch),
+        Err(StaleLayout::ColumnLength {
+            column: 0,
+            cached: 5,
+            actual: 6
+        })
+    );
+    assert_eq!(column.y_bottoms, before);
+    layout.invalidate();
+    assert!(
+        column
+            .run_physics_cached(1.0 / 60.0, &physprop, &mut layout, &mut scratch)
+            .is_ok()
+    );
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// Every `life` a slime poked hard goes through under `life_curve`, until it settles.
+fn lives(life_curve: LifeCurve) -> Vec<f32> {
+    let physprop = PhysicsProperties {
+        life_curve,
+        ..PhysicsProperties::default()
+    };
+    let mut board = DenseGridBoard::new(1, 1);
+    board.set(0, 0, SlimeState::Settled);
+    board.impulse_signed((0, 0), 3.0, Cardinal4::Up, &physprop);
+    let mut lives = Vec::new();
+    while let Some(&SlimeState::Jiggling { life, .. }) = board.get(0, 0) {
+        lives.push(life);
+        board.run_physics(1.0 / 60.0, &physprop);
+        assert!(lives.len() < 600, "{life_curve:?} never settled");
+    }
+    assert!(board.is_settled());
+    lives
+}
+
+#[test]
+fn every_curve_runs_down_and_settles() {
+    for curve in [
+        LifeCurve::Linear,
+        LifeCurve::Exponential,
+        LifeCurve::SmoothStep,
+    ] {
+        let lives = lives(curve);
+        assert!(lives.len() > 10, "{curve:?} settled straight away");
+        assert_eq!(lives[0], 1.0);
+        assert!(
+            lives.windows(2).all(|w| w[1] < w[0]),
+            "{curve:?}: {lives:?}"
+        );
+    }
+}
+
+#[test]
+fn exponential_life_keeps_the_same_ratio() {
+    let step = (-1.0f32 / 60.0).exp();
+    for w in lives(LifeCurve::Exponential).windows(2) {
+        assert!((w[1] / w[0] - step).abs() < 1e-5, "{w:?}");
+    }
+}
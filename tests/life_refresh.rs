@@ -0,0 +1,59 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// How many steps a jiggling slime keeps going while it's poked every fifth step, up to `limit`.
+fn poked_for(life_refresh: LifeRefresh, limit: usize) -> usize {
+    let physprop = PhysicsProperties {
+        life_refresh,
+        ..PhysicsProperties::default()
+    };
+    let mut board = DenseGridBoard::new(1, 1);
+    board.set(0, 0, SlimeState::Settled);
+    board.impulse_signed((0, 0), 1.0, Cardinal4::Up, &physprop);
+    for step in 0..limit {
+        if !matches!(board.get(0, 0), Some(SlimeState::Jiggling { .. })) {
+            return step;
+        }
+        if step % 5 == 4 {
+            board.impulse_signed((0, 0), 1.0, Cardinal4::Up, &physprop);
+        }
+        board.run_physics(1.0 / 60.0, &physprop);
+    }
+    limit
+}
+
+#[test]
+fn reset_keeps_a_poked_slime_going() {
+    assert_eq!(poked_for(LifeRefresh::Reset, 1200), 1200);
+}
+
+#[test]
+fn other_policies_let_a_poked_slime_settle() {
+    for life_refresh in [
+        LifeRefresh::Max { per_impulse: 0.1 },
+        LifeRefresh::Add {
+            per_impulse: 0.01,
+            cap: 1.0,
+        },
+    ] {
+        let steps = poked_for(life_refresh, 1200);
+        // A second and a bit of life, give or take what the pokes add back
+        assert!(steps < 120, "{life_refresh:?} took {steps}");
+    }
+}
+
+#[test]
+fn refresh_policies() {
+    assert_eq!(LifeRefresh::Reset.refresh(0.2, 0.1), 1.0);
+    let max = LifeRefresh::Max { per_impulse: 0.5 };
+    assert_eq!(max.refresh(0.2, 1.0), 0.5);
+    assert_eq!(max.refresh(0.7, 1.0), 0.7);
+    assert_eq!(max.refresh(0.2, 10.0), 1.0);
+    let add = LifeRefresh::Add {
+        per_impulse: 0.25,
+        cap: 0.8,
+    };
+    assert_eq!(add.refresh(0.2, 1.0), 0.45);
+    assert_eq!(add.refresh(0.7, 1.0), 0.8);
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+type Event = PropagationEvent<(usize, usize), Cardinal4>;
+
+/// Every event the listener is given for a slime dropped onto the middle of a row of three, until it
+/// settles, along with the step it came in.
+fn listen() -> Vec<(u32, Event)> {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(3, 2);
+    for x in 0..3 {
+        board.set(x, 0, SlimeState::Settled);
+    }
+    board.set(1, 1, SlimeState::falling(&physprop));
+    board.cell_mut(1, 1).unwrap().y_bottom = 3.0;
+    let mut heard = Vec::new();
+    let mut step = 0;
+    loop {
+        let settled = board.run_physics_with_listener(1.0 / 60.0, &physprop, &mut |event| {
+            heard.push((step, event));
+        });
+        if settled {
+            return heard;
+        }
+        step += 1;
+        assert!(step < 600, "never settled");
+    }
+}
+
+#[test]
+fn every_slime_the_wave_reaches_is_heard_once_in_order() {
+    let heard = listen();
+    assert_eq!(heard.len(), 4, "{heard:?}");
+    // All on the step it lands, and nothing while they jiggle down
+    assert!(heard.iter().all(|event| event.0 == heard[0].0));
+    // The landing slime, the one under it, then either side of that, losing half each hop
+    let impact = heard[0].1.impulse;
+    let heard: Vec<_> = heard
+        .iter()
+        .map(|(_, event)| {
+            (
+                event.loc,
+                event.impulse / impact,
+                event.came_from,
+                event.hops,
+            )
+        })
+        .collect();
+    assert_eq!(
+        heard,
+        [
+            ((1, 1), 1.0, Cardinal4::Up, 0),
+            ((1, 0), 0.5, Cardinal4::Up, 1),
+            ((0, 0), 0.25, Cardinal4::Right, 2),
+            ((2, 0), 0.25, Cardinal4::Left, 2),
+        ]
+    );
+}
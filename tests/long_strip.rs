@@ -0,0 +1,31 @@
+#![cfg(feature = "alloc")]
+
+use std::thread;
+
+use jiggly_fever::*;
+
+const LENGTH: usize = 10_000;
+
+#[test]
+fn a_wave_along_ten_thousand_slimes_fits_in_a_small_stack() {
+    // Far too little stack for a call per hop
+    let handle = thread::Builder::new()
+        .stack_size(128 * 1024)
+        .spawn(|| {
+            let physprop = PhysicsProperties::default();
+            let mut board = DenseGridBoard::new(LENGTH, 1);
+            board.transfer_horizontal = 1.0;
+            for x in 0..LENGTH {
+                board.set(x, 0, SlimeState::Settled);
+            }
+            board.impulse_signed((0, 0), 1.0, Cardinal4::Up, &physprop);
+            for _ in 0..10 {
+                board.run_physics(1.0 / 60.0, &physprop);
+            }
+            (0..LENGTH)
+                .filter(|&x| matches!(board.get(x, 0), Some(SlimeState::Jiggling { .. })))
+                .count()
+        })
+        .unwrap();
+    assert_eq!(handle.join().unwrap(), LENGTH);
+}
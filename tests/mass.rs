@@ -0,0 +1,82 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A grid with one slime four times heavier than the rest.
+struct Weighted {
+    grid: DenseGridBoard,
+    heavy: (usize, usize),
+}
+
+impl JigglyBoard for Weighted {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.grid.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.grid.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.grid.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.grid.impulse_jiggle_with(loc, f)
+    }
+    fn peek_slime<R>(
+        &self,
+        loc: (usize, usize),
+        f: impl FnOnce(&SlimeState, f32) -> R,
+    ) -> Option<R> {
+        self.grid.peek_slime(loc, f)
+    }
+    fn mass(&self, loc: (usize, usize)) -> f32 {
+        if loc == self.heavy { 4.0 } else { 1.0 }
+    }
+}
+
+/// How far the slime resting at (1, 1) gets squashed when the one at (0, 1) drops in next to it, with
+/// `heavy` the heavy one of the two.
+fn neighbour_squash(heavy: (usize, usize)) -> f32 {
+    let physprop = PhysicsProperties::default();
+    let mut board = Weighted {
+        grid: DenseGridBoard::new(2, 2),
+        heavy,
+    };
+    board.grid.set(0, 0, SlimeState::Settled);
+    board.grid.set(1, 0, SlimeState::Settled);
+    board.grid.set(1, 1, SlimeState::Settled);
+    board.grid.set(0, 1, SlimeState::falling(&physprop));
+    board.grid.cell_mut(0, 1).unwrap().y_bottom = 3.0;
+    let mut furthest = 0.0f32;
+    while !board.run_physics(1.0 / 60.0, &physprop) {
+        if let Some(&SlimeState::Jiggling { offset, .. }) = board.grid.get(1, 1) {
+            furthest = furthest.max(offset.abs());
+        }
+    }
+    furthest
+}
+
+#[test]
+fn heavy_landings_shake_light_neighbours() {
+    let heavy_on_light = neighbour_squash((0, 1));
+    let light_on_heavy = neighbour_squash((1, 1));
+    assert!(light_on_heavy > 0.0);
+    assert!(
+        heavy_on_light > light_on_heavy * 4.0,
+        "{heavy_on_light} against {light_on_heavy}"
+    );
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A [`DenseGridBoard`] where column `column`, if any, is made of `material`.
+struct Goo {
+    grid: DenseGridBoard,
+    column: Option<usize>,
+    material: Material,
+}
+
+impl JigglyBoard for Goo {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.grid.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.grid.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.grid.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.grid.impulse_jiggle_with(loc, f)
+    }
+    fn loc_index(&self, loc: (usize, usize)) -> Option<usize> {
+        self.grid.loc_index(loc)
+    }
+    fn cell_material(&self, (x, _): (usize, usize)) -> Material {
+        if Some(x) == self.column {
+            self.material
+        } else {
+            Material::default()
+        }
+    }
+}
+
+/// How hard each slime in a row of twenty is jiggling after a shove into the left end.
+fn reach(
+    column: Option<usize>,
+    absorb: f32,
+    amplify: f32,
+    physprop: &PhysicsProperties,
+) -> Vec<f32> {
+    let mut grid = DenseGridBoard::new(20, 1);
+    for x in 0..20 {
+        grid.set(x, 0, SlimeState::Settled);
+    }
+    let mut board = Goo {
+        grid,
+        column,
+        material: Material { absorb, amplify },
+    };
+    board.propagate_jiggle(
+        JigglePropagation {
+            at: (0, 0),
+            impulse: 50.0,
+            came_from: Cardinal4::Left,
+            hops: 0,
+        },
+        physprop,
+    );
+    (0..20)
+        .map(|x| match board.grid.get(x, 0) {
+            Some(SlimeState::Jiggling {
+                momentum,
+                momentum_x,
+                ..
+            }) => momentum.abs() + momentum_x.abs(),
+            _ => 0.0,
+        })
+        .collect()
+}
+
+fn reached(jiggles: &[f32]) -> usize {
+    jiggles.iter().filter(|jiggle| **jiggle > 0.0).count()
+}
+
+#[test]
+fn materials_soak_up_or_boost_what_passes_through() {
+    for wave_mode in [WaveMode::Separate, WaveMode::Coalesced] {
+        let physprop = PhysicsProperties {
+            wave_mode,
+            ..PhysicsProperties::default()
+        };
+        let plain = reach(None, 0.0, 0.0, &physprop);
+        let soaking = reach(Some(3), 0.8, 0.0, &physprop);
+        let boosting = reach(Some(3), 0.0, 0.2, &physprop);
+        assert!(reached(&soaking) < reached(&plain), "{wave_mode:?}");
+        assert!(reached(&boosting) >= reached(&plain), "{wave_mode:?}");
+        assert_eq!(plain[..3], soaking[..3]);
+        assert!(soaking[3] < plain[3] && boosting[3] > plain[3]);
+        assert_eq!(reached(&reach(Some(3), 1.0, 0.0, &physprop)), 3);
+    }
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+fn momentum(board: &DenseGridBoard) -> f32 {
+    match board.get(1, 0) {
+        Some(&SlimeState::Jiggling { momentum, .. }) => momentum,
+        _ => 0.0,
+    }
+}
+
+#[test]
+fn piled_up_impulses_stay_under_the_cap() {
+    let physprop = PhysicsProperties {
+        max_momentum: 2.0,
+        ..PhysicsProperties::default()
+    };
+    let mut board = DenseGridBoard::new(3, 2);
+    for x in 0..3 {
+        board.set(x, 0, SlimeState::Settled);
+    }
+    for _ in 0..5 {
+        board.impulse_signed((1, 0), 1.5, Cardinal4::Up, &physprop);
+        assert!(momentum(&board).abs() <= 2.0, "{}", momentum(&board));
+    }
+    assert_eq!(momentum(&board), 2.0);
+    for _ in 0..600 {
+        board.run_physics(1.0 / 60.0, &physprop);
+        assert!(momentum(&board).abs() <= 2.0, "{}", momentum(&board));
+    }
+    assert!(board.is_settled());
+}
+
+#[test]
+fn without_a_cap_they_add_up() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(3, 2);
+    for x in 0..3 {
+        board.set(x, 0, SlimeState::Settled);
+    }
+    for _ in 0..5 {
+        board.impulse_signed((1, 0), 1.5, Cardinal4::Up, &physprop);
+    }
+    assert!(momentum(&board) > 7.0, "{}", momentum(&board));
+}
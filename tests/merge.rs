@@ -0,0 +1,116 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A grid that takes merged slimes away and drops whatever was stacked on them.
+struct Merger {
+    grid: DenseGridBoard,
+    physprop: PhysicsProperties,
+    merged: Vec<(usize, usize)>,
+}
+
+impl JigglyBoard for Merger {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.grid.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.grid.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.grid.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.grid.impulse_jiggle_with(loc, f)
+    }
+    fn peek_slime<R>(
+        &self,
+        loc: (usize, usize),
+        f: impl FnOnce(&SlimeState, f32) -> R,
+    ) -> Option<R> {
+        self.grid.peek_slime(loc, f)
+    }
+    fn on_merged(&mut self, (x, y): (usize, usize)) -> Option<((usize, usize), Cardinal4)> {
+        self.merged.push((x, y));
+        self.grid.remove(x, y);
+        let physprop = self.physprop;
+        for above in y..6 {
+            if self.grid.get(x, above).is_some() {
+                self.begin_fall((x, above), &physprop);
+            }
+        }
+        Some(((x + 1, y), Cardinal4::Right))
+    }
+}
+
+#[test]
+fn the_stack_above_drops_when_the_merge_finishes() {
+    let physprop = PhysicsProperties {
+        merge_duration: 0.25,
+        ..PhysicsProperties::default()
+    };
+    let mut board = Merger {
+        grid: DenseGridBoard::new(2, 6),
+        physprop,
+        merged: Vec::new(),
+    };
+    for y in 0..4 {
+        board.grid.set(0, y, SlimeState::Settled);
+    }
+    board.grid.set(1, 0, SlimeState::Settled);
+    board.merge((0, 1), 1.0, 0.0);
+    let mut steps = 0;
+    loop {
+        assert!(!board.run_physics(1.0 / 60.0, &physprop));
+        steps += 1;
+        if !board.merged.is_empty() {
+            break;
+        }
+        // Sinking with it as it shrinks, but resting on it the whole time
+        let Some(&SlimeState::Merging { progress, .. }) = board.grid.get(0, 1) else {
+            panic!("{:?}", board.grid.get(0, 1));
+        };
+        for y in 2..4 {
+            let cell = board.grid.cell(0, y).unwrap();
+            assert_eq!(cell.state, SlimeState::Settled, "step {steps}");
+            let expected = y as f32 - progress;
+            assert!((cell.y_bottom - expected).abs() < 1e-5, "step {steps}");
+        }
+        assert_eq!(board.grid.get(1, 0), Some(&SlimeState::Settled));
+    }
+    // merge_duration in, or a step later for the rounding in adding up the progress
+    assert!((15..=16).contains(&steps), "{steps}");
+    assert_eq!(board.merged, [(0, 1)]);
+    // Both that were on it let go the step it finished, and the one it went into got the impulse
+    for y in 1..3 {
+        assert!(matches!(
+            board.grid.get(0, y),
+            Some(SlimeState::Falling { .. })
+        ));
+    }
+    assert!(matches!(
+        board.grid.get(1, 0),
+        Some(SlimeState::Jiggling { .. })
+    ));
+    assert!((0..600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+    assert_eq!(board.merged.len(), 1);
+    for y in 0..3 {
+        assert_eq!(board.grid.get(0, y), Some(&SlimeState::Settled));
+        assert_eq!(board.grid.cell(0, y).unwrap().y_bottom, y as f32);
+    }
+    assert_eq!(board.grid.get(0, 3), None);
+}
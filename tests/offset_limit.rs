@@ -0,0 +1,77 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+fn limited(offset_limit_mode: OffsetLimitMode) -> PhysicsProperties {
+    PhysicsProperties {
+        max_offset: 0.5,
+        offset_limit_mode,
+        min_y_scale: 0.2,
+        ..PhysicsProperties::default()
+    }
+}
+
+fn jiggling(momentum: f32, offset: f32) -> SlimeState {
+    SlimeState::Jiggling {
+        momentum,
+        offset,
+        life: 1.0,
+        momentum_x: 0.0,
+        offset_x: 0.0,
+    }
+}
+
+#[test]
+fn a_huge_impulse_never_flattens_a_slime() {
+    for mode in [OffsetLimitMode::Discard, OffsetLimitMode::Bleed] {
+        let physprop = limited(mode);
+        let mut board = DenseGridBoard::new(3, 2);
+        for x in 0..3 {
+            board.set(x, 0, SlimeState::Settled);
+        }
+        // A thousand times what landing from a cell up sends out
+        let impulse = 1000.0 * physprop.velocity_to_impact * (2.0 * physprop.gravity).sqrt();
+        board.apply_impulse_at((1, 0), impulse, Cardinal4::Up, &physprop);
+        let mut settled = false;
+        for _ in 0..3600 {
+            settled = board.run_physics(1.0 / 60.0, &physprop);
+            for x in 0..3 {
+                let y_scale = board.cell(x, 0).unwrap().render.y_scale;
+                assert!(y_scale >= physprop.min_y_scale, "{mode:?}: {y_scale}");
+                assert!(
+                    y_scale >= 1.0 - physprop.max_offset - 1e-6,
+                    "{mode:?}: {y_scale}"
+                );
+            }
+            if settled {
+                break;
+            }
+        }
+        assert!(settled, "{mode:?} never settled");
+    }
+}
+
+#[test]
+fn bleed_keeps_only_the_overshoot() {
+    let dt = 1.0 / 60.0;
+    for (mode, momentum) in [
+        (OffsetLimitMode::Discard, 0.0),
+        (OffsetLimitMode::Bleed, -21.84),
+    ] {
+        let physprop = limited(mode);
+        let mut board = DenseGridBoard::new(1, 1);
+        board.set(0, 0, jiggling(30.0, 0.45));
+        board.run_physics(dt, &physprop);
+        // (30 - 400 * 0.45 * dt) * 0.92 = 24.84 would take it 0.364 past the limit
+        let Some(&SlimeState::Jiggling {
+            momentum: after,
+            offset,
+            ..
+        }) = board.get(0, 0)
+        else {
+            panic!("{mode:?} stopped jiggling");
+        };
+        assert_eq!(offset, 0.5, "{mode:?}");
+        assert!((after - momentum).abs() < 1e-3, "{mode:?}: {after}");
+    }
+}
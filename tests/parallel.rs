@@ -0,0 +1,209 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction for Dir {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        [Dir::Up, Dir::Down, Dir::Left, Dir::Right]
+            .into_iter()
+            .filter(move |&dir| dir != self)
+    }
+    fn opposite(self) -> Self {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+            Dir::Right => Dir::Left,
+        }
+    }
+    const UP: Self = Dir::Up;
+    const RIGHT: Option<Self> = Some(Dir::Right);
+}
+
+/// Columns of different heights, each slime as its state and `y_bottom`.
+struct Columns {
+    cols: Vec<Vec<(Option<SlimeState>, f32)>>,
+}
+
+struct Column<'a>(&'a mut Vec<(Option<SlimeState>, f32)>);
+
+impl ColumnMut for Column<'_> {
+    type Loc = (usize, usize);
+    fn mut_slime_with(
+        &mut self,
+        (_, y): (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        let (state, y_bottom) = &mut self.0[y];
+        let out = f(SlimePropsIn {
+            state: state.take().unwrap(),
+            y_bottom: *y_bottom,
+        });
+        *state = Some(out.state);
+        *y_bottom = out.y_bottom;
+    }
+}
+
+impl Columns {
+    /// 37 columns of up to five slimes, dropped from staggered heights.
+    fn dropped() -> Self {
+        Columns {
+            cols: (0..37)
+                .map(|x| {
+                    (0..x % 5 + 1)
+                        .map(|y| {
+                            let state = SlimeState::Falling {
+                                velocity: 0.0,
+                                fallen: 0.0,
+                            };
+                            (Some(state), y as f32 * 1.3 + (x % 3) as f32)
+                        })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+    /// Every slime's state and `y_bottom`, bit for bit.
+    fn bits(&self) -> Vec<(Vec<u32>, u32)> {
+        self.cols
+            .iter()
+            .flatten()
+            .map(|(state, y_bottom)| {
+                let state = match state.as_ref().unwrap() {
+                    SlimeState::Settled => vec![0],
+                    SlimeState::Falling { velocity, fallen } => {
+                        vec![1, velocity.to_bits(), fallen.to_bits()]
+                    }
+                    SlimeState::Jiggling {
+                        momentum,
+                        offset,
+                        life,
+                        momentum_x,
+                        offset_x,
+                    } => [momentum, offset, life, momentum_x, offset_x]
+                        .iter()
+                        .map(|value| value.to_bits())
+                        .collect(),
+                    _ => vec![2],
+                };
+                (state, y_bottom.to_bits())
+            })
+            .collect()
+    }
+}
+
+impl JigglyBoard for Columns {
+    type Dir = Dir;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Dir,
+        (x, y): (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        let to = match dir {
+            Dir::Left if x > 0 => (x - 1, y),
+            Dir::Right if x + 1 < self.cols.len() => (x + 1, y),
+            Dir::Up => (x, y + 1),
+            Dir::Down if y > 0 => (x, y - 1),
+            _ => return None,
+        };
+        match self.cols[to.0].get(to.1)? {
+            (Some(SlimeState::Falling { .. }), _) => None,
+            _ => Some((to, impulse * 0.5)),
+        }
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.cols
+            .iter()
+            .enumerate()
+            .map(|(x, col)| (0..col.len()).map(move |y| (x, y)))
+    }
+    fn mut_slime_with(
+        &mut self,
+        (x, y): (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        Column(&mut self.cols[x]).mut_slime_with((x, y), f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        (x, y): (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        let state = &mut self.cols[x][y].0;
+        *state = Some(f(state.take().unwrap()));
+    }
+    #[cfg(feature = "parallel")]
+    fn par_columns_mut(&mut self, jobs: &mut [ColumnJob<(usize, usize)>]) {
+        self.step_columns_par(jobs);
+    }
+    /// Last column first, to show the order doesn't matter.
+    #[cfg(not(feature = "parallel"))]
+    fn par_columns_mut(&mut self, jobs: &mut [ColumnJob<(usize, usize)>]) {
+        for (column, job) in self.cols.iter_mut().zip(jobs).rev() {
+            job.step(&mut Column(column));
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl ParallelBoard for Columns {
+    type ColumnMut<'a> = Column<'a>;
+    fn columns_mut(&mut self) -> impl Iterator<Item = Column<'_>> {
+        self.cols.iter_mut().map(Column)
+    }
+}
+
+#[test]
+fn parallel_steps_match_sequential_bit_for_bit() {
+    let physprop = PhysicsProperties::bouncy();
+    let mut sequential = Columns::dropped();
+    let mut parallel = Columns::dropped();
+    let mut settled = false;
+    for step in 0..300 {
+        let a = sequential.run_physics(1.0 / 60.0, &physprop);
+        let b = parallel.run_physics_par(1.0 / 60.0, &physprop);
+        assert_eq!(a, b, "step {step}");
+        assert_eq!(sequential.bits(), parallel.bits(), "step {step}");
+        settled = a;
+    }
+    assert!(settled);
+}
+
+#[test]
+fn default_column_pass_matches_sequential() {
+    let physprop = PhysicsProperties::default();
+    let staircase = || {
+        let mut board = DenseGridBoard::new(4, 6);
+        for x in 0..4 {
+            for y in 0..x + 1 {
+                board.set(x, y, SlimeState::falling(&physprop));
+                board.cell_mut(x, y).unwrap().y_bottom = (y + x) as f32 * 1.5;
+            }
+        }
+        board
+    };
+    let mut sequential = staircase();
+    let mut in_jobs = staircase();
+    let mut settled = false;
+    for step in 0..600 {
+        let a = sequential.run_physics(1.0 / 60.0, &physprop);
+        let b = in_jobs.run_physics_par(1.0 / 60.0, &physprop);
+        assert_eq!(a, b, "step {step}");
+        assert_eq!(sequential.snapshot(), in_jobs.snapshot(), "step {step}");
+        settled = a;
+        if settled {
+            break;
+        }
+    }
+    assert!(settled);
+}
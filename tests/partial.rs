@@ -0,0 +1,48 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// Four columns, each with a slime about to land on the one below it at the same moment.
+fn four_landings(physprop: &PhysicsProperties) -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(4, 3);
+    for x in 0..4 {
+        board.set(x, 0, SlimeState::Settled);
+        board.set(x, 1, SlimeState::falling(physprop));
+        board.cell_mut(x, 1).unwrap().y_bottom = 2.5;
+    }
+    board
+}
+
+#[test]
+fn partial_steps_on_an_indexed_board_keep_to_the_budget() {
+    let physprop = PhysicsProperties::default();
+    let mut whole = four_landings(&physprop);
+    let mut partial = four_landings(&physprop);
+    assert!(partial.loc_index((0, 0)).is_some());
+    let mut budget = StepBudget::new(4, 1);
+    let mut landed = false;
+    for _ in 0..600 {
+        let mut stats = StepStats::default();
+        let settled = whole.physics_step_observed(1.0 / 60.0, &physprop, &mut stats);
+        let mut calls = 0;
+        let also = loop {
+            calls += 1;
+            if let Some(settled) = partial.run_physics_partial(1.0 / 60.0, &physprop, &mut budget) {
+                break settled;
+            }
+        };
+        assert_eq!(partial.snapshot(), whole.snapshot());
+        assert_eq!(also, settled);
+        if stats.landings > 0 {
+            // The columns and the first landing's wave, then one more call for each of the other three
+            assert_eq!(stats.landings, 4);
+            assert_eq!(calls, 4);
+            landed = true;
+        }
+        if settled {
+            assert!(landed);
+            return;
+        }
+    }
+    panic!("never settled");
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A grid that keeps track of the pops it's told about.
+struct Popper {
+    grid: DenseGridBoard,
+    popped: Vec<(usize, usize)>,
+}
+
+impl JigglyBoard for Popper {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.grid.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.grid.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.grid.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.grid.impulse_jiggle_with(loc, f)
+    }
+    fn peek_slime<R>(
+        &self,
+        loc: (usize, usize),
+        f: impl FnOnce(&SlimeState, f32) -> R,
+    ) -> Option<R> {
+        self.grid.peek_slime(loc, f)
+    }
+    fn on_popped(&mut self, loc: (usize, usize)) {
+        self.popped.push(loc);
+        self.grid.on_popped(loc);
+    }
+}
+
+#[test]
+fn a_mid_column_pop_drops_the_stack_a_cell() {
+    let physprop = PhysicsProperties {
+        pop_duration: 0.2,
+        ..PhysicsProperties::default()
+    };
+    let dt = 1.0 / 60.0;
+    let mut board = Popper {
+        grid: DenseGridBoard::new(1, 4),
+        popped: Vec::new(),
+    };
+    for y in 0..4 {
+        board.grid.set(0, y, SlimeState::Settled);
+    }
+    board.grid.set(0, 1, SlimeState::Popping { progress: 0.0 });
+    let mut steps = 0;
+    loop {
+        assert!(!board.run_physics(dt, &physprop));
+        steps += 1;
+        if !board.popped.is_empty() {
+            break;
+        }
+        let Some(&SlimeState::Popping { progress }) = board.grid.get(0, 1) else {
+            panic!("{:?}", board.grid.get(0, 1));
+        };
+        // Still holding up what's above it with whatever's left of it
+        let render = board.grid.cell(0, 1).unwrap().render;
+        assert!((render.y_scale - (1.0 - progress)).abs() < 1e-6);
+        let above = board.grid.cell(0, 2).unwrap().y_bottom;
+        assert!(above >= 1.0 + render.y_scale - 1e-5, "{above}");
+    }
+    // Told once, the step it finished, about the slime that popped
+    // pop_duration in, or a step later for the rounding in adding up the progress
+    assert!((12..=13).contains(&steps), "{steps}");
+    assert_eq!(board.popped, [(0, 1)]);
+    assert_eq!(board.grid.get(0, 3), None);
+    while !board.run_physics(dt, &physprop) {}
+    assert_eq!(board.popped.len(), 1);
+    // Everything that was above it is exactly a cell lower
+    for y in 0..3 {
+        assert_eq!(board.grid.get(0, y), Some(&SlimeState::Settled));
+        assert_eq!(board.grid.cell(0, y).unwrap().y_bottom, y as f32);
+    }
+}
@@ -0,0 +1,125 @@
+use jiggly_fever::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction for Dir {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        [Dir::Up, Dir::Down, Dir::Left, Dir::Right]
+            .into_iter()
+            .filter(move |&dir| dir != self)
+    }
+    fn opposite(self) -> Self {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+            Dir::Right => Dir::Left,
+        }
+    }
+    const UP: Self = Dir::Up;
+}
+
+/// A full `size` by `size` board, column by column, bottom first.
+struct Board {
+    size: usize,
+    states: Vec<SlimeState>,
+    y_bottoms: Vec<f32>,
+}
+
+impl Board {
+    /// Every slime dropped from a cell or so above where it ends up, a little staggered so they
+    /// don't all land at once.
+    fn dropped(size: usize) -> Self {
+        Board {
+            size,
+            states: (0..size * size)
+                .map(|_| SlimeState::Falling {
+                    velocity: 0.0,
+                    fallen: 0.0,
+                })
+                .collect(),
+            y_bottoms: (0..size * size)
+                .map(|i| (i % size) as f32 + 1.0 + (i / size) as f32 * 0.1)
+                .collect(),
+        }
+    }
+}
+
+impl JigglyBoard for Board {
+    type Dir = Dir;
+    type Loc = usize;
+    fn apply_dir_to_loc(&self, dir: Dir, loc: usize, impulse: f32) -> Option<(usize, f32)> {
+        let (x, y) = (loc / self.size, loc % self.size);
+        let to = match dir {
+            Dir::Up if y + 1 < self.size => loc + 1,
+            Dir::Down if y > 0 => loc - 1,
+            Dir::Left if x > 0 => loc - self.size,
+            Dir::Right if x + 1 < self.size => loc + self.size,
+            _ => return None,
+        };
+        if matches!(self.states[to], SlimeState::Falling { .. }) {
+            return None;
+        }
+        Some((to, impulse * 0.5))
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = usize>> {
+        let size = self.size;
+        (0..size).map(move |x| (0..size).map(move |y| x * size + y))
+    }
+    fn mut_slime_with(&mut self, loc: usize, f: impl FnOnce(SlimePropsIn) -> SlimePropsOut) {
+        let state = std::mem::replace(&mut self.states[loc], SlimeState::Settled);
+        let out = f(SlimePropsIn {
+            state,
+            y_bottom: self.y_bottoms[loc],
+        });
+        self.states[loc] = out.state;
+        self.y_bottoms[loc] = out.y_bottom;
+    }
+    fn impulse_jiggle_with(&mut self, loc: usize, f: impl FnOnce(SlimeState) -> SlimeState) {
+        let state = std::mem::replace(&mut self.states[loc], SlimeState::Settled);
+        self.states[loc] = f(state);
+    }
+}
+
+/// How many steps at 60Hz a dropped 10x10 board takes to settle.
+fn steps_to_settle(physprop: &PhysicsProperties) -> Option<u32> {
+    let mut board = Board::dropped(10);
+    (1..=600).find(|_| board.run_physics(1.0 / 60.0, physprop))
+}
+
+#[test]
+fn presets_settle_a_dropped_board_quickly() {
+    for (name, physprop) in [
+        ("default", PhysicsProperties::default()),
+        ("gelatin", PhysicsProperties::gelatin()),
+        ("stiff", PhysicsProperties::stiff()),
+        ("bouncy", PhysicsProperties::bouncy()),
+    ] {
+        // A couple of seconds of wobbling, plus the fall.
+        let steps = steps_to_settle(&physprop);
+        assert!(
+            steps.is_some_and(|steps| steps <= 150),
+            "{name} took {steps:?} steps"
+        );
+    }
+}
+
+#[test]
+fn presets_keep_the_threshold_inverse_consistent() {
+    for physprop in [
+        PhysicsProperties::gelatin(),
+        PhysicsProperties::stiff(),
+        PhysicsProperties::bouncy(),
+    ] {
+        assert_eq!(
+            physprop.jiggle_life_threshold_inverse,
+            1.0 / physprop.jiggle_life_threshold
+        );
+    }
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+fn sending_up(propagate_up_factor: f32) -> PhysicsProperties {
+    PhysicsProperties {
+        propagate_up_factor,
+        ..PhysicsProperties::default()
+    }
+}
+
+fn jiggling(state: Option<&SlimeState>) -> bool {
+    matches!(state, Some(SlimeState::Jiggling { .. }))
+}
+
+#[test]
+fn a_landing_on_a_stack_sets_all_of_it_jiggling() {
+    let physprop = sending_up(0.5);
+    let mut board = DenseGridBoard::new(1, 4);
+    for y in 0..3 {
+        board.set(0, y, SlimeState::Settled);
+    }
+    board.set(0, 3, SlimeState::falling(&physprop));
+    board.cell_mut(0, 3).unwrap().y_bottom = 4.0;
+    while !jiggling(board.get(0, 3)) {
+        board.run_physics(1.0 / 60.0, &physprop);
+    }
+    assert!((0..3).all(|y| jiggling(board.get(0, y))));
+}
+
+#[test]
+fn a_poke_at_the_bottom_shakes_the_stack_above() {
+    for factor in [0.0, 0.5] {
+        let physprop = sending_up(factor);
+        let mut board = DenseGridBoard::new(1, 3);
+        for y in 0..3 {
+            board.set(0, y, SlimeState::Settled);
+        }
+        board.apply_impulse_at((0, 0), 2.0, Cardinal4::Up, &physprop);
+        assert!(jiggling(board.get(0, 0)));
+        let shaken = factor > 0.0;
+        assert_eq!(jiggling(board.get(0, 2)), shaken, "{factor}");
+        match board.get(0, 1) {
+            // Half of it sent up, and half of that getting across to the slime above
+            Some(&SlimeState::Jiggling { momentum, .. }) => {
+                assert!(shaken);
+                assert!((momentum - 0.5).abs() < 1e-6, "{momentum}");
+            }
+            state => assert!(!shaken, "{state:?}"),
+        }
+    }
+}
+
+/// A [`DenseGridBoard`] whose jiggles reach the slime above even while it's still falling.
+struct Loose {
+    grid: DenseGridBoard,
+}
+
+impl JigglyBoard for Loose {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        (x, y): (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        if dir == Cardinal4::Up && self.grid.get(x, y + 1).is_some() {
+            return Some(((x, y + 1), impulse * self.grid.transfer_vertical));
+        }
+        self.grid.apply_dir_to_loc(dir, (x, y), impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.grid.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.grid.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.grid.impulse_jiggle_with(loc, f)
+    }
+}
+
+/// The velocity of the slime riding down on one that's just landed, on the step it lands.
+fn rider_velocity(propagate_up_factor: f32) -> f32 {
+    let physprop = sending_up(propagate_up_factor);
+    let mut grid = DenseGridBoard::new(1, 3);
+    grid.set(0, 0, SlimeState::Settled);
+    for y in 1..3 {
+        grid.set(0, y, SlimeState::falling(&physprop));
+        grid.cell_mut(0, y).unwrap().y_bottom = y as f32 + 0.5;
+    }
+    let mut board = Loose { grid };
+    while !jiggling(board.grid.get(0, 1)) {
+        board.run_physics(1.0 / 60.0, &physprop);
+    }
+    match board.grid.get(0, 2) {
+        Some(&SlimeState::Falling { velocity, .. }) => velocity,
+        state => panic!("{state:?}"),
+    }
+}
+
+#[test]
+fn a_slime_falling_onto_a_landing_is_knocked_back() {
+    let (carried_on, knocked) = (rider_velocity(0.0), rider_velocity(0.5));
+    assert!(carried_on > 0.0);
+    assert!(knocked < carried_on, "{knocked} vs {carried_on}");
+}
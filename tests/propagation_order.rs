@@ -0,0 +1,58 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// Every slime a tap in the middle of a 9 long strip reaches, in the order it reaches them, as
+/// `(x, impulse, hops)`.
+fn ripple(propagation_order: PropagationOrder) -> Vec<(usize, f32, u32)> {
+    let physprop = PhysicsProperties {
+        propagation_order,
+        ..PhysicsProperties::default()
+    };
+    let mut board = DenseGridBoard::new(9, 1);
+    board.transfer_horizontal = 0.9;
+    for x in 0..9 {
+        board.set(x, 0, SlimeState::Settled);
+    }
+    let mut reached = Vec::new();
+    board.propagate_jiggle_with_listener(
+        JigglePropagation {
+            at: (4, 0),
+            impulse: 4.0,
+            came_from: Cardinal4::Up,
+            hops: 0,
+        },
+        &physprop,
+        &mut |event| reached.push((event.loc.0, event.impulse, event.hops)),
+    );
+    reached
+}
+
+fn first_three(reached: &[(usize, f32, u32)]) -> Vec<usize> {
+    let mut xs: Vec<usize> = reached[..3].iter().map(|&(x, ..)| x).collect();
+    xs.sort();
+    xs
+}
+
+#[test]
+fn breadth_first_gets_to_the_nearest_three_first() {
+    let reached = ripple(PropagationOrder::BreadthFirst);
+    assert_eq!(first_three(&reached), [3, 4, 5]);
+    assert!(reached.windows(2).all(|w| w[0].2 <= w[1].2), "{reached:?}");
+}
+
+#[test]
+fn depth_first_runs_off_down_one_side() {
+    let reached = ripple(PropagationOrder::DepthFirst);
+    assert_ne!(first_three(&reached), [3, 4, 5], "{reached:?}");
+}
+
+#[test]
+fn either_way_reaches_the_same_slimes_just_as_hard() {
+    let mut depth = ripple(PropagationOrder::DepthFirst);
+    let mut breadth = ripple(PropagationOrder::BreadthFirst);
+    assert_eq!(depth.len(), 9);
+    depth.sort_by_key(|&(x, ..)| x);
+    breadth.sort_by_key(|&(x, ..)| x);
+    assert_eq!(depth, breadth);
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "alloc")]
+
+use std::collections::BTreeSet;
+
+use jiggly_fever::*;
+
+struct Recovered(BTreeSet<(usize, usize)>);
+
+impl<D> PhysicsObserver<(usize, usize), D> for Recovered {
+    fn recovered(&mut self, loc: (usize, usize)) {
+        self.0.insert(loc);
+    }
+}
+
+#[test]
+fn a_nan_in_any_state_is_reset_and_reported() {
+    let physprop = PhysicsProperties::default();
+    let nan = f32::NAN;
+    let states = [
+        SlimeState::Launched {
+            velocity: nan,
+            height: 0.0,
+        },
+        SlimeState::Popping { progress: nan },
+        SlimeState::Held {
+            buffered_impulse: nan,
+        },
+        SlimeState::Sliding {
+            x_offset: nan,
+            x_velocity: 0.0,
+            returning: false,
+        },
+        SlimeState::Jiggling {
+            momentum: nan,
+            offset: 0.0,
+            life: 1.0,
+            momentum_x: 0.0,
+            offset_x: 0.0,
+        },
+        SlimeState::Squished { amount: nan },
+        SlimeState::AboutToFall { remaining: nan },
+    ];
+    let mut board = DenseGridBoard::new(states.len(), 1);
+    for (x, state) in states.into_iter().enumerate() {
+        board.set(x, 0, state);
+    }
+    let mut recovered = Recovered(BTreeSet::new());
+    board.run_physics_observed(1.0 / 60.0, &physprop, &mut recovered);
+    assert_eq!(
+        recovered.0,
+        (0..board.width()).map(|x| (x, 0)).collect::<BTreeSet<_>>()
+    );
+    assert!(
+        (0..600).any(|_| board.run_physics(1.0 / 60.0, &physprop)),
+        "never settled"
+    );
+    for x in 0..board.width() {
+        let cell = board.cell(x, 0).unwrap();
+        assert!(matches!(cell.state, SlimeState::Settled));
+        assert_eq!(cell.y_bottom, 0.0);
+    }
+}
+
+#[test]
+fn a_nan_position_is_reset_too() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(1, 2);
+    board.set(0, 0, SlimeState::Settled);
+    board.set(0, 1, SlimeState::Settled);
+    board.cell_mut(0, 1).unwrap().y_bottom = f32::NAN;
+    let mut recovered = Recovered(BTreeSet::new());
+    board.run_physics_observed(1.0 / 60.0, &physprop, &mut recovered);
+    assert_eq!(recovered.0, BTreeSet::from([(0, 1)]));
+    assert!((0..600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+    assert!(board.cell(0, 1).unwrap().y_bottom.is_finite());
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// Every impulse a tap on the left end of an 8 long strip delivers, as `(x, impulse, hops)`.
+fn ripple(edge_reflectance: f32) -> Vec<(usize, f32, u32)> {
+    let physprop = PhysicsProperties {
+        edge_reflectance,
+        ..PhysicsProperties::default()
+    };
+    let mut board = DenseGridBoard::new(8, 1);
+    for x in 0..8 {
+        board.set(x, 0, SlimeState::Settled);
+    }
+    let mut delivered = Vec::new();
+    board.propagate_jiggle_with_listener(
+        JigglePropagation {
+            at: (0, 0),
+            impulse: 4.0,
+            came_from: Cardinal4::Up,
+            hops: 0,
+        },
+        &physprop,
+        &mut |event| delivered.push((event.loc.0, event.impulse, event.hops)),
+    );
+    delivered
+}
+
+/// What reached the slime at `x`, nearest the start of the wave first.
+fn at(delivered: &[(usize, f32, u32)], x: usize) -> Vec<(f32, u32)> {
+    let mut hits: Vec<_> = delivered
+        .iter()
+        .filter(|delivery| delivery.0 == x)
+        .map(|&(_, impulse, hops)| (impulse, hops))
+        .collect();
+    hits.sort_by_key(|&(_, hops)| hops);
+    hits
+}
+
+#[test]
+fn a_wave_dies_at_the_edge_by_default() {
+    let delivered = ripple(0.0);
+    for x in 0..2 {
+        assert_eq!(at(&delivered, x).len(), 1, "{delivered:?}");
+    }
+}
+
+#[test]
+fn a_wave_bounces_back_off_the_edge_weaker() {
+    let delivered = ripple(0.5);
+    for x in 0..2 {
+        let hits = at(&delivered, x);
+        assert!(hits.len() >= 2, "{x}: {delivered:?}");
+        let (first, first_hops) = hits[0];
+        let (second, second_hops) = hits[1];
+        assert!(second < first, "{x}: {hits:?}");
+        assert_eq!(second_hops, first_hops + 1, "{x}: {hits:?}");
+    }
+    // The tap itself, then the half of it that bounced straight back off the wall
+    let hits = at(&delivered, 0);
+    assert_eq!(hits[0], (4.0, 0));
+    assert_eq!(hits[1], (2.0, 1));
+}
@@ -0,0 +1,99 @@
+use jiggly_fever::*;
+
+const AROUND: usize = 100;
+
+/// A row of slimes that wraps round, left of the first being the last, and passes jiggles on whole, so
+/// only a cycle guard can stop a wave going round it for ever.
+struct Ring {
+    cells: [(SlimeState, f32); AROUND],
+}
+
+impl Ring {
+    fn dropped() -> Self {
+        let mut cells = [(SlimeState::Settled, 0.0); AROUND];
+        cells[0] = (SlimeState::falling(&PhysicsProperties::default()), 1.0);
+        Ring { cells }
+    }
+}
+
+impl JigglyBoard for Ring {
+    type Dir = Cardinal4;
+    type Loc = usize;
+    fn apply_dir_to_loc(&self, dir: Cardinal4, x: usize, impulse: f32) -> Option<(usize, f32)> {
+        match dir {
+            Cardinal4::Left => Some(((x + AROUND - 1) % AROUND, impulse)),
+            Cardinal4::Right => Some(((x + 1) % AROUND, impulse)),
+            Cardinal4::Up | Cardinal4::Down => None,
+        }
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = usize>> {
+        (0..AROUND).map(core::iter::once)
+    }
+    fn mut_slime_with(&mut self, x: usize, f: impl FnOnce(SlimePropsIn) -> SlimePropsOut) {
+        let (state, y_bottom) = &mut self.cells[x];
+        let out = f(SlimePropsIn {
+            state: *state,
+            y_bottom: *y_bottom,
+        });
+        (*state, *y_bottom) = (out.state, out.y_bottom);
+    }
+    fn impulse_jiggle_with(&mut self, x: usize, f: impl FnOnce(SlimeState) -> SlimeState) {
+        let (state, _) = &mut self.cells[x];
+        *state = f(*state);
+    }
+    fn loc_index(&self, x: usize) -> Option<usize> {
+        Some(x)
+    }
+}
+
+#[test]
+fn a_wave_round_a_ring_stops() {
+    let physprop = PhysicsProperties {
+        cycle_guard: CycleGuard::VisitOnce,
+        // Only ever two cells to come back to, so it's just the cells visited that can run out of room
+        propagation_order: PropagationOrder::BreadthFirst,
+        ..PhysicsProperties::default()
+    };
+    let mut board = Ring::dropped();
+    let mut stats = StepStats::default();
+    while stats.landings == 0 {
+        stats = board.run_physics_stats(1.0 / 60.0, &physprop);
+    }
+    let reached = board
+        .cells
+        .iter()
+        .filter(|(state, _)| matches!(state, SlimeState::Jiggling { .. }))
+        .count();
+    if cfg!(feature = "alloc") {
+        // The landing slime, then each of the others once, whichever way round it got to them first
+        assert_eq!(stats.propagations as usize, AROUND);
+        assert_eq!(reached, AROUND);
+        assert_eq!(stats.truncated, 0);
+    } else {
+        // Only so many fit to be kept track of, and it stops at the first one past that, each way round
+        assert_eq!(stats.propagations, 64);
+        assert_eq!(reached, 64);
+        assert_eq!(stats.truncated, 2);
+    }
+}
+
+#[test]
+fn a_deep_wave_says_what_it_dropped() {
+    let physprop = PhysicsProperties {
+        cycle_guard: CycleGuard::VisitOnce,
+        ..PhysicsProperties::default()
+    };
+    let mut board = Ring::dropped();
+    let mut stats = StepStats::default();
+    while stats.landings == 0 {
+        stats = board.run_physics_stats(1.0 / 60.0, &physprop);
+    }
+    if cfg!(feature = "alloc") {
+        assert_eq!(stats.propagations as usize, AROUND);
+        assert_eq!(stats.truncated, 0);
+    } else {
+        // Every cell it's been through waits to be come back to, and there's only room for 16
+        assert_eq!(stats.propagations, 33);
+        assert_eq!(stats.truncated, 2);
+    }
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+fn step(board: &mut DenseGridBoard, physprop: &PhysicsProperties) {
+    board.run_physics(1.0 / 60.0, physprop);
+}
+
+/// Four columns three deep, with the top of the first jiggling.
+fn board(physprop: &PhysicsProperties) -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(4, 6);
+    for x in 0..4 {
+        for y in 0..3 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board.impulse_signed((0, 2), 3.0, Cardinal4::Up, physprop);
+    board
+}
+
+#[test]
+fn old_frames_are_evicted_and_later_ones_dropped_on_rollback() {
+    let physprop = PhysicsProperties::default();
+    let mut board = board(&physprop);
+    let mut buffer = RollbackBuffer::new(6);
+    for frame in 0..10u64 {
+        buffer.save(frame, &board);
+        step(&mut board, &physprop);
+    }
+    assert_eq!(buffer.frames().collect::<Vec<_>>(), [4, 5, 6, 7, 8, 9]);
+    assert_eq!(
+        buffer.rollback_to(2, &mut board),
+        Err(RollbackError::Evicted {
+            frame: 2,
+            oldest: 4
+        })
+    );
+    assert_eq!(
+        buffer.rollback_to(20, &mut board),
+        Err(RollbackError::NotSaved { frame: 20 })
+    );
+    buffer.rollback_to(6, &mut board).unwrap();
+    assert_eq!(board.snapshot(), *buffer.get(6).unwrap());
+    assert_eq!(buffer.newest(), Some(6));
+    assert_eq!(
+        buffer.rollback_to(8, &mut board),
+        Err(RollbackError::NotSaved { frame: 8 })
+    );
+}
+
+#[test]
+fn rolling_back_within_a_new_timeline_replays_it() {
+    let physprop = PhysicsProperties::default();
+    let mut board = board(&physprop);
+    let mut buffer = RollbackBuffer::new(6);
+    for frame in 0..7u64 {
+        buffer.save(frame, &board);
+        step(&mut board, &physprop);
+    }
+    buffer.rollback_to(6, &mut board).unwrap();
+    board.impulse_signed((3, 2), 5.0, Cardinal4::Left, &physprop);
+    step(&mut board, &physprop);
+    let mut ours = Vec::new();
+    for frame in 7..14u64 {
+        buffer.save(frame, &board);
+        ours.push(board.snapshot());
+        step(&mut board, &physprop);
+    }
+    assert_eq!(buffer.frames().collect::<Vec<_>>(), [8, 9, 10, 11, 12, 13]);
+    buffer.rollback_to(9, &mut board).unwrap();
+    assert_eq!(buffer.len(), 2);
+    assert_eq!(board.snapshot(), ours[2]);
+    for snapshot in &ours[3..] {
+        step(&mut board, &physprop);
+        assert_eq!(board.snapshot(), *snapshot);
+    }
+}
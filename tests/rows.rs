@@ -0,0 +1,51 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A 4 wide board with columns 3, 0, 5 and 1 high.
+fn ragged() -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(4, 5);
+    for (x, height) in [3, 0, 5, 1].into_iter().enumerate() {
+        for y in 0..height {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board
+}
+
+#[test]
+fn rows_hold_the_same_slimes_as_cols() {
+    let board = ragged();
+    let rows: Vec<Vec<_>> = board.rows().map(|row| row.collect()).collect();
+    for (y, row) in rows.iter().enumerate() {
+        assert!(row.iter().all(|&(_, row_y)| row_y == y));
+    }
+    let mut by_row: Vec<_> = rows.concat();
+    let mut by_col: Vec<_> = board.cols().flatten().collect();
+    by_row.sort();
+    by_col.sort();
+    assert_eq!(by_row, by_col);
+    let erased: &dyn JigglyBoardDyn<f32, Loc = (usize, usize), Dir = Cardinal4> = &board;
+    let erased_rows: Vec<Vec<_>> = erased.rows().map(|row| row.collect()).collect();
+    assert_eq!(erased_rows, rows);
+}
+
+#[test]
+fn neighbor_finds_slimes_whether_or_not_jiggles_reach_them() {
+    let mut board = ragged();
+    assert_eq!(board.neighbor((0, 0), Cardinal4::Right), None);
+    assert_eq!(board.neighbor((0, 0), Cardinal4::Up), Some((0, 1)));
+    assert_eq!(board.neighbor((2, 4), Cardinal4::Up), None);
+    board.set(0, 1, SlimeState::falling(&PhysicsProperties::default()));
+    assert_eq!(board.neighbor((0, 0), Cardinal4::Up), Some((0, 1)));
+    assert_eq!(board.apply_dir_to_loc(Cardinal4::Up, (0, 0), 1.0), None);
+}
+
+#[test]
+fn neighbor_goes_between_layers() {
+    let mut board = LayeredGridBoard::<f32>::new(2, 2, 2);
+    board.layer_mut(0).unwrap().set(0, 0, SlimeState::Settled);
+    board.layer_mut(1).unwrap().set(0, 0, SlimeState::Settled);
+    assert_eq!(board.neighbor((0, 0, 0), Layered::Back), Some((0, 0, 1)));
+    assert_eq!(board.neighbor((0, 0, 0), Layered::Front), None);
+}
@@ -0,0 +1,134 @@
+use jiggly_fever::*;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction for Dir {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        [Dir::Up, Dir::Down, Dir::Left, Dir::Right]
+            .into_iter()
+            .filter(move |&dir| dir != self)
+    }
+    fn opposite(self) -> Self {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+            Dir::Right => Dir::Left,
+        }
+    }
+    const UP: Self = Dir::Up;
+    const RIGHT: Option<Self> = Some(Dir::Right);
+}
+
+/// A `width` by `height` board, row by row, bottom first.
+struct Grid<S> {
+    width: usize,
+    height: usize,
+    states: Vec<SlimeState<S>>,
+    y_bottoms: Vec<S>,
+}
+
+impl<S: Scalar> Grid<S> {
+    fn dropped(width: usize, height: usize) -> Self {
+        Grid {
+            width,
+            height,
+            states: (0..width * height)
+                .map(|_| SlimeState::Falling {
+                    velocity: S::ZERO,
+                    fallen: S::ZERO,
+                })
+                .collect(),
+            y_bottoms: (0..width * height)
+                .map(|i| S::from_f64((i / width) as f64 + 2.0 + (i % width) as f64 * 0.7))
+                .collect(),
+        }
+    }
+}
+
+impl<S: Scalar> JigglyBoard<S> for Grid<S> {
+    type Dir = Dir;
+    type Loc = usize;
+    fn apply_dir_to_loc(&self, dir: Dir, loc: usize, impulse: S) -> Option<(usize, S)> {
+        let (x, y) = (loc % self.width, loc / self.width);
+        let to = match dir {
+            Dir::Left if x > 0 => loc - 1,
+            Dir::Right if x + 1 < self.width => loc + 1,
+            Dir::Up if y + 1 < self.height => loc + self.width,
+            Dir::Down if y > 0 => loc - self.width,
+            _ => return None,
+        };
+        if matches!(self.states[to], SlimeState::Falling { .. }) {
+            return None;
+        }
+        Some((to, impulse * S::from_f64(0.5)))
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = usize>> {
+        let (width, height) = (self.width, self.height);
+        (0..width).map(move |x| (0..height).map(move |y| x + y * width))
+    }
+    fn mut_slime_with(&mut self, loc: usize, f: impl FnOnce(SlimePropsIn<S>) -> SlimePropsOut<S>) {
+        let state = std::mem::replace(&mut self.states[loc], SlimeState::Settled);
+        let out = f(SlimePropsIn {
+            state,
+            y_bottom: self.y_bottoms[loc],
+        });
+        self.states[loc] = out.state;
+        self.y_bottoms[loc] = out.y_bottom;
+    }
+    fn impulse_jiggle_with(&mut self, loc: usize, f: impl FnOnce(SlimeState<S>) -> SlimeState<S>) {
+        let state = std::mem::replace(&mut self.states[loc], SlimeState::Settled);
+        self.states[loc] = f(state);
+    }
+}
+
+/// Drop a 4x3 board and let it settle, returning how many steps that took and where everything
+/// ended up.
+fn settle<S: Scalar>() -> (u32, Vec<f64>) {
+    let mut grid = Grid::<S>::dropped(4, 3);
+    let physprop = PhysicsProperties::<S>::default();
+    let steps = (1..=2000)
+        .find(|_| grid.run_physics(S::from_f64(1.0 / 60.0), &physprop))
+        .expect("never settled");
+    (steps, grid.y_bottoms.iter().map(|y| y.to_f64()).collect())
+}
+
+#[test]
+fn f32_and_f64_settle_the_same_way() {
+    let (f32_steps, f32_ys) = settle::<f32>();
+    let (f64_steps, f64_ys) = settle::<f64>();
+    assert_eq!(f32_steps, f64_steps);
+    for (a, b) in f32_ys.iter().zip(&f64_ys) {
+        assert!((a - b).abs() < 1e-3, "{a} vs {b}");
+    }
+}
+
+#[test]
+fn float_math_matches_std() {
+    for x in [0.1f64, 0.5, 1.0, 2.0, 10.0] {
+        assert!((Scalar::exp(x) - x.exp()).abs() <= 1e-6 * x.exp());
+        assert!((Scalar::sin(x) - x.sin()).abs() <= 1e-6);
+        assert!((Scalar::sqrt(x) - x.sqrt()).abs() <= 1e-6);
+        assert!((Scalar::powf(x, 0.37) - x.powf(0.37)).abs() <= 1e-6);
+        let y = x as f32;
+        assert!((Scalar::exp(y) - y.exp()).abs() <= 1e-4 * y.exp());
+        assert!((Scalar::sin(y) - y.sin()).abs() <= 1e-4);
+        assert!((Scalar::powf(y, 0.37) - y.powf(0.37)).abs() <= 1e-4);
+    }
+}
+
+#[test]
+fn presets_cast_to_f64() {
+    let physprop = PhysicsProperties::gelatin().cast::<f64>();
+    assert_eq!(physprop.validate(), Ok(()));
+    assert_eq!(
+        physprop.gravity,
+        PhysicsProperties::gelatin().gravity as f64
+    );
+}
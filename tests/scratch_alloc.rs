@@ -0,0 +1,83 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// The system allocator, counting allocations made on each thread.
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static COUNTING: Counting = Counting;
+
+/// A 32 by 32 board, half full.
+fn half_full() -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(32, 32);
+    for x in 0..32 {
+        for y in 0..16 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board
+}
+
+/// Step `board` in `scratch` until it settles, returning how many allocations that took.
+fn allocations_to_settle(
+    board: &mut DenseGridBoard,
+    scratch: &mut PhysicsScratch<(usize, usize), Cardinal4>,
+    physprop: &PhysicsProperties,
+) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    let mut steps = 0;
+    while !board.run_physics_with_scratch(1.0 / 60.0, physprop, scratch, &mut ()) {
+        steps += 1;
+        assert!(steps < 3600, "never settled");
+    }
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn a_settled_board_steps_without_allocating() {
+    let physprop = PhysicsProperties::default();
+    let mut board = half_full();
+    let mut scratch = PhysicsScratch::new();
+    assert!(board.run_physics_with_scratch(1.0 / 60.0, &physprop, &mut scratch, &mut ()));
+    let before = ALLOCATIONS.with(Cell::get);
+    for _ in 0..600 {
+        assert!(board.run_physics_with_scratch(1.0 / 60.0, &physprop, &mut scratch, &mut ()));
+    }
+    assert_eq!(ALLOCATIONS.with(Cell::get), before);
+}
+
+#[test]
+fn a_jiggling_board_steps_without_allocating() {
+    let physprop = PhysicsProperties::default();
+    let mut board = half_full();
+    let mut scratch = PhysicsScratch::new();
+    board.run_physics_with_scratch(1.0 / 60.0, &physprop, &mut scratch, &mut ());
+    for round in 0..3 {
+        for x in (0..32).step_by(3) {
+            board.nudge((x, 15 - round), 2.0, Cardinal4::Up, &physprop);
+        }
+        assert!(!board.is_settled());
+        assert_eq!(
+            allocations_to_settle(&mut board, &mut scratch, &physprop),
+            0,
+            "round {round}"
+        );
+    }
+}
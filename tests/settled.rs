@@ -0,0 +1,131 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// Three columns, three slimes deep, all settled.
+fn board() -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(3, 6);
+    for x in 0..3 {
+        for y in 0..3 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board
+}
+
+#[test]
+fn is_settled_tracks_run_physics() {
+    let physprop = PhysicsProperties::default();
+    let mut board = board();
+    assert!(board.is_settled());
+    assert_eq!(board.count_active().total(), 0);
+    board.set(1, 3, SlimeState::falling(&physprop));
+    board.cell_mut(1, 3).unwrap().y_bottom = 5.0;
+    assert!(!board.is_settled());
+    assert_eq!(
+        board.count_active(),
+        ActiveCounts {
+            falling: 1,
+            jiggling: 0,
+            other: 0
+        }
+    );
+    let mut jiggled = false;
+    let mut settled = false;
+    for _ in 0..3600 {
+        settled = board.run_physics(1.0 / 60.0, &physprop);
+        jiggled |= board.count_active().jiggling > 0;
+        assert_eq!(board.count_active().total() == 0, board.is_settled());
+        if settled {
+            break;
+        }
+    }
+    assert!(settled && jiggled);
+    assert!(board.is_settled());
+}
+
+#[test]
+fn erased_boards_peek_through() {
+    let board = board();
+    let erased: Box<dyn JigglyBoardDyn<f32, Loc = (usize, usize), Dir = Cardinal4>> =
+        Box::new(board);
+    assert!(erased.is_settled());
+    assert_eq!(erased.peek_slime((1, 2), |_, y_bottom| y_bottom), Some(2.0));
+    assert_eq!(erased.peek_slime((1, 3), |_, y_bottom| y_bottom), None);
+}
+
+#[test]
+fn hex_boards_count_too() {
+    let mut board = HexBoard::<f32>::new(2, 2);
+    board.set((0, 0), SlimeState::landed());
+    assert_eq!(board.count_active().jiggling, 1);
+    assert!(!board.is_settled());
+}
+
+#[test]
+fn is_settled_agrees_with_run_physics_on_held_slimes() {
+    let physprop = PhysicsProperties::default();
+    let mut board = board();
+    board.set(0, 2, SlimeState::Frozen);
+    board.squish((2, 2), 0.5);
+    board.set(1, 3, SlimeState::falling(&physprop));
+    board.cell_mut(1, 3).unwrap().y_bottom = 5.0;
+    let mut settled = false;
+    for _ in 0..3600 {
+        settled = board.run_physics(1.0 / 60.0, &physprop);
+        assert!(!settled || board.is_settled());
+        if settled {
+            break;
+        }
+    }
+    assert!(settled);
+    assert_eq!(board.count_active().total(), 0);
+    assert_eq!(board.active_cells().count(), 0);
+}
+
+/// A [`DenseGridBoard`] that only hands its slimes out through `mut_slime_with`.
+struct Blind(DenseGridBoard);
+
+impl JigglyBoard for Blind {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.0.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.0.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.0.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.0.impulse_jiggle_with(loc, f)
+    }
+}
+
+#[test]
+fn is_at_rest_sees_past_peek_slime() {
+    let physprop = PhysicsProperties::default();
+    let mut board = Blind(board());
+    assert!(!board.is_settled());
+    assert!(board.is_at_rest());
+    board.0.set(1, 3, SlimeState::falling(&physprop));
+    board.0.cell_mut(1, 3).unwrap().y_bottom = 5.0;
+    assert!(!board.is_at_rest());
+    assert!((0..3600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+    assert!(board.is_at_rest());
+    assert_eq!(board.0.count_active().total(), 0);
+}
@@ -0,0 +1,173 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A small linear congruential generator, so shakes are the same every run.
+struct Lcg(u32);
+
+impl RngSource for Lcg {
+    fn next_f32(&mut self) -> f32 {
+        self.0 = self.0.wrapping_mul(1664525).wrapping_add(1013904223);
+        (self.0 >> 8) as f32 / (1u32 << 24) as f32
+    }
+}
+
+/// Six columns four deep, with one more slime on its way down.
+fn board() -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(6, 6);
+    for x in 0..6 {
+        for y in 0..4 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board.set(
+        2,
+        4,
+        SlimeState::Falling {
+            velocity: 3.0,
+            fallen: 0.2,
+        },
+    );
+    board.cell_mut(2, 4).unwrap().y_bottom = 5.5;
+    board
+}
+
+#[test]
+fn shake_jiggles_every_resting_slime_by_about_the_same() {
+    let physprop = PhysicsProperties::default();
+    let mut board = board();
+    board.shake(2.0, 0.5, &mut Lcg(7), &physprop);
+    let mut momenta = Vec::new();
+    for x in 0..6 {
+        for y in 0..4 {
+            let Some(SlimeState::Jiggling { momentum, .. }) = board.get(x, y) else {
+                panic!("{x} {y}");
+            };
+            assert!((1.5..=2.5).contains(momentum), "{momentum}");
+            momenta.push(*momentum);
+        }
+    }
+    assert!(momenta.windows(2).any(|pair| pair[0] != pair[1]));
+    assert!(matches!(
+        board.get(2, 4),
+        Some(SlimeState::Falling { velocity: 3.0, .. })
+    ));
+    assert!((0..3600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+}
+
+#[test]
+fn same_seed_same_shake() {
+    let physprop = PhysicsProperties::default();
+    let (mut first, mut second) = (board(), board());
+    first.shake(2.0, 0.5, &mut Lcg(7), &physprop);
+    second.shake(2.0, 0.5, &mut Lcg(7), &physprop);
+    assert_eq!(first.snapshot(), second.snapshot());
+}
+
+#[test]
+fn alternating_shake_is_a_checkerboard() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(4, 4);
+    for x in 0..4 {
+        for y in 0..4 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board.shake_alternating(1.0, 0.0, &mut || 0.5, &physprop);
+    for x in 0..4 {
+        for y in 0..4 {
+            let Some(SlimeState::Jiggling { momentum, .. }) = board.get(x, y) else {
+                panic!("{x} {y}");
+            };
+            assert_eq!(*momentum, if (x + y) % 2 == 0 { 1.0 } else { -1.0 });
+        }
+    }
+}
+
+/// Directions where only a hit from `Up` squashes, so which side a shake comes from shows.
+#[derive(Clone, Copy, PartialEq)]
+enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction for Dir {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        [Dir::Up, Dir::Down, Dir::Left, Dir::Right]
+            .into_iter()
+            .filter(move |&dir| dir != self)
+    }
+    fn opposite(self) -> Self {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+            Dir::Right => Dir::Left,
+        }
+    }
+    const UP: Self = Dir::Up;
+    const RIGHT: Option<Self> = Some(Dir::Right);
+    fn squashes(self) -> bool {
+        self == Dir::Up
+    }
+}
+
+/// One column of settled slimes, stacked against whichever end `gravity` says.
+struct Column {
+    slimes: Vec<SlimeState>,
+    gravity: GravityDirection,
+}
+
+impl JigglyBoard for Column {
+    type Dir = Dir;
+    type Loc = usize;
+    fn apply_dir_to_loc(&self, _: Dir, _: usize, _: f32) -> Option<(usize, f32)> {
+        None
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = usize>> {
+        std::iter::once(0..self.slimes.len())
+    }
+    fn mut_slime_with(&mut self, at: usize, f: impl FnOnce(SlimePropsIn) -> SlimePropsOut) {
+        let out = f(SlimePropsIn {
+            state: self.slimes[at],
+            y_bottom: at as f32,
+        });
+        self.slimes[at] = out.state;
+    }
+    fn impulse_jiggle_with(&mut self, at: usize, f: impl FnOnce(SlimeState) -> SlimeState) {
+        self.slimes[at] = f(self.slimes[at]);
+    }
+    fn gravity_dir(&self) -> GravityDirection {
+        self.gravity
+    }
+}
+
+#[test]
+fn shakes_come_from_where_landings_do() {
+    let physprop = PhysicsProperties::default();
+    for gravity in [GravityDirection::Down, GravityDirection::Up] {
+        let mut board = Column {
+            slimes: vec![SlimeState::Settled; 3],
+            gravity,
+        };
+        board.shake(1.0, 0.0, &mut || 0.5, &physprop);
+        for slime in &board.slimes {
+            let SlimeState::Jiggling {
+                momentum,
+                momentum_x,
+                ..
+            } = *slime
+            else {
+                panic!("{slime:?}");
+            };
+            // Only a hit from above squashes, so from below, with gravity up, they lean over instead
+            let want = match gravity {
+                GravityDirection::Down => (1.0, 0.0),
+                GravityDirection::Up => (0.0, 1.0),
+            };
+            assert_eq!((momentum, momentum_x), want);
+        }
+    }
+}
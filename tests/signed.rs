@@ -0,0 +1,50 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// How tall each slime of a three-tall stack is drawn for the first few steps after the top one is hit
+/// with `value`.
+fn first_frames(value: f32) -> Vec<[f32; 3]> {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(1, 3);
+    for y in 0..3 {
+        board.set(0, y, SlimeState::Settled);
+    }
+    board.impulse_signed((0, 2), value, Cardinal4::Up, &physprop);
+    (0..5)
+        .map(|_| {
+            board.run_physics(1.0 / 60.0, &physprop);
+            [0, 1, 2].map(|y| board.cell(0, y).unwrap().render.y_scale)
+        })
+        .collect()
+}
+
+#[test]
+fn a_negative_impulse_stretches_first() {
+    for scales in first_frames(-3.0) {
+        assert!(scales[2] > 1.0, "{scales:?}");
+        // And the stretch is carried down the stack
+        assert!(scales[1] > 1.0, "{scales:?}");
+    }
+}
+
+#[test]
+fn a_positive_impulse_squashes_first() {
+    for scales in first_frames(3.0) {
+        assert!(scales[2] < 1.0, "{scales:?}");
+        assert!(scales[1] < 1.0, "{scales:?}");
+    }
+}
+
+#[test]
+fn the_two_are_mirror_images() {
+    let (stretched, squashed) = (first_frames(-3.0), first_frames(3.0));
+    for (stretched, squashed) in stretched.iter().zip(&squashed) {
+        for y in 0..3 {
+            assert!(
+                (stretched[y] - 1.0 + squashed[y] - 1.0).abs() < 1e-5,
+                "{stretched:?} {squashed:?}"
+            );
+        }
+    }
+}
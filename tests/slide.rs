@@ -0,0 +1,126 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A single row of slots, each with a slime or not, and how far across its slime is drawn.
+struct Row {
+    slots: Vec<Option<(SlimeState, f32)>>,
+}
+
+impl Row {
+    fn new(slots: &[bool]) -> Self {
+        Row {
+            slots: slots
+                .iter()
+                .map(|&full| full.then_some((SlimeState::Settled, 0.0)))
+                .collect(),
+        }
+    }
+    fn state(&self, x: usize) -> Option<SlimeState> {
+        self.slots[x].map(|(state, _)| state)
+    }
+}
+
+impl JigglyBoard for Row {
+    type Dir = Cardinal4;
+    type Loc = usize;
+    fn apply_dir_to_loc(&self, dir: Cardinal4, x: usize, impulse: f32) -> Option<(usize, f32)> {
+        let x = match dir {
+            Cardinal4::Left => x.checked_sub(1)?,
+            Cardinal4::Right => x + 1,
+            Cardinal4::Up | Cardinal4::Down => return None,
+        };
+        self.slots.get(x)?.as_ref()?;
+        Some((x, impulse * 0.5))
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = usize>> {
+        (0..self.slots.len()).map(|x| self.slots[x].map(|_| x).into_iter())
+    }
+    fn mut_slime_with(&mut self, x: usize, f: impl FnOnce(SlimePropsIn) -> SlimePropsOut) {
+        if let Some((state, x_offset)) = &mut self.slots[x] {
+            let out = f(SlimePropsIn {
+                state: *state,
+                y_bottom: 0.0,
+            });
+            (*state, *x_offset) = (out.state, out.x_offset);
+        }
+    }
+    fn impulse_jiggle_with(&mut self, x: usize, f: impl FnOnce(SlimeState) -> SlimeState) {
+        if let Some((state, _)) = &mut self.slots[x] {
+            *state = f(*state);
+        }
+    }
+    fn peek_slime<R>(&self, x: usize, f: impl FnOnce(&SlimeState, f32) -> R) -> Option<R> {
+        self.slots[x].as_ref().map(|(state, _)| f(state, 0.0))
+    }
+    fn try_slide(&mut self, from: usize, positive: bool) -> Option<(usize, Cardinal4)> {
+        let (to, dir) = if positive {
+            (from + 1, Cardinal4::Right)
+        } else {
+            (from.checked_sub(1)?, Cardinal4::Left)
+        };
+        if self.slots.get(to)?.is_some() {
+            return None;
+        }
+        self.slots[to] = self.slots[from].take();
+        Some((to, dir))
+    }
+}
+
+#[test]
+fn a_slide_into_a_free_slot_arrives_and_shakes_the_next_one_along() {
+    let physprop = PhysicsProperties::default();
+    let mut board = Row::new(&[true, false, true]);
+    board.shove(0, 5.0);
+    let mut last = 0.0;
+    while let Some((SlimeState::Sliding { .. }, x_offset)) = board.slots[0] {
+        assert!(x_offset >= last && x_offset <= 1.0, "{x_offset}");
+        last = x_offset;
+        board.run_physics(1.0 / 60.0, &physprop);
+    }
+    assert_eq!(board.slots[0], None);
+    assert!(matches!(board.state(1), Some(SlimeState::Jiggling { .. })));
+    // Arriving sends a ripple on into the slime it came up against
+    assert!(matches!(board.state(2), Some(SlimeState::Jiggling { .. })));
+    assert!((0..600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+    assert_eq!(board.slots[1], Some((SlimeState::Settled, 0.0)));
+}
+
+#[test]
+fn a_blocked_slide_bounces_back_home() {
+    let physprop = PhysicsProperties {
+        slide_bounce: 0.5,
+        ..PhysicsProperties::default()
+    };
+    let mut board = Row::new(&[true, true]);
+    board.shove(0, 5.0);
+    let mut furthest = 0.0f32;
+    let mut bounced = false;
+    while let Some((
+        SlimeState::Sliding {
+            x_velocity,
+            returning,
+            ..
+        },
+        x_offset,
+    )) = board.slots[0]
+    {
+        furthest = furthest.max(x_offset);
+        if returning {
+            // Heading home at half the speed it hit with
+            assert_eq!(x_velocity, -2.5);
+            bounced = true;
+        }
+        board.run_physics(1.0 / 60.0, &physprop);
+    }
+    assert!(bounced);
+    assert_eq!(furthest, 1.0);
+    // Back in its own slot, still wobbling the way it bounced, and never made it into the other
+    assert!(matches!(
+        board.state(0),
+        Some(SlimeState::Jiggling { momentum_x, .. }) if momentum_x < 0.0
+    ));
+    assert_eq!(board.state(1), Some(SlimeState::Settled));
+    assert!((0..600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+    assert_eq!(board.slots[0], Some((SlimeState::Settled, 0.0)));
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// Where every slime is and how it's drawn, after each of `steps` steps.
+fn trajectory(
+    board: &mut DenseGridBoard,
+    physprop: &PhysicsProperties,
+    steps: usize,
+) -> Vec<Vec<(f32, f32, f32)>> {
+    (0..steps)
+        .map(|_| {
+            board.run_physics(1.0 / 60.0, physprop);
+            board
+                .cols()
+                .flatten()
+                .map(|(x, y)| {
+                    let cell = board.cell(x, y).unwrap();
+                    (cell.y_bottom, cell.render.y_scale, cell.render.x_offset)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Four columns three deep, part way through a landing in the second.
+fn mid_landing(physprop: &PhysicsProperties) -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(4, 6);
+    for x in 0..4 {
+        for y in 0..3 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board.set(1, 3, SlimeState::falling(physprop));
+    board.cell_mut(1, 3).unwrap().y_bottom = 5.0;
+    for _ in 0..40 {
+        board.run_physics(1.0 / 60.0, physprop);
+    }
+    board
+}
+
+#[test]
+fn restoring_replays_the_same_steps() {
+    let physprop = PhysicsProperties::default();
+    let mut board = mid_landing(&physprop);
+    assert!(!board.is_settled());
+    let snapshot = board.snapshot();
+    assert_eq!(snapshot.entries.len(), 13);
+    let first = trajectory(&mut board, &physprop, 200);
+    board.set(3, 3, SlimeState::falling(&physprop));
+    board.restore(&snapshot);
+    assert_eq!(board.snapshot(), snapshot);
+    assert_eq!(first, trajectory(&mut board, &physprop, 200));
+}
+
+#[test]
+fn erased_boards_restore_too() {
+    let physprop = PhysicsProperties::default();
+    let mut board = mid_landing(&physprop);
+    let snapshot = board.snapshot();
+    board.run_physics(1.0 / 60.0, &physprop);
+    let erased = &mut board as &mut dyn JigglyBoardDyn<f32, Loc = (usize, usize), Dir = Cardinal4>;
+    erased.restore(&snapshot);
+    assert_eq!(erased.snapshot(), snapshot);
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+#[test]
+fn a_spawning_slime_grows_and_lifts_the_stack() {
+    let physprop = PhysicsProperties {
+        spawn_duration: 0.25,
+        ..PhysicsProperties::default()
+    };
+    let dt = 1.0 / 60.0;
+    let mut board = DenseGridBoard::new(1, 3);
+    board.set(0, 0, SlimeState::Settled);
+    board.set(0, 1, SlimeState::Spawning { progress: 0.0 });
+    board.set(0, 2, SlimeState::Settled);
+    for step in 1..=15 {
+        assert!(!board.run_physics(dt, &physprop));
+        let progress = (step as f32 * dt / 0.25).min(1.0);
+        let render = board.cell(0, 1).unwrap().render;
+        assert!((render.y_scale - progress).abs() < 1e-5, "step {step}");
+        assert!((render.x_scale - progress).abs() < 1e-5, "step {step}");
+        assert_eq!(render.y_bottom, 1.0);
+        // Only takes up as much of the column as there is of it so far, which the slime above can't
+        // sink into
+        let above = board.cell(0, 2).unwrap().y_bottom;
+        assert!(above >= 1.0 + progress - 1e-5, "step {step}: {above}");
+    }
+    // Fully grown a quarter of a second in, with the slime above come to rest on it
+    assert!(!matches!(
+        board.get(0, 1),
+        Some(SlimeState::Spawning { .. })
+    ));
+    while !board.run_physics(dt, &physprop) {}
+    for y in 0..3 {
+        assert_eq!(board.get(0, y), Some(&SlimeState::Settled));
+        assert_eq!(board.cell(0, y).unwrap().y_bottom, y as f32);
+    }
+}
+
+#[test]
+fn a_slime_spawned_in_the_air_falls_once_grown() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(1, 2);
+    board.set(0, 0, SlimeState::Settled);
+    board.set(0, 1, SlimeState::Spawning { progress: 0.0 });
+    board.cell_mut(0, 1).unwrap().y_bottom = 4.0;
+    while let Some(SlimeState::Spawning { .. }) = board.get(0, 1) {
+        board.run_physics(1.0 / 60.0, &physprop);
+        assert_eq!(board.cell(0, 1).unwrap().y_bottom, 4.0);
+    }
+    assert!(matches!(board.get(0, 1), Some(SlimeState::Falling { .. })));
+    while !board.run_physics(1.0 / 60.0, &physprop) {}
+    assert_eq!(board.cell(0, 1).unwrap().y_bottom, 1.0);
+}
@@ -0,0 +1,199 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A one column board whose second slime up is ten times stiffer, or not stiff at all if `broken`.
+struct Springy {
+    grid: DenseGridBoard,
+    broken: bool,
+}
+
+impl JigglyBoard for Springy {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.grid.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.grid.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.grid.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.grid.impulse_jiggle_with(loc, f)
+    }
+    fn peek_slime<R>(
+        &self,
+        loc: (usize, usize),
+        f: impl FnOnce(&SlimeState, f32) -> R,
+    ) -> Option<R> {
+        self.grid.peek_slime(loc, f)
+    }
+    fn props_for(&self, (_, y): (usize, usize), base: &PhysicsProperties) -> PhysicsProperties {
+        let jiggle_stiff = match (y, self.broken) {
+            (1, true) => 0.0,
+            (1, false) => base.jiggle_stiff * 10.0,
+            _ => base.jiggle_stiff,
+        };
+        PhysicsProperties {
+            jiggle_stiff,
+            ..*base
+        }
+    }
+}
+
+fn springy(broken: bool) -> Springy {
+    let mut grid = DenseGridBoard::new(1, 4);
+    for y in 0..3 {
+        grid.set(0, y, SlimeState::Settled);
+    }
+    grid.set(0, 0, SlimeState::landed());
+    Springy { grid, broken }
+}
+
+#[test]
+fn spring_from_its_frequency() {
+    let physprop = PhysicsProperties::from_spring(4.0, 0.3, 1.0 / 60.0).unwrap();
+    assert_eq!(physprop.validate(), Ok(()));
+    let clamped = PhysicsProperties::from_spring(1000.0, 0.3, 1.0 / 60.0).unwrap();
+    let fastest = PhysicsProperties::max_stable_frequency(1.0 / 60.0);
+    let omega = 2.0 * core::f32::consts::PI * fastest;
+    assert!((clamped.jiggle_stiff - omega * omega).abs() < 1e-2);
+}
+
+#[test]
+fn spring_oscillates_at_its_frequency() {
+    for (frequency_hz, dt) in [(4.0, 1.0 / 240.0), (2.0, 1.0 / 60.0), (6.0, 1.0 / 120.0)] {
+        let physprop = PhysicsProperties::from_spring(frequency_hz, 0.1, dt).unwrap();
+        let mut board = DenseGridBoard::new(1, 1);
+        board.set(
+            0,
+            0,
+            SlimeState::Jiggling {
+                momentum: 0.0,
+                offset: 0.3,
+                life: 1.0,
+                momentum_x: 0.0,
+                offset_x: 0.0,
+            },
+        );
+        // Each time it goes from stretched back to squashed, in steps
+        let mut crossings = Vec::new();
+        let mut last = 0.3;
+        for step in 0.. {
+            board.run_physics(dt, &physprop);
+            let Some(&SlimeState::Jiggling { offset, .. }) = board.get(0, 0) else {
+                break;
+            };
+            if last < 0.0 && offset >= 0.0 {
+                crossings.push(step as f32);
+            }
+            last = offset;
+        }
+        assert!(crossings.len() >= 2, "{frequency_hz}Hz: {crossings:?}");
+        let periods = crossings.len() - 1;
+        let period = (crossings[periods] - crossings[0]) * dt / periods as f32;
+        let expected = 1.0 / frequency_hz;
+        assert!(
+            (period - expected).abs() < expected * 0.1,
+            "{frequency_hz}Hz: {period}s rather than {expected}s"
+        );
+    }
+}
+
+#[test]
+fn no_spring_is_an_error() {
+    let err = PhysicsProperties::from_spring(0.0, 0.3, 1.0 / 60.0).unwrap_err();
+    assert_eq!(err.field(), "jiggle_stiff");
+    assert!(PhysicsProperties::from_spring(-3.0, 0.3, 1.0 / 60.0).is_err());
+    let err = PhysicsProperties::from_spring(4.0, 0.3, 0.0).unwrap_err();
+    assert_eq!(err.field(), "dt");
+    assert!(PhysicsProperties::from_spring(4.0, 0.3, f32::NAN).is_err());
+}
+
+#[test]
+fn overrides_are_checked_before_stepping() {
+    let physprop = PhysicsProperties::default();
+    let mut fine = springy(false);
+    assert_eq!(fine.validate_props(&physprop), Ok(()));
+    assert_eq!(fine.try_run_physics(1.0 / 60.0, &physprop), Ok(false));
+    let mut broken = springy(true);
+    let err = broken.try_run_physics(1.0 / 60.0, &physprop).unwrap_err();
+    assert_eq!(err.field(), "jiggle_stiff");
+    assert!(matches!(
+        broken.grid.get(0, 0),
+        Some(SlimeState::Jiggling { momentum, .. }) if *momentum == 0.0
+    ));
+}
+
+#[test]
+fn try_run_physics_leaves_the_board_alone() {
+    let physprop = PhysicsProperties {
+        jiggle_stiff: 0.0,
+        ..PhysicsProperties::default()
+    };
+    let mut board = springy(false);
+    let before = board.snapshot();
+    let err = board.try_run_physics(1.0 / 60.0, &physprop).unwrap_err();
+    assert_eq!(err.field(), "jiggle_stiff");
+    assert_eq!(before.entries.len(), 3);
+    assert_eq!(board.snapshot(), before);
+}
+
+#[test]
+fn a_stiffer_neighbour_settles_sooner() {
+    let physprop = PhysicsProperties::default();
+    let mut board = springy(false);
+    let poked = SlimeState::Jiggling {
+        momentum: 2.0,
+        offset: 0.0,
+        life: 1.0,
+        momentum_x: 0.0,
+        offset_x: 0.0,
+    };
+    board.grid.set(0, 0, SlimeState::Settled);
+    board.grid.set(0, 1, poked);
+    board.grid.set(0, 2, poked);
+    // The step each of the two poked slimes stopped on
+    let mut settled_at = [None; 2];
+    for step in 0..600 {
+        board.run_physics(1.0 / 60.0, &physprop);
+        for (y, at) in (1..3).zip(&mut settled_at) {
+            if at.is_none() && board.grid.get(0, y) == Some(&SlimeState::Settled) {
+                *at = Some(step);
+            }
+        }
+    }
+    let [Some(stiff), Some(soft)] = settled_at else {
+        panic!("{settled_at:?}");
+    };
+    assert!(
+        stiff * 3 < soft * 2,
+        "stiff after {stiff} steps, soft after {soft}"
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "jiggle_stiff")]
+fn invalid_properties_fail_loudly_in_debug_builds() {
+    let physprop = PhysicsProperties {
+        jiggle_stiff: 0.0,
+        ..PhysicsProperties::default()
+    };
+    springy(false).run_physics(1.0 / 60.0, &physprop);
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+const MIN_X_SCALE: f32 = 0.6;
+const MAX_X_SCALE: f32 = 1.6;
+
+fn preserving() -> PhysicsProperties {
+    PhysicsProperties {
+        squash_mode: SquashMode::PreserveArea {
+            min_x_scale: MIN_X_SCALE,
+            max_x_scale: MAX_X_SCALE,
+        },
+        ..PhysicsProperties::default()
+    }
+}
+
+/// One step of a board with a slime per column in each of `states`, each `y_bottom` up, and how each
+/// is drawn after it.
+fn drawn(states: &[SlimeState], y_bottom: f32, physprop: &PhysicsProperties) -> Vec<RenderProps> {
+    let mut board = DenseGridBoard::new(states.len(), 1);
+    for (x, &state) in states.iter().enumerate() {
+        board.set(x, 0, state);
+        board.cell_mut(x, 0).unwrap().y_bottom = y_bottom;
+    }
+    board.run_physics(1.0 / 60.0, physprop);
+    (0..states.len())
+        .map(|x| board.cell(x, 0).unwrap().render)
+        .collect()
+}
+
+/// Check `render` keeps its area, unless that would take `x_scale` out of the clamp.
+fn assert_area_kept(render: RenderProps) {
+    let RenderProps {
+        x_scale, y_scale, ..
+    } = render;
+    let wanted = y_scale.recip();
+    if (MIN_X_SCALE..=MAX_X_SCALE).contains(&wanted) {
+        assert!((x_scale * y_scale - 1.0).abs() < 1e-5, "{render:?}");
+    } else {
+        assert_eq!(
+            x_scale,
+            wanted.clamp(MIN_X_SCALE, MAX_X_SCALE),
+            "{render:?}"
+        );
+    }
+}
+
+#[test]
+fn jiggling_slimes_keep_their_area() {
+    let physprop = preserving();
+    let states: Vec<_> = (-10..=10)
+        .map(|i| SlimeState::Jiggling {
+            momentum: 0.0,
+            offset: i as f32 * 0.08,
+            life: 1.0,
+            momentum_x: 0.0,
+            offset_x: 0.0,
+        })
+        .collect();
+    let renders = drawn(&states, 0.0, &physprop);
+    // Both ends of the range get clamped, so it's not just the easy middle being checked
+    assert!(renders.iter().any(|r| r.x_scale == MIN_X_SCALE));
+    assert!(renders.iter().any(|r| r.x_scale == MAX_X_SCALE));
+    for render in renders {
+        assert_area_kept(render);
+    }
+}
+
+#[test]
+fn falling_slimes_keep_their_area() {
+    let physprop = preserving();
+    let states: Vec<_> = (0..=10)
+        .map(|i| SlimeState::Falling {
+            velocity: i as f32 * 2.0,
+            fallen: 0.0,
+        })
+        .collect();
+    let renders = drawn(&states, 10.0, &physprop);
+    assert!(renders.iter().any(|r| r.y_scale > 1.5));
+    for render in renders {
+        assert_area_kept(render);
+    }
+}
+
+#[test]
+fn legacy_is_still_the_default() {
+    let physprop = PhysicsProperties::default();
+    assert_eq!(physprop.squash_mode, SquashMode::Legacy);
+    let squashed = SlimeState::Jiggling {
+        momentum: 0.0,
+        offset: 0.6,
+        life: 1.0,
+        momentum_x: 0.0,
+        offset_x: 0.0,
+    };
+    let [render] = drawn(&[squashed], 0.0, &physprop)[..] else {
+        unreachable!()
+    };
+    // Legacy stops widening at half height, so this much squash loses area
+    assert!(render.x_scale * render.y_scale < 0.95, "{render:?}");
+}
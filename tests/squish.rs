@@ -0,0 +1,56 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+fn stack() -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(1, 2);
+    board.set(0, 0, SlimeState::Settled);
+    board.set(0, 1, SlimeState::Settled);
+    board
+}
+
+#[test]
+fn a_squished_slime_holds_the_stack_down() {
+    let physprop = PhysicsProperties::default();
+    let mut board = stack();
+    board.squish((0, 0), 0.4);
+    for _ in 0..30 {
+        assert!(board.run_physics(1.0 / 60.0, &physprop));
+        assert_eq!(board.get(0, 0), Some(&SlimeState::Squished { amount: 0.4 }));
+        assert!((board.cell(0, 0).unwrap().render.y_scale - 0.6).abs() < 1e-6);
+        assert!((board.cell(0, 1).unwrap().y_bottom - 0.6).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn letting_go_springs_back_with_the_squish() {
+    let physprop = PhysicsProperties {
+        squish_release_momentum: 8.0,
+        ..PhysicsProperties::default()
+    };
+    for (amount, kept) in [(0.0, 0.0), (0.25, 0.25), (0.5, 0.5), (1.0, 1.0), (1.5, 1.0)] {
+        let mut board = stack();
+        board.squish((0, 0), amount);
+        board.unsquish((0, 0), &physprop);
+        let Some(&SlimeState::Jiggling {
+            momentum, offset, ..
+        }) = board.get(0, 0)
+        else {
+            panic!("{amount}: {:?}", board.get(0, 0));
+        };
+        // Up and away from the squish, harder the further it was pressed
+        assert_eq!(momentum, -kept * 8.0, "{amount}");
+        assert_eq!(offset, kept, "{amount}");
+    }
+}
+
+#[test]
+fn only_squished_slimes_spring_back() {
+    let physprop = PhysicsProperties::default();
+    let mut board = stack();
+    board.unsquish((0, 0), &physprop);
+    assert_eq!(board.get(0, 0), Some(&SlimeState::Settled));
+    board.set(0, 1, SlimeState::falling(&physprop));
+    board.squish((0, 1), 0.5);
+    assert!(matches!(board.get(0, 1), Some(SlimeState::Falling { .. })));
+}
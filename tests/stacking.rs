@@ -0,0 +1,32 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+#[test]
+fn fallers_a_cell_apart_land_on_each_other() {
+    let physprop = PhysicsProperties::default();
+    for dt in [1.0 / 30.0, 1.0 / 60.0, 1.0 / 240.0] {
+        let mut board = DenseGridBoard::new(1, 3);
+        board.set(0, 0, SlimeState::Settled);
+        board.set(0, 1, SlimeState::falling(&physprop));
+        board.set(0, 2, SlimeState::falling(&physprop));
+        board.cell_mut(0, 1).unwrap().y_bottom = 4.0;
+        board.cell_mut(0, 2).unwrap().y_bottom = 6.0;
+        let mut steps = 0;
+        while !board.run_physics(dt, &physprop) {
+            // The upper one never gets into the lower one, however squashed that is
+            let lower = board.cell(0, 1).unwrap().render;
+            let upper = board.cell(0, 2).unwrap().y_bottom;
+            assert!(
+                upper >= lower.y_bottom + lower.y_scale - 1e-4,
+                "{dt}, step {steps}: {lower:?}, {upper}"
+            );
+            steps += 1;
+            assert!(steps < 2400, "never settled");
+        }
+        for y in 0..3 {
+            assert_eq!(board.get(0, y), Some(&SlimeState::Settled));
+            assert_eq!(board.cell(0, y).unwrap().y_bottom, y as f32);
+        }
+    }
+}
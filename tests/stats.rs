@@ -0,0 +1,47 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+#[test]
+fn counters_follow_a_drop_and_its_ripple() {
+    let physprop = PhysicsProperties::default();
+    let dt = 1.0 / 60.0;
+    let mut board = DenseGridBoard::new(3, 2);
+    for x in 0..3 {
+        board.set(x, 0, SlimeState::Settled);
+    }
+    board.set(1, 1, SlimeState::falling(&physprop));
+    board.cell_mut(1, 1).unwrap().y_bottom = 1.1;
+    // Picking up speed for four steps
+    for step in 1..=4 {
+        let stats = board.run_physics_stats(dt, &physprop);
+        assert!(!stats.settled);
+        assert_eq!((stats.falling, stats.jiggling), (1, 0));
+        assert_eq!((stats.landings, stats.propagations), (0, 0));
+        let expected = physprop.gravity * dt * step as f32;
+        assert!((stats.max_velocity - expected).abs() < 1e-4, "{stats:?}");
+        assert_eq!(stats.max_offset, 0.0);
+    }
+    // Then landing, which reaches itself, the slime it landed on and the two either side of that
+    let stats = board.run_physics_stats(dt, &physprop);
+    assert_eq!((stats.falling, stats.jiggling), (0, 1));
+    assert_eq!((stats.landings, stats.propagations), (1, 4));
+    assert_eq!(stats.max_velocity, 0.0);
+    // All four jiggle, fewer and fewer of them, until they've all stopped
+    let mut jiggling = 4;
+    loop {
+        let stats = board.run_physics_stats(dt, &physprop);
+        assert_eq!(
+            (stats.falling, stats.landings, stats.propagations),
+            (0, 0, 0)
+        );
+        assert!(stats.jiggling <= jiggling, "{stats:?}");
+        jiggling = stats.jiggling;
+        assert_eq!(stats.max_offset > 0.0, jiggling > 0, "{stats:?}");
+        assert_eq!(stats.truncated, 0);
+        if stats.settled {
+            assert_eq!(jiggling, 0);
+            break;
+        }
+    }
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// Two slimes coming down onto a board two deep, one of them from higher up.
+fn dropping() -> DenseGridBoard {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(4, 4);
+    for x in 0..4 {
+        for y in 0..2 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    for (x, y_bottom) in [(1, 3.0), (3, 2.4)] {
+        board.set(x, 2, SlimeState::falling(&physprop));
+        board.cell_mut(x, 2).unwrap().y_bottom = y_bottom;
+    }
+    board.launch((0, 1), 2.0);
+    board
+}
+
+#[test]
+fn column_by_column_matches_run_physics() {
+    for wave_mode in [WaveMode::Separate, WaveMode::Coalesced] {
+        let physprop = PhysicsProperties {
+            wave_mode,
+            ..PhysicsProperties::default()
+        };
+        let dt = 1.0 / 60.0;
+        let mut whole = dropping();
+        let mut by_column = dropping();
+        let mut landings = 0;
+        for step in 0..600 {
+            let settled = whole.run_physics(dt, &physprop);
+            let cols: Vec<Vec<_>> = by_column.cols().map(Iterator::collect).collect();
+            let mut propagations = Vec::new();
+            for (col_index, col) in cols.into_iter().enumerate() {
+                by_column.step_column(col_index, col.into_iter(), dt, &physprop, &mut propagations);
+            }
+            landings += propagations.len();
+            by_column.apply_propagations(propagations, &physprop);
+            assert_eq!(
+                whole.snapshot(),
+                by_column.snapshot(),
+                "{wave_mode:?}, step {step}"
+            );
+            if settled {
+                break;
+            }
+        }
+        assert!(whole.is_settled(), "{wave_mode:?}");
+        // The two drops and the launch all came down
+        assert!(landings >= 3, "{wave_mode:?}: {landings}");
+    }
+}
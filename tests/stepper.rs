@@ -0,0 +1,69 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A slime dropped from so high up it's still falling when the tests are done with it.
+fn falling_forever() -> DenseGridBoard {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(1, 2);
+    board.set(0, 0, SlimeState::Settled);
+    board.set(0, 1, SlimeState::falling(&physprop));
+    board.cell_mut(0, 1).unwrap().y_bottom = 10000.0;
+    board
+}
+
+#[test]
+fn simulated_time_keeps_up_with_the_wall_clock() {
+    let physprop = PhysicsProperties::default();
+    let mut board = falling_forever();
+    let mut stepper = FixedStepper::new(1.0 / 120.0);
+    let dt = f64::from(stepper.dt);
+    let (mut wall, mut steps) = (0.0, 0);
+    // Frames from 2 to 50 ms, never more than max_steps' worth
+    let mut seed = 12345u32;
+    for frame in 0..500 {
+        seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+        let real_dt = 0.002 + f64::from(seed >> 8) / f64::from(1u32 << 24) * 0.048;
+        wall += real_dt;
+        let report = stepper.advance(real_dt as f32, &mut board, &physprop);
+        assert!(!report.settled);
+        steps += report.steps;
+        let simulated = f64::from(steps) * dt;
+        let behind = wall - simulated;
+        assert!(
+            (-1e-4..dt + 1e-4).contains(&behind),
+            "frame {frame}: {behind}s behind"
+        );
+        let leftover = f64::from(report.alpha) * dt;
+        assert!((behind - leftover).abs() < 1e-4, "frame {frame}");
+    }
+}
+
+#[test]
+fn a_hitch_only_catches_up_so_far() {
+    let physprop = PhysicsProperties::default();
+    let mut board = falling_forever();
+    let mut stepper = FixedStepper::new(1.0 / 120.0).with_max_steps(4);
+    let report = stepper.advance(1.0, &mut board, &physprop);
+    assert_eq!(report.steps, 4);
+    assert!((0.0..1.0).contains(&report.alpha));
+    // The rest of the second is dropped rather than run over the next frames
+    let report = stepper.advance(1.0 / 120.0, &mut board, &physprop);
+    assert!(report.steps <= 1);
+}
+
+#[test]
+fn settling_skips_the_rest_of_the_frame() {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(1, 1);
+    board.set(0, 0, SlimeState::Settled);
+    let mut stepper = FixedStepper::new(1.0 / 120.0);
+    let report = stepper.advance(0.05, &mut board, &physprop);
+    assert_eq!(report.steps, 1);
+    assert!(report.settled);
+    assert!(report.alpha < 1.0);
+    // A frame too short to step still says it's settled
+    let report = stepper.advance(0.001, &mut board, &physprop);
+    assert_eq!(report.steps, 0);
+    assert!(report.settled);
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A slime's jiggle offset after each step, from being squashed down, until it stops jiggling.
+fn offsets(physprop: &PhysicsProperties) -> Vec<f32> {
+    let mut board = DenseGridBoard::new(1, 1);
+    board.set(
+        0,
+        0,
+        SlimeState::Jiggling {
+            momentum: 0.0,
+            offset: 0.3,
+            life: 1.0,
+            momentum_x: 0.0,
+            offset_x: 0.0,
+        },
+    );
+    let mut offsets = Vec::new();
+    for _ in 0..600 {
+        board.run_physics(1.0 / 240.0, physprop);
+        match board.get(0, 0) {
+            Some(SlimeState::Jiggling { offset, .. }) => offsets.push(*offset),
+            _ => return offsets,
+        }
+    }
+    panic!("never settled");
+}
+
+/// How many steps each half of the first swing, squashed then stretched, lasted.
+fn first_swing(offsets: &[f32]) -> (usize, usize) {
+    let squashed = offsets.iter().take_while(|o| **o > 0.0).count();
+    let stretched = offsets[squashed..]
+        .iter()
+        .take_while(|o| **o <= 0.0)
+        .count();
+    (squashed, stretched)
+}
+
+#[test]
+fn stiffer_stretch_snaps_back_faster() {
+    let even = PhysicsProperties::default();
+    let stiff_stretch = PhysicsProperties {
+        jiggle_stiff_stretch: Some(even.jiggle_stiff * 4.0),
+        ..even
+    };
+    let (even, stiff_stretch) = (offsets(&even), offsets(&stiff_stretch));
+    let (squashed, stretched) = first_swing(&even);
+    let (squashed_too, stretched_faster) = first_swing(&stiff_stretch);
+    // Squashing goes just the same, up to where it starts stretching
+    assert_eq!(squashed, squashed_too);
+    assert_eq!(even[..squashed], stiff_stretch[..squashed]);
+    // Four times as stiff would be twice as fast undamped
+    assert!(
+        stretched_faster * 10 < stretched * 6,
+        "{stretched_faster} rather than {stretched}"
+    );
+    // And it doesn't stretch out as far
+    let deepest = |offsets: &[f32]| offsets.iter().copied().fold(0.0, f32::min);
+    assert!(deepest(&stiff_stretch) > deepest(&even) * 0.75);
+}
+
+#[test]
+fn no_stretch_stiffness_is_the_same_as_jiggle_stiff() {
+    let default = PhysicsProperties::default();
+    assert_eq!(default.jiggle_stiff_stretch, None);
+    let explicit = PhysicsProperties {
+        jiggle_stiff_stretch: Some(default.jiggle_stiff),
+        ..default
+    };
+    assert_eq!(offsets(&default), offsets(&explicit));
+}
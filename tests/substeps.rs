@@ -0,0 +1,76 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A slime about to land on another, stiff enough to stop jiggling a step after it lands.
+fn about_to_land() -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(1, 3);
+    board.set(0, 0, SlimeState::Settled);
+    board.set(
+        0,
+        1,
+        SlimeState::Falling {
+            velocity: 1.0,
+            fallen: 0.0,
+        },
+    );
+    board.cell_mut(0, 1).unwrap().y_bottom = 1.001;
+    board
+}
+
+#[test]
+fn a_call_is_only_settled_if_every_substep_is() {
+    let physprop = PhysicsProperties {
+        jiggle_offset_epsilon: 0.5,
+        jiggle_momentum_epsilon: 5.0,
+        max_dt: 1.0 / 120.0,
+        ..PhysicsProperties::default()
+    };
+    let mut stepped = about_to_land();
+    let mut scratch = PhysicsScratch::new();
+    let settled: Vec<bool> = (0..3)
+        .map(|_| stepped.run_physics_with_scratch(1.0 / 120.0, &physprop, &mut scratch, &mut ()))
+        .collect();
+    assert_eq!(settled, [false, false, true]);
+    let mut board = about_to_land();
+    assert!(!board.run_physics(3.0 / 120.0, &physprop));
+    assert!(board.is_settled());
+    assert!(board.run_physics(3.0 / 120.0, &physprop));
+}
+
+/// How far from rest a slime jiggling from `offset` 0.3 gets over two seconds of 0.25 s frames.
+fn furthest_at_quarter_second_frames(physprop: &PhysicsProperties) -> f32 {
+    let mut board = DenseGridBoard::new(1, 1);
+    board.set(
+        0,
+        0,
+        SlimeState::Jiggling {
+            momentum: 0.0,
+            offset: 0.3,
+            life: 1.0,
+            momentum_x: 0.0,
+            offset_x: 0.0,
+        },
+    );
+    let mut furthest = 0.0f32;
+    for _ in 0..8 {
+        board.run_physics(0.25, physprop);
+        if let Some(&SlimeState::Jiggling { offset, .. }) = board.get(0, 0) {
+            furthest = furthest.max(offset.abs());
+        }
+    }
+    furthest
+}
+
+#[test]
+fn a_hitch_stays_bounded() {
+    let physprop = PhysicsProperties {
+        max_dt: 1.0 / 60.0,
+        max_substeps: 16,
+        ..PhysicsProperties::default()
+    };
+    let furthest = furthest_at_quarter_second_frames(&physprop);
+    assert!(furthest <= 0.3, "{furthest}");
+    let whole = furthest_at_quarter_second_frames(&PhysicsProperties::default());
+    assert!(whole > 1.0, "{whole}");
+}
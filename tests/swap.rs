@@ -0,0 +1,141 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A grid whose slimes have names, so it can be told which one ended up where.
+struct Named {
+    grid: DenseGridBoard,
+    names: Vec<((usize, usize), char)>,
+}
+
+impl Named {
+    fn name(&self, loc: (usize, usize)) -> char {
+        self.names.iter().find(|(at, _)| *at == loc).unwrap().1
+    }
+}
+
+impl JigglyBoard for Named {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.grid.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.grid.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.grid.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.grid.impulse_jiggle_with(loc, f)
+    }
+    fn peek_slime<R>(
+        &self,
+        loc: (usize, usize),
+        f: impl FnOnce(&SlimeState, f32) -> R,
+    ) -> Option<R> {
+        self.grid.peek_slime(loc, f)
+    }
+    fn exchange_slimes(&mut self, a: (usize, usize), b: (usize, usize)) -> Option<(f32, f32)> {
+        let moved = self.grid.exchange_slimes(a, b)?;
+        for (at, _) in &mut self.names {
+            if *at == a {
+                *at = b;
+            } else if *at == b {
+                *at = a;
+            }
+        }
+        Some(moved)
+    }
+}
+
+/// Two columns of two, named `a` to `d` bottom left first.
+fn named() -> Named {
+    let mut grid = DenseGridBoard::new(2, 2);
+    let mut names = Vec::new();
+    for (loc, name) in [((0, 0), 'a'), ((0, 1), 'b'), ((1, 0), 'c'), ((1, 1), 'd')] {
+        grid.set(loc.0, loc.1, SlimeState::Settled);
+        names.push((loc, name));
+    }
+    Named { grid, names }
+}
+
+#[test]
+fn swapped_slimes_settle_in_each_others_places() {
+    let physprop = PhysicsProperties::default();
+    let mut board = named();
+    assert!(board.swap((0, 0), (1, 0), &physprop));
+    let mut last = 1.0f32;
+    while let Some(SlimeState::Swapping { .. }) = board.grid.get(0, 0) {
+        board.run_physics(1.0 / 60.0, &physprop);
+        // Drawn on the way over from the right, but the stacks above stay put
+        let render = board.grid.cell(0, 0).unwrap().render;
+        assert!(render.x_offset <= last, "{render:?}");
+        last = render.x_offset;
+        assert_eq!(
+            board.grid.cell(1, 0).unwrap().render.x_offset,
+            -render.x_offset
+        );
+        for x in 0..2 {
+            assert_eq!(board.grid.cell(x, 0).unwrap().y_bottom, 0.0);
+            assert_eq!(board.grid.cell(x, 1).unwrap().y_bottom, 1.0);
+        }
+    }
+    // Arrive with a bit of a squish
+    assert!(matches!(
+        board.grid.get(0, 0),
+        Some(SlimeState::Jiggling { .. })
+    ));
+    assert!(matches!(
+        board.grid.get(1, 0),
+        Some(SlimeState::Jiggling { .. })
+    ));
+    assert!((0..600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+    assert_eq!(board.name((0, 0)), 'c');
+    assert_eq!(board.name((1, 0)), 'a');
+    for x in 0..2 {
+        for y in 0..2 {
+            let cell = board.grid.cell(x, y).unwrap();
+            assert_eq!(cell.state, SlimeState::Settled);
+            assert_eq!(cell.y_bottom, y as f32);
+            assert_eq!(cell.render.x_offset, 0.0);
+        }
+    }
+}
+
+#[test]
+fn an_impulse_mid_swap_arrives_with_it() {
+    let physprop = PhysicsProperties::default();
+    let mut board = named();
+    assert!(board.swap((0, 0), (1, 0), &physprop));
+    board.run_physics(1.0 / 60.0, &physprop);
+    board.impulse_signed((0, 1), 2.0, Cardinal4::Up, &physprop);
+    let Some(&SlimeState::Swapping {
+        buffered_impulse, ..
+    }) = board.grid.get(0, 0)
+    else {
+        panic!("{:?}", board.grid.get(0, 0));
+    };
+    assert!(buffered_impulse > 0.0);
+    while let Some(SlimeState::Swapping { .. }) = board.grid.get(0, 0) {
+        board.run_physics(1.0 / 60.0, &physprop);
+    }
+    assert!(matches!(
+        board.grid.get(0, 0),
+        Some(SlimeState::Jiggling { momentum, .. })
+            if *momentum == physprop.swap_momentum + buffered_impulse
+    ));
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// A column of four beside a single slime, with another dropping onto that one from just above.
+fn board(physprop: &PhysicsProperties) -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(2, 6);
+    for y in 0..4 {
+        board.set(1, y, SlimeState::Settled);
+    }
+    board.set(0, 0, SlimeState::Settled);
+    board.set(0, 1, SlimeState::falling(physprop));
+    board.cell_mut(0, 1).unwrap().y_bottom = 2.0;
+    board
+}
+
+#[test]
+fn swapping_into_a_lower_slot_lands_at_once() {
+    let physprop = PhysicsProperties::default();
+    let mut board = board(&physprop);
+    assert!(board.swap_cells((0, 1), (1, 3), &physprop, 0.5));
+    assert!(matches!(board.get(1, 3), Some(SlimeState::Jiggling { .. })));
+    assert_eq!(board.cell(1, 3).unwrap().y_bottom, 3.0);
+    assert!(matches!(board.get(0, 1), Some(SlimeState::Jiggling { .. })));
+    assert_eq!(board.cell(0, 1).unwrap().y_bottom, 2.0);
+    assert!((0..3600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+    assert!((board.cell(1, 3).unwrap().y_bottom - 3.0).abs() < 1e-4);
+    assert!((board.cell(0, 1).unwrap().y_bottom - 1.0).abs() < 1e-4);
+}
+
+#[test]
+fn swapping_a_slime_in_the_air_keeps_its_height() {
+    let physprop = PhysicsProperties::default();
+    let mut board = board(&physprop);
+    board.cell_mut(0, 1).unwrap().y_bottom = 5.0;
+    assert!(board.swap_cells((0, 1), (1, 1), &physprop, 0.5));
+    assert!(matches!(board.get(1, 1), Some(SlimeState::Falling { .. })));
+    assert_eq!(board.cell(1, 1).unwrap().y_bottom, 5.0);
+}
+
+#[test]
+fn swapping_with_nothing_or_itself_does_nothing() {
+    let physprop = PhysicsProperties::default();
+    let mut board = board(&physprop);
+    assert!(!board.swap_cells((1, 2), (1, 2), &physprop, 0.5));
+    assert!(matches!(board.get(1, 2), Some(SlimeState::Settled)));
+    assert!(!board.swap_cells((0, 0), (0, 5), &physprop, 0.5));
+    assert!(matches!(board.get(0, 0), Some(SlimeState::Settled)));
+    assert!(board.get(0, 5).is_none());
+}
@@ -0,0 +1,85 @@
+use jiggly_fever::*;
+
+#[derive(Clone, Copy, PartialEq)]
+struct Up;
+
+impl Direction for Up {
+    fn other_directions(self) -> impl Iterator<Item = Self> {
+        core::iter::empty()
+    }
+    fn opposite(self) -> Self {
+        self
+    }
+    const UP: Self = Up;
+}
+
+/// A board of one slime, falling onto the floor.
+struct One {
+    state: SlimeState,
+    y_bottom: f32,
+}
+
+impl JigglyBoard for One {
+    type Dir = Up;
+    type Loc = ();
+    fn apply_dir_to_loc(&self, _: Up, _: (), _: f32) -> Option<((), f32)> {
+        None
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = ()>> {
+        core::iter::once(core::iter::once(()))
+    }
+    fn mut_slime_with(&mut self, _: (), f: impl FnOnce(SlimePropsIn) -> SlimePropsOut) {
+        let state = std::mem::replace(&mut self.state, SlimeState::Settled);
+        let out = f(SlimePropsIn {
+            state,
+            y_bottom: self.y_bottom,
+        });
+        self.state = out.state;
+        self.y_bottom = out.y_bottom;
+    }
+    fn impulse_jiggle_with(&mut self, _: (), f: impl FnOnce(SlimeState) -> SlimeState) {
+        let state = std::mem::replace(&mut self.state, SlimeState::Settled);
+        self.state = f(state);
+    }
+}
+
+/// Drop one slime from `height` cells up, returning the impulse it landed with.
+///
+/// Nothing's around to pass the landing on to, so it all goes into the slime's own momentum.
+fn landing_impulse(height: f32, physprop: &PhysicsProperties) -> f32 {
+    let mut one = One {
+        state: SlimeState::Falling {
+            velocity: 0.0,
+            fallen: 0.0,
+        },
+        y_bottom: height,
+    };
+    for _ in 0..10_000 {
+        one.run_physics(1.0 / 60.0, physprop);
+        if let SlimeState::Jiggling { momentum, .. } = one.state {
+            return momentum;
+        }
+    }
+    panic!("never landed");
+}
+
+#[test]
+fn landing_from_high_up_hits_at_terminal_velocity() {
+    let physprop = PhysicsProperties {
+        terminal_velocity: 20.0,
+        ..PhysicsProperties::default()
+    };
+    let impulse = landing_impulse(100.0, &physprop);
+    assert_eq!(
+        impulse,
+        physprop.velocity_to_impact * physprop.terminal_velocity
+    );
+}
+
+#[test]
+fn infinite_terminal_velocity_keeps_accelerating() {
+    let physprop = PhysicsProperties::default();
+    assert_eq!(physprop.terminal_velocity, f32::INFINITY);
+    let impulse = landing_impulse(100.0, &physprop);
+    assert!(impulse > physprop.velocity_to_impact * 50.0);
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "alloc")]
+
+use std::collections::BTreeMap;
+
+use jiggly_fever::*;
+
+/// A board whose right hand column passes on only a quarter of what the rest do sideways.
+struct Lopsided {
+    grid: DenseGridBoard,
+}
+
+impl JigglyBoard for Lopsided {
+    type Dir = Cardinal4;
+    type Loc = (usize, usize);
+    fn apply_dir_to_loc(
+        &self,
+        dir: Cardinal4,
+        loc: (usize, usize),
+        impulse: f32,
+    ) -> Option<((usize, usize), f32)> {
+        self.grid.apply_dir_to_loc(dir, loc, impulse)
+    }
+    fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = (usize, usize)>> {
+        self.grid.cols()
+    }
+    fn mut_slime_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimePropsIn) -> SlimePropsOut,
+    ) {
+        self.grid.mut_slime_with(loc, f)
+    }
+    fn impulse_jiggle_with(
+        &mut self,
+        loc: (usize, usize),
+        f: impl FnOnce(SlimeState) -> SlimeState,
+    ) {
+        self.grid.impulse_jiggle_with(loc, f)
+    }
+    fn props_for(&self, (x, _): (usize, usize), base: &PhysicsProperties) -> PhysicsProperties {
+        PhysicsProperties {
+            transfer_horizontal: if x == 2 { 0.25 } else { 1.0 },
+            ..*base
+        }
+    }
+}
+
+/// A row of three with the slime at `dropped` landing in it, and what reached each slime as it did.
+fn row_landing<B: JigglyBoard<Loc = (usize, usize), Dir = Cardinal4>>(
+    board: &mut B,
+    physprop: &PhysicsProperties,
+) -> BTreeMap<(usize, usize), f32> {
+    let mut reached = BTreeMap::new();
+    for _ in 0..600 {
+        board.run_physics_with_listener(1.0 / 60.0, physprop, &mut |event: PropagationEvent<
+            _,
+            _,
+        >| {
+            *reached.entry(event.loc).or_insert(0.0) += event.impulse;
+        });
+        if !reached.is_empty() {
+            return reached;
+        }
+    }
+    panic!("never landed");
+}
+
+fn row(dropped: usize, physprop: &PhysicsProperties) -> DenseGridBoard {
+    let mut grid = DenseGridBoard::new(3, 1);
+    for x in 0..3 {
+        grid.set(x, 0, SlimeState::Settled);
+    }
+    grid.set(dropped, 0, SlimeState::falling(physprop));
+    grid.cell_mut(dropped, 0).unwrap().y_bottom = 1.0;
+    grid
+}
+
+#[test]
+fn sideways_decay_follows_transfer_horizontal() {
+    for transfer_horizontal in [1.0, 0.5, 0.2] {
+        let physprop = PhysicsProperties {
+            transfer_horizontal,
+            min_impactable: 1e-3,
+            ..PhysicsProperties::default()
+        };
+        let reached = row_landing(&mut row(0, &physprop), &physprop);
+        // The grid's own half, then the tuning's share, at each hop
+        let per_hop = 0.5 * transfer_horizontal;
+        assert!((reached[&(1, 0)] - reached[&(0, 0)] * per_hop).abs() < 1e-4);
+        assert!((reached[&(2, 0)] - reached[&(1, 0)] * per_hop).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn down_the_stack_follows_transfer_down() {
+    for (transfer_up, transfer_down) in [(1.0, 1.0), (0.0, 0.5), (1.0, 0.2)] {
+        let physprop = PhysicsProperties {
+            transfer_up,
+            transfer_down,
+            min_impactable: 1e-3,
+            ..PhysicsProperties::default()
+        };
+        let mut grid = DenseGridBoard::new(1, 3);
+        for y in 0..3 {
+            grid.set(0, y, SlimeState::Settled);
+        }
+        grid.set(0, 2, SlimeState::falling(&physprop));
+        grid.cell_mut(0, 2).unwrap().y_bottom = 3.0;
+        let reached = row_landing(&mut grid, &physprop);
+        let per_hop = 0.5 * transfer_down;
+        assert!((reached[&(0, 1)] - reached[&(0, 2)] * per_hop).abs() < 1e-4);
+        assert!((reached[&(0, 0)] - reached[&(0, 1)] * per_hop).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn left_and_right_decay_differently_where_the_tuning_does() {
+    let physprop = PhysicsProperties::default();
+    let rightwards = row_landing(
+        &mut Lopsided {
+            grid: row(0, &physprop),
+        },
+        &physprop,
+    );
+    let leftwards = row_landing(
+        &mut Lopsided {
+            grid: row(2, &physprop),
+        },
+        &physprop,
+    );
+    // Both landings are alike, only what each end passes on differs
+    assert_eq!(rightwards[&(0, 0)], leftwards[&(2, 0)]);
+    let right = rightwards[&(1, 0)] / rightwards[&(0, 0)];
+    let left = leftwards[&(1, 0)] / leftwards[&(2, 0)];
+    assert!((right - 0.5).abs() < 1e-4, "{right}");
+    assert!((left - 0.125).abs() < 1e-4, "{left}");
+    // From the middle on, both go on the same
+    assert!((rightwards[&(2, 0)] / rightwards[&(1, 0)] - 0.5).abs() < 1e-4);
+    assert!((leftwards[&(0, 0)] / leftwards[&(1, 0)] - 0.5).abs() < 1e-4);
+}
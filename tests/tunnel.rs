@@ -0,0 +1,45 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// The impulse a slime dropped from twenty cells up lands on another with, stepping by `dt`, and where
+/// it lands.
+fn landing(dt: f32) -> (f32, f32) {
+    let physprop = PhysicsProperties::default();
+    let mut board = DenseGridBoard::new(1, 2);
+    board.set(0, 0, SlimeState::Settled);
+    board.set(0, 1, SlimeState::falling(&physprop));
+    board.cell_mut(0, 1).unwrap().y_bottom = 21.0;
+    let mut events = Vec::new();
+    for _ in 0..100_000 {
+        events.clear();
+        board.run_physics_with_events(dt, &physprop, &mut events);
+        if let Some(impulse) = events.iter().find_map(|event| match event {
+            SlimeEvent::Landed { impulse, .. } => Some(*impulse),
+            _ => None,
+        }) {
+            return (impulse, board.cell(0, 1).unwrap().y_bottom);
+        }
+    }
+    panic!("never landed");
+}
+
+#[test]
+fn a_fall_three_cells_a_step_lands_like_small_steps() {
+    // 40 cells a second at the bottom of the drop, so three cells a step
+    let (coarse, coarse_at) = landing(0.075);
+    let (fine, fine_at) = landing(1.0 / 1000.0);
+    assert_eq!(coarse_at, 1.0);
+    assert_eq!(fine_at, 1.0);
+    assert!(
+        (coarse - fine).abs() < fine * 0.01,
+        "{coarse} against {fine}"
+    );
+    // Exactly what falling twenty cells under gravity comes to
+    let physprop = PhysicsProperties::<f32>::default();
+    let expected = physprop.velocity_to_impact * (2.0 * physprop.gravity * 20.0f32).sqrt();
+    assert!(
+        (coarse - expected).abs() < expected * 1e-4,
+        "{coarse} against {expected}"
+    );
+}
@@ -0,0 +1,97 @@
+use jiggly_fever::*;
+
+fn check(physprop: PhysicsProperties) -> PhysicsPropertiesError {
+    physprop.validate().unwrap_err()
+}
+
+#[test]
+fn defaults_and_presets_are_valid() {
+    assert_eq!(PhysicsProperties::<f32>::default().validate(), Ok(()));
+    assert_eq!(PhysicsProperties::bouncy().validate(), Ok(()));
+}
+
+#[test]
+fn nan_and_infinity_are_not_finite() {
+    let err = check(PhysicsProperties {
+        gravity: f32::NAN,
+        ..PhysicsProperties::default()
+    });
+    assert!(
+        matches!(err, PhysicsPropertiesError::NotFinite { field: "gravity", value } if value.is_nan())
+    );
+    let err = check(PhysicsProperties {
+        velocity_to_impact: f32::INFINITY,
+        ..PhysicsProperties::default()
+    });
+    assert_eq!(
+        err,
+        PhysicsPropertiesError::NotFinite {
+            field: "velocity_to_impact",
+            value: f32::INFINITY
+        }
+    );
+    let err = check(PhysicsProperties {
+        jiggle_damp: f32::NAN,
+        ..PhysicsProperties::default()
+    });
+    assert_eq!(err.field(), "jiggle_damp");
+}
+
+#[test]
+fn negative_gravity_is_rejected() {
+    let err = check(PhysicsProperties {
+        gravity: -40.0,
+        ..PhysicsProperties::default()
+    });
+    assert_eq!(
+        err,
+        PhysicsPropertiesError::Negative {
+            field: "gravity",
+            value: -40.0
+        }
+    );
+}
+
+#[test]
+fn damping_must_be_above_zero_and_at_most_one() {
+    for jiggle_damp in [0.0, -0.5, 1.01, 2.0] {
+        let err = check(PhysicsProperties {
+            jiggle_damp,
+            ..PhysicsProperties::default()
+        });
+        assert_eq!(
+            err,
+            PhysicsPropertiesError::OutOfRange {
+                field: "jiggle_damp",
+                value: jiggle_damp,
+                min: 0.0,
+                max: 1.0
+            }
+        );
+    }
+    let undamped = PhysicsProperties {
+        jiggle_damp: 1.0,
+        ..PhysicsProperties::default()
+    };
+    assert_eq!(undamped.validate(), Ok(()));
+}
+
+#[test]
+fn inverse_must_match_the_threshold() {
+    let physprop = PhysicsProperties::default();
+    let err = check(PhysicsProperties {
+        jiggle_life_threshold_inverse: physprop.jiggle_life_threshold_inverse * 1.01,
+        ..physprop
+    });
+    assert!(matches!(
+        err,
+        PhysicsPropertiesError::InverseMismatch { .. }
+    ));
+    assert_eq!(err.field(), "jiggle_life_threshold_inverse");
+    // Rounding is fine
+    let close = PhysicsProperties {
+        jiggle_life_threshold_inverse: physprop.jiggle_life_threshold_inverse * (1.0 + 1e-6),
+        ..physprop
+    };
+    assert_eq!(close.validate(), Ok(()));
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "alloc")]
+
+use jiggly_fever::*;
+
+/// Two columns of four, with the second slime of the first column taken out behind the board's back:
+/// the two above it moved down a slot keeping their height, and the bottom one left a little raised.
+fn edited() -> DenseGridBoard {
+    let mut board = DenseGridBoard::new(2, 6);
+    for x in 0..2 {
+        for y in 0..4 {
+            board.set(x, y, SlimeState::Settled);
+        }
+    }
+    board.take_slime((0, 1));
+    for y in 2..4 {
+        let (state, y_bottom) = board.take_slime((0, y)).unwrap();
+        board.set_slime((0, y - 1), state, y_bottom);
+    }
+    board.cell_mut(0, 0).unwrap().y_bottom = 0.3;
+    board
+}
+
+fn everywhere() -> impl Iterator<Item = (usize, usize)> {
+    (0..2).flat_map(|x| (0..4).map(move |y| (x, y)))
+}
+
+#[test]
+fn moved_slimes_fall_and_the_rest_jiggle() {
+    let physprop = PhysicsProperties::default();
+    let mut board = edited();
+    board.wake_region(everywhere(), &physprop);
+    for y in 0..3 {
+        assert!(
+            matches!(board.get(0, y), Some(SlimeState::Falling { .. })),
+            "{y}"
+        );
+    }
+    assert!(matches!(board.get(1, 0), Some(SlimeState::Jiggling { .. })));
+    assert!((0..3600).any(|_| board.run_physics(1.0 / 60.0, &physprop)));
+    for y in 0..3 {
+        assert!((board.cell(0, y).unwrap().y_bottom - y as f32).abs() < 1e-4);
+    }
+    for y in 0..4 {
+        assert!((board.cell(1, y).unwrap().y_bottom - y as f32).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn slimes_outside_the_region_are_left_alone() {
+    let physprop = PhysicsProperties::default();
+    let mut board = edited();
+    board.wake_region([(1, 0)], &physprop);
+    assert!(matches!(board.get(0, 0), Some(SlimeState::Settled)));
+    assert!(matches!(board.get(1, 0), Some(SlimeState::Jiggling { .. })));
+    board.wake_region([], &physprop);
+    assert!(matches!(board.get(0, 1), Some(SlimeState::Settled)));
+}